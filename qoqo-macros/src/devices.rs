@@ -229,6 +229,23 @@ pub fn device_wrapper_def(
                 })
             }
 
+            /// Return the matrix of the decoherence rates of the Lindblad equation if it has been set.
+            ///
+            /// Args:
+            ///     qubit (int): The qubit for which the rate matrix M is returned
+            ///
+            /// Returns:
+            ///     Optional[np.array]: 3 by 3 numpy array of decoherence rates or None if no rates are set for the qubit
+            ///
+            #[pyo3(text_signature = "(qubit)")]
+            pub fn decoherence_rate_matrix(&self, qubit: usize) -> Option<Py<PyArray2<f64>>> {
+                Python::with_gil(|py| -> Option<Py<PyArray2<f64>>> {
+                    self.internal
+                        .qubit_decoherence_rates(&qubit)
+                        .map(|matrix| matrix.to_pyarray_bound(py).unbind())
+                })
+            }
+
             /// Adds single qubit damping to noise rates.
             ///
             /// Args:
@@ -327,6 +344,80 @@ pub fn device_wrapper_def(
                 self.internal.multi_qubit_gate_names()
             }
 
+            /// Returns the gate times of all single and two qubit gates available on the device.
+            ///
+            /// Single qubit gate times use keys of the form `"gate:qubit"`, two qubit gate times
+            /// use keys of the form `"gate:control:target"` for both directions of each edge
+            /// in the connectivity graph. Gates that are not available are not included.
+            ///
+            /// Returns:
+            ///     Dict[str, float]: The gate times of the device.
+            pub fn gate_times_dict(&self) -> std::collections::HashMap<String, f64> {
+                let mut gate_times: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
+                for gate in self.internal.single_qubit_gate_names() {
+                    for qubit in 0..self.internal.number_qubits() {
+                        if let Some(time) = self.internal.single_qubit_gate_time(&gate, &qubit) {
+                            gate_times.insert(format!("{}:{}", gate, qubit), time);
+                        }
+                    }
+                }
+                for gate in self.internal.two_qubit_gate_names() {
+                    for (qubit_a, qubit_b) in self.internal.two_qubit_edges() {
+                        for (control, target) in [(qubit_a, qubit_b), (qubit_b, qubit_a)] {
+                            if let Some(time) = self.internal.two_qubit_gate_time(&gate, &control, &target) {
+                                gate_times.insert(format!("{}:{}:{}", gate, control, target), time);
+                            }
+                        }
+                    }
+                }
+                gate_times
+            }
+
+            /// Checks whether all gate operations of a circuit can be executed on the device.
+            ///
+            /// Each gate must be supported by the device and act on qubits (or connected qubit pairs)
+            /// for which the gate is available. Non-gate operations are not checked.
+            ///
+            /// Args:
+            ///     circuit (Circuit): The circuit that is validated.
+            ///
+            /// Returns:
+            ///     bool: True if the circuit is valid on the device.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to a Circuit.
+            ///     ValueError: Circuit contains operations not available on the device, the message lists all of them.
+            #[pyo3(text_signature = "(circuit)")]
+            pub fn validate_circuit(&self, circuit: &Bound<PyAny>) -> PyResult<bool> {
+                let circuit = crate::convert_into_circuit(circuit).map_err(|err| {
+                    PyTypeError::new_err(format!("Cannot convert python object to Circuit: {:?}", err))
+                })?;
+                crate::devices::validate_circuit_on_device(&self.internal, &circuit)
+            }
+
+            /// Returns the directed pairs of qubits that can execute a two qubit gate on the device.
+            ///
+            /// A pair `[control, target]` is included if at least one two qubit gate has a finite gate time
+            /// for that pair. The list can be used to build a graph for routing, e.g. with `networkx.from_edgelist`.
+            ///
+            /// Returns:
+            ///     List[List[int]]: The deduplicated `[control, target]` pairs sorted lexicographically.
+            pub fn coupling_map(&self) -> Vec<[usize; 2]> {
+                let mut pairs: std::collections::BTreeSet<[usize; 2]> = std::collections::BTreeSet::new();
+                for gate in self.internal.two_qubit_gate_names() {
+                    for (qubit_a, qubit_b) in self.internal.two_qubit_edges() {
+                        for (control, target) in [(qubit_a, qubit_b), (qubit_b, qubit_a)] {
+                            if let Some(time) = self.internal.two_qubit_gate_time(&gate, &control, &target) {
+                                if time.is_finite() {
+                                    pairs.insert([control, target]);
+                                }
+                            }
+                        }
+                    }
+                }
+                pairs.into_iter().collect()
+            }
+
             /// Returns a copy of the device (copy here produces a deepcopy).
             ///
             /// Returns:
@@ -396,12 +487,56 @@ pub fn device_wrapper_def(
                     .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
                 Ok(#ident {
-                    internal: deserialize(&bytes[..]).map_err(|_| {
-                        PyValueError::new_err("Input cannot be deserialized to selected Device.")
+                    internal: deserialize(&bytes[..]).map_err(|err| {
+                        PyValueError::new_err(format!("Input cannot be deserialized to selected Device: {}", err))
                     })?,
                 })
             }
 
+            #[cfg(feature = "json_schema")]
+            /// Save the json representation of the Device to a file.
+            ///
+            /// Args:
+            ///     path (str): The path of the file the Device is written to.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize Device to json.
+            ///     IOError: Cannot write Device to file.
+            #[pyo3(text_signature = "(path)")]
+            pub fn save_to_json_file(&self, path: &str) -> PyResult<()> {
+                let serialized = self.to_json()?;
+                std::fs::write(path, serialized).map_err(|err| {
+                    pyo3::exceptions::PyIOError::new_err(format!(
+                        "Cannot write Device to file {}: {}",
+                        path, err
+                    ))
+                })
+            }
+
+            #[cfg(feature = "json_schema")]
+            /// Load a Device from a file containing its json representation.
+            ///
+            /// Args:
+            ///     path (str): The path of the file the Device is read from.
+            ///
+            /// Returns:
+            ///     The deserialized Device.
+            ///
+            /// Raises:
+            ///     IOError: Cannot read Device from file.
+            ///     ValueError: Input cannot be deserialized to selected Device.
+            #[staticmethod]
+            #[pyo3(text_signature = "(path)")]
+            pub fn load_from_json_file(path: &str) -> PyResult<#ident> {
+                let serialized = std::fs::read_to_string(path).map_err(|err| {
+                    pyo3::exceptions::PyIOError::new_err(format!(
+                        "Cannot read Device from file {}: {}",
+                        path, err
+                    ))
+                })?;
+                #ident::from_json(&serialized)
+            }
+
             /// Convert the json representation of a device to a qoqo device.
             ///
             /// Args:
@@ -416,8 +551,8 @@ pub fn device_wrapper_def(
             #[pyo3(text_signature = "(input)")]
             pub fn from_json(input: &str) -> PyResult<#ident> {
                 Ok(#ident {
-                    internal: serde_json::from_str(input).map_err(|_| {
-                        PyValueError::new_err("Input cannot be deserialized to selected Device.")
+                    internal: serde_json::from_str(input).map_err(|err| {
+                        PyValueError::new_err(format!("Input cannot be deserialized to selected Device: {}", err))
                     })?,
                 })
             }
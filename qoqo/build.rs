@@ -28,8 +28,8 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::visit::{self, Visit};
 use syn::{
-    AttrStyle, Fields, File, GenericArgument, Ident, ItemStruct, LitStr, Macro, Path,
-    PathArguments, Token, Type, TypePath,
+    AttrStyle, Fields, File, GenericArgument, Ident, ItemStruct, Macro, Path, PathArguments, Token,
+    Type, TypePath,
 };
 
 type StructFieldInfo = Vec<(Ident, Option<String>, Type)>;
@@ -55,18 +55,6 @@ impl Visitor {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct CfgFeatureMacroArgument(String);
-
-impl Parse for CfgFeatureMacroArgument {
-    fn parse(input: ParseStream) -> syn::parse::Result<Self> {
-        input.parse::<Ident>()?;
-        input.parse::<Token![=]>()?;
-        let feature_name: LitStr = input.parse()?;
-        Ok(Self(feature_name.value()))
-    }
-}
-
 /// Struct for parsed derive macro arguments. Used to identify structs belonging to enums
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -106,17 +94,6 @@ impl<'ast> Visit<'ast> for Visitor {
         // Check attributes
         for att in itemstruct.attrs.clone() {
             let path = att.path().get_ident().map(|id| id.to_string());
-            // TOFIX: REMOVE WHEN STABILISED
-            if matches!(att.style, AttrStyle::Outer)
-                && path == Some("cfg".to_string())
-                && !cfg!(feature = "unstable_operation_definition")
-            {
-                let cfg_feature_name: CfgFeatureMacroArgument =
-                    att.parse_args().expect("parsing failed 1");
-                if cfg_feature_name.0.contains("unstable_operation_definition") {
-                    return;
-                }
-            }
             // only consider the wrap attribute, if no derive attribute is present don't add anything
             // to the internal storage of the visitor
             if matches!(att.style, AttrStyle::Outer) && path == Some("wrap".to_string()) {
@@ -134,14 +111,6 @@ impl<'ast> Visit<'ast> for Visitor {
             Some(id) => Some(id.clone()),
             _ => i.path.segments.last().map(|segment| segment.ident.clone()),
         };
-        // TOFIX: REMOVE WHEN STABILISED
-        if i.tokens.clone().into_iter().any(|tok| {
-            tok.to_string().contains("CallDefinedGate")
-                || tok.to_string().contains("DefinitionGate")
-        }) && !cfg!(feature = "unstable_operation_definition")
-        {
-            return;
-        }
         if let Some(ident) = id {
             if ident.to_string().as_str() == "insert_pyany_to_operation" {
                 self.pyany_to_operation.push(i.tokens.clone())
@@ -405,6 +374,8 @@ Raises:
                     if meth_doc.eq("") {
                         continue;
                     }
+                    // Static methods are stored as staticmethod objects wrapping the function in `__func__`
+                    let is_static = meth.hasattr("__func__")?;
                     let meth_args = collect_args_from_doc(
                         meth_doc.as_str(),
                         name.as_str(),
@@ -414,8 +385,13 @@ Raises:
                     )
                     .join(", ");
                     module_doc.push_str(&format!(
-                        "    def {meth_name}(self{}){}: # type: ignore\n        \"\"\"\n{meth_doc}\n\"\"\"\n\n",
-                        if meth_args.is_empty() { "".to_owned() } else { format!(", {}", meth_args) },
+                        "{}    def {meth_name}({}){}: # type: ignore\n        \"\"\"\n{meth_doc}\n\"\"\"\n\n",
+                        if is_static { "    @staticmethod\n" } else { "" },
+                        match (is_static, meth_args.is_empty()) {
+                            (true, _) => meth_args,
+                            (false, true) => "self".to_owned(),
+                            (false, false) => format!("self, {}", meth_args),
+                        },
                         collect_return_from_doc(
                             meth_doc.as_str(),
                             name.as_str(),
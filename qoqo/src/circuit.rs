@@ -20,15 +20,22 @@
 
 use crate::{QoqoError, QOQO_VERSION};
 use bincode::{deserialize, serialize};
+use numpy::ToPyArray;
 use pyo3::exceptions::{PyIndexError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
+use pyo3::types::{PyByteArray, PyDict, PySet, PySlice, PyTuple};
+use qoqo_calculator::{Calculator, CalculatorError, CalculatorFloat};
+use roqoqo::operations::{Operation, SingleQubitGateOperation, TwoQubitGateOperation};
 use roqoqo::prelude::*;
 use roqoqo::{Circuit, OperationIterator, ROQOQO_VERSION};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
+#[cfg(feature = "unstable_analog_operations")]
+use struqture::OperateOnDensityMatrix;
 
 use crate::operations::{convert_operation_to_pyobject, convert_pyany_to_operation};
 
+mod peephole;
+
 /// Module containing the Circuit class that represents a quantum circuit in qoqo.
 ///
 /// In qoqo, single operations are collected in a circuit to build up a quantum program.
@@ -86,6 +93,19 @@ impl CircuitWrapper {
             })
         }
     }
+
+    /// Convert a possibly negative Python index to an index into the Circuit.
+    fn normalize_index(&self, index: isize) -> PyResult<usize> {
+        let length = self.internal.len() as isize;
+        let normalized = if index < 0 { index + length } else { index };
+        if normalized < 0 || normalized >= length {
+            return Err(PyIndexError::new_err(format!(
+                "Index {} out of range",
+                index
+            )));
+        }
+        Ok(normalized as usize)
+    }
 }
 
 #[pymethods]
@@ -132,6 +152,42 @@ impl CircuitWrapper {
         })
     }
 
+    /// Substitute the symbolic parameters in a clone of the Circuit and check that no symbolic parameters remain.
+    ///
+    /// Parameters in substitution_parameters that do not occur in the Circuit are ignored.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the Circuit.
+    ///
+    /// Returns:
+    ///     self: The Circuit with all parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: Symbolic parameters are missing from substitution_parameters, listing their names.
+    ///     RuntimeError: The parameter substitution failed.
+    pub fn make_concrete(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let missing: Vec<String> = symbolic_parameter_names(&self.internal)
+            .into_iter()
+            .filter(|name| !substitution_parameters.contains_key(name))
+            .collect();
+        if !missing.is_empty() {
+            return Err(PyRuntimeError::new_err(format!(
+                "Circuit cannot be made concrete, symbolic parameters without value: {}",
+                missing.join(", ")
+            )));
+        }
+        let concrete = self.substitute_parameters(substitution_parameters)?;
+        if concrete.internal.is_parametrized() {
+            return Err(PyRuntimeError::new_err(
+                "Circuit still contains symbolic parameters after substitution",
+            ));
+        }
+        Ok(concrete)
+    }
+
     /// Remap qubits in operations in clone of Circuit.
     ///
     /// Args:
@@ -151,6 +207,102 @@ impl CircuitWrapper {
         })
     }
 
+    /// Permute the qubits of all operations in clone of Circuit.
+    ///
+    /// Qubit `i` of the Circuit is moved to qubit `permutation[i]`.
+    ///
+    /// Args:
+    ///     permutation (List[int]): The permutation of the qubit indices.
+    ///
+    /// Returns:
+    ///     self: The Circuit with the qubits permuted.
+    ///
+    /// Raises:
+    ///     ValueError: The permutation is not valid for the Circuit.
+    ///     RuntimeError: The qubit remapping failed.
+    pub fn apply_qubit_permutation(&self, permutation: Vec<usize>) -> PyResult<Self> {
+        let number_qubits = permutation.len();
+        let mut seen: Vec<bool> = vec![false; number_qubits];
+        for &target in permutation.iter() {
+            if target >= number_qubits {
+                return Err(PyValueError::new_err(format!(
+                    "Permutation index {} is out of range for a permutation of length {}",
+                    target, number_qubits
+                )));
+            }
+            if seen[target] {
+                return Err(PyValueError::new_err(format!(
+                    "Permutation index {} appears more than once",
+                    target
+                )));
+            }
+            seen[target] = true;
+        }
+        if let InvolvedQubits::Set(involved) = self.internal.involved_qubits() {
+            if let Some(qubit) = involved.into_iter().filter(|q| *q >= number_qubits).max() {
+                return Err(PyValueError::new_err(format!(
+                    "Permutation of length {} does not cover qubit {} of the Circuit",
+                    number_qubits, qubit
+                )));
+            }
+        }
+        let mapping: HashMap<usize, usize> = permutation.into_iter().enumerate().collect();
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|err| PyRuntimeError::new_err(format!("Qubit remapping failed: {:?}", err)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Remap the qubits of a clone of the Circuit to compact indices starting from 0.
+    ///
+    /// The involved qubits are sorted and assigned new sequential indices in that order.
+    ///
+    /// Returns:
+    ///     Tuple[Circuit, Dict[int, int]]: The remapped Circuit and the {old qubit: new qubit} mapping used.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    pub fn normalize_qubit_indices(&self) -> PyResult<(Self, HashMap<usize, usize>)> {
+        let mut qubits: BTreeSet<usize> = BTreeSet::new();
+        for op in self.internal.iter() {
+            if let InvolvedQubits::Set(involved) = op.involved_qubits() {
+                qubits.extend(involved);
+            }
+        }
+        let mapping: HashMap<usize, usize> = qubits
+            .into_iter()
+            .enumerate()
+            .map(|(new, old)| (old, new))
+            .collect();
+        // Qubit remapping requires a permutation, so the freed old indices are
+        // assigned to the new indices that are not remapped themselves.
+        let mut full_mapping = mapping.clone();
+        let freed: BTreeSet<usize> = mapping
+            .keys()
+            .filter(|old| !mapping.values().any(|new| new == *old))
+            .copied()
+            .collect();
+        let unmapped: BTreeSet<usize> = mapping
+            .values()
+            .filter(|new| !mapping.contains_key(new))
+            .copied()
+            .collect();
+        full_mapping.extend(unmapped.into_iter().zip(freed));
+        let new_internal = self
+            .internal
+            .remap_qubits(&full_mapping)
+            .map_err(|err| PyRuntimeError::new_err(format!("Qubit remapping failed: {:?}", err)))?;
+        Ok((
+            Self {
+                internal: new_internal,
+            },
+            mapping,
+        ))
+    }
+
     /// Return clone of the circuit with all overrotation Pragmas applied.
     ///
     /// Returns:
@@ -179,6 +331,179 @@ impl CircuitWrapper {
         })
     }
 
+    /// Return a copy of the Circuit with constant operations folded.
+    ///
+    /// Constant symbolic expressions are evaluated, gates equivalent to the identity
+    /// (e.g. zero-angle rotations) are removed and adjacent pairs of identical
+    /// self-inverse gates (e.g. PauliX followed by PauliX) cancel.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit with constant operations folded.
+    ///
+    /// Raises:
+    ///     RuntimeError: The Circuit is parametrized and cannot be folded.
+    pub fn constant_folding(&self) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.constant_folding().map_err(|x| {
+                PyRuntimeError::new_err(format!(
+                    "Constant folding is only possible for circuits without free parameters: {:?}",
+                    x
+                ))
+            })?,
+        })
+    }
+
+    /// Return the inverse of the Circuit.
+    ///
+    /// The order of the operations is reversed and every operation is replaced with its inverse.
+    /// Rotations are replaced with rotations by the negative angle, self-inverse gates are unchanged
+    /// and gates with a known inverse gate (e.g. SqrtPauliX) are replaced with that gate.
+    /// GivensRotation and GivensRotationLittleEndian are replaced with a Givens rotation and a PhaseShiftState1.
+    /// SGate and TGate are replaced with PhaseShiftState1, so inverting twice does not return
+    /// SGate and TGate but PhaseShiftState1 gates with the same unitary matrix.
+    ///
+    /// Returns:
+    ///     Circuit: The inverse Circuit.
+    ///
+    /// Raises:
+    ///     RuntimeError: The Circuit contains an operation without a known inverse.
+    pub fn inverse(&self) -> PyResult<Self> {
+        Ok(Self {
+            internal: self
+                .internal
+                .inverse()
+                .map_err(|x| PyRuntimeError::new_err(format!("Cannot invert circuit: {:?}", x)))?,
+        })
+    }
+
+    /// Return the adjoint (Hermitian conjugate) of the Circuit.
+    ///
+    /// For unitary Circuits the adjoint is the inverse, see `inverse`.
+    ///
+    /// Returns:
+    ///     Circuit: The adjoint Circuit.
+    ///
+    /// Raises:
+    ///     RuntimeError: The Circuit contains an operation without a known adjoint, e.g. a noise PRAGMA.
+    pub fn adjoint(&self) -> PyResult<Self> {
+        self.inverse()
+    }
+
+    /// Return a copy of the Circuit with all PragmaLoop operations replaced by their repeated circuits.
+    ///
+    /// Every PragmaLoop is replaced with its circuit repeated floor(repetitions) times.
+    /// Nested PragmaLoop operations are flattened recursively.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit without PragmaLoop operations.
+    ///
+    /// Raises:
+    ///     RuntimeError: A PragmaLoop has a symbolic number of repetitions.
+    pub fn flatten_pragma_loops(&self) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.flatten_pragma_loops().map_err(|x| {
+                PyRuntimeError::new_err(format!("Cannot flatten PragmaLoop operations: {:?}", x))
+            })?,
+        })
+    }
+
+    /// Return a copy of the Circuit with a peephole optimization pass applied.
+    ///
+    /// A window of up to `window_size` consecutive operations is slid across the Circuit.
+    /// Sequences of single-qubit gates on the same qubit matching a known simplification
+    /// (e.g. [TGate, TGate] -> [SGate] or [Hadamard, PauliZ, Hadamard] -> [PauliX]) are replaced.
+    ///
+    /// Args:
+    ///     window_size (int): The maximum number of consecutive operations replaced at once. Defaults to 3.
+    ///
+    /// Returns:
+    ///     Circuit: The optimized Circuit.
+    #[pyo3(signature = (window_size = 3))]
+    pub fn peephole_optimization(&self, window_size: usize) -> PyResult<Self> {
+        Ok(Self {
+            internal: peephole::peephole_optimization(&self.internal, window_size),
+        })
+    }
+
+    /// Return the qubits involved in the operations of the Circuit.
+    ///
+    /// Returns:
+    ///     Set[int]: The union of the qubits of all operations, or {"All"} if any operation acts on all qubits.
+    pub fn involved_qubits<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PySet>> {
+        match self.internal.involved_qubits() {
+            InvolvedQubits::All => PySet::new_bound(py, &["All"]),
+            InvolvedQubits::None => PySet::empty_bound(py),
+            InvolvedQubits::Set(qubits) => {
+                PySet::new_bound(py, &qubits.into_iter().collect::<Vec<usize>>())
+            }
+        }
+    }
+
+    /// Return the largest qubit index of the operations in the Circuit.
+    ///
+    /// Operations acting on all qubits or on no qubits are not taken into account.
+    ///
+    /// Returns:
+    ///     Optional[int]: The largest qubit index, or None if no operation acts on a specific qubit.
+    pub fn max_qubit_index(&self) -> Option<usize> {
+        self.internal
+            .iter()
+            .filter_map(|op| match op.involved_qubits() {
+                InvolvedQubits::Set(qubits) => qubits.into_iter().max(),
+                _ => None,
+            })
+            .max()
+    }
+
+    /// Return the depth of the Circuit.
+    ///
+    /// The depth is the length of the longest chain of operations where each operation
+    /// acts on at least one qubit of the previous operation in the chain.
+    /// Operations acting on all qubits are treated as acting on every qubit seen so far in the Circuit.
+    ///
+    /// Returns:
+    ///     int: The depth of the Circuit.
+    pub fn depth(&self) -> usize {
+        self.internal.depth()
+    }
+
+    /// Return the number of operations acting on each qubit of the Circuit.
+    ///
+    /// Operations acting on all qubits count for every qubit seen so far in the Circuit.
+    ///
+    /// Returns:
+    ///     Dict[int, int]: The number of operations for each qubit.
+    pub fn gate_count_by_qubit(&self) -> HashMap<usize, usize> {
+        self.internal.gate_count_by_qubit()
+    }
+
+    /// Return the gate volume of the Circuit.
+    ///
+    /// The gate volume is the depth of the Circuit (the longest chain of operations
+    /// acting on shared qubits) multiplied by its width (the number of distinct qubits involved).
+    ///
+    /// Returns:
+    ///     int: The gate volume of the Circuit.
+    pub fn gate_volume(&self) -> usize {
+        self.internal.gate_volume()
+    }
+
+    /// Return the operations on the critical path of the Circuit.
+    ///
+    /// The critical path is the longest chain of operations where each operation
+    /// acts on at least one qubit of the previous operation in the chain.
+    /// When several chains have the same length, any one of them is returned.
+    ///
+    /// Returns:
+    ///     List[Operation]: The operations on the critical path in execution order.
+    pub fn critical_path_gates(&self) -> PyResult<Vec<PyObject>> {
+        self.internal
+            .critical_path_gates()
+            .into_iter()
+            .map(convert_operation_to_pyobject)
+            .collect()
+    }
+
     /// Count the number of occurences of a set of operation tags in the circuit.
     ///
     /// Args:
@@ -208,6 +533,167 @@ impl CircuitWrapper {
         operations
     }
 
+    /// Count how often each operation type occurs in the circuit.
+    ///
+    /// Returns:
+    ///     Dict[str, int]: The number of occurences of each hqslang name in the Circuit.
+    pub fn count_operations_by_hqslang(&self) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for op in self.internal.iter() {
+            *counts.entry(op.hqslang().to_string()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Return whether the Circuit corresponds to a unitary transformation.
+    ///
+    /// A Circuit is unitary when all of its operations are single-, two-, three- or multi-qubit gate operations.
+    /// Circuits containing definitions, measurements or PRAGMA operations are not unitary.
+    ///
+    /// Returns:
+    ///     bool: True if every operation in the Circuit is a gate operation, False otherwise.
+    pub fn is_unitary(&self) -> bool {
+        const UNITARY_TAGS: [&str; 4] = [
+            "SingleQubitGateOperation",
+            "TwoQubitGateOperation",
+            "ThreeQubitGateOperation",
+            "MultiQubitGateOperation",
+        ];
+        self.internal
+            .iter()
+            .all(|op| UNITARY_TAGS.iter().any(|tag| op.tags().contains(tag)))
+    }
+
+    /// Return whether any operation in the Circuit contains symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if at least one operation is parametrized, False otherwise.
+    pub fn is_parametrized(&self) -> bool {
+        self.internal.iter().any(|op| op.is_parametrized())
+    }
+
+    /// Return the names of the free symbolic parameters of all operations in the Circuit.
+    ///
+    /// Returns:
+    ///     List[str]: The sorted list of names of the symbolic parameters.
+    pub fn free_parameters(&self) -> Vec<String> {
+        symbolic_parameter_names(&self.internal)
+    }
+
+    /// Return whether the Circuit contains any measurement operation.
+    ///
+    /// Returns:
+    ///     bool: True if at least one operation has the Measurement tag, False otherwise.
+    pub fn has_measurements(&self) -> bool {
+        self.internal
+            .iter()
+            .any(|op| op.tags().contains(&"Measurement"))
+    }
+
+    /// Return whether the Circuit contains any classical register definition.
+    ///
+    /// Returns:
+    ///     bool: True if at least one operation has the Definition tag, False otherwise.
+    pub fn has_definitions(&self) -> bool {
+        self.internal
+            .iter()
+            .any(|op| op.tags().contains(&"Definition"))
+    }
+
+    /// Convert the Circuit to a Pennylane QuantumScript.
+    ///
+    /// Gate operations with a direct Pennylane equivalent are mapped to the corresponding class in `pennylane.ops`,
+    /// all other single- and two-qubit gates are applied as a `QubitUnitary`. PragmaLoop operations are flattened
+    /// and definitions are ignored. MeasureQubit, PragmaRepeatedMeasurement, PragmaGetStateVector,
+    /// PragmaGetDensityMatrix, PragmaGetReducedDensityMatrix and PragmaGetOccupationProbability are mapped
+    /// to Pennylane measurements and need to come after all gate operations.
+    ///
+    /// Returns:
+    ///     pennylane.tape.QuantumScript: The QuantumScript with the operations and measurements of the Circuit.
+    ///
+    /// Raises:
+    ///     ImportError: Pennylane is not installed.
+    ///     ValueError: The Circuit contains an operation that cannot be converted to Pennylane.
+    pub fn to_pennylane_tape<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let qml = py.import_bound("pennylane")?;
+        let circuit = self.internal.flatten_pragma_loops().map_err(|err| {
+            PyValueError::new_err(format!("Cannot flatten PragmaLoop operations: {:?}", err))
+        })?;
+        let mut circuit_qubits: BTreeSet<usize> = BTreeSet::new();
+        for operation in circuit.iter() {
+            if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+                circuit_qubits.extend(qubits);
+            }
+        }
+        let circuit_qubits: Vec<usize> = circuit_qubits.into_iter().collect();
+
+        let mut operations: Vec<Bound<PyAny>> = Vec::new();
+        let mut measurements: Vec<Bound<PyAny>> = Vec::new();
+        for operation in circuit.iter() {
+            if operation.tags().contains(&"Definition") {
+                continue;
+            }
+            if let Some(measurement) = pennylane_measurement(&qml, operation, &circuit_qubits)? {
+                measurements.push(measurement);
+            } else if !measurements.is_empty() {
+                return Err(PyValueError::new_err(format!(
+                    "Operation {} after a measurement cannot be converted to Pennylane",
+                    operation.hqslang()
+                )));
+            } else {
+                operations.push(pennylane_operation(&qml, operation)?);
+            }
+        }
+        py.import_bound("pennylane.tape")?
+            .getattr("QuantumScript")?
+            .call1((operations, measurements))
+    }
+
+    /// Return a summary of diagnostic information about the Circuit.
+    ///
+    /// The summary contains the keys:
+    ///
+    /// * `number_of_operations` - The total number of operations.
+    /// * `operation_counts` - The number of operations for each hqslang name.
+    /// * `involved_qubits` - The sorted list of qubits the operations act on.
+    /// * `depth` - The depth of the Circuit.
+    /// * `is_parametrized` - Whether any operation contains symbolic parameters.
+    /// * `symbolic_parameters` - The sorted list of names of the symbolic parameters.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The summary of the Circuit.
+    pub fn summary(&self, py: Python) -> HashMap<String, PyObject> {
+        let mut operation_counts: HashMap<String, usize> = HashMap::new();
+        let mut involved_qubits: HashSet<usize> = HashSet::new();
+        let mut is_parametrized = false;
+        for op in self.internal.iter() {
+            *operation_counts
+                .entry(op.hqslang().to_string())
+                .or_insert(0) += 1;
+            if let InvolvedQubits::Set(qubits) = op.involved_qubits() {
+                involved_qubits.extend(qubits);
+            }
+            is_parametrized = is_parametrized || op.is_parametrized();
+        }
+        let mut involved_qubits: Vec<usize> = involved_qubits.into_iter().collect();
+        involved_qubits.sort_unstable();
+
+        let mut summary: HashMap<String, PyObject> = HashMap::new();
+        summary.insert(
+            "number_of_operations".to_string(),
+            self.internal.len().into_py(py),
+        );
+        summary.insert("operation_counts".to_string(), operation_counts.into_py(py));
+        summary.insert("involved_qubits".to_string(), involved_qubits.into_py(py));
+        summary.insert("depth".to_string(), self.internal.depth().into_py(py));
+        summary.insert("is_parametrized".to_string(), is_parametrized.into_py(py));
+        summary.insert(
+            "symbolic_parameters".to_string(),
+            symbolic_parameter_names(&self.internal).into_py(py),
+        );
+        summary
+    }
+
     /// Return a copy of the Circuit (copy here produces a deepcopy).
     ///
     /// Returns:
@@ -284,6 +770,81 @@ impl CircuitWrapper {
         })
     }
 
+    /// Write the bincode representation of the Circuit to a file using the [bincode] crate.
+    ///
+    /// The serialization and the file write happen in Rust, avoiding an intermediate Python bytes object.
+    ///
+    /// Args:
+    ///     path (str): The path of the file the serialized Circuit is written to.
+    ///
+    /// Raises:
+    ///     RuntimeError: Cannot serialize Circuit to bytes or cannot write file.
+    pub fn to_bincode_file(&self, path: &str) -> PyResult<()> {
+        let serialized = serialize(&self.internal)
+            .map_err(|_| PyRuntimeError::new_err("Cannot serialize Circuit to bytes"))?;
+        std::fs::write(path, serialized)
+            .map_err(|err| PyRuntimeError::new_err(format!("Cannot write file {}: {}", path, err)))
+    }
+
+    /// Read a Circuit from a file containing its bincode representation.
+    ///
+    /// Args:
+    ///     path (str): The path of the file containing the serialized Circuit (in [bincode] form).
+    ///
+    /// Returns:
+    ///     Circuit: The deserialized Circuit.
+    ///
+    /// Raises:
+    ///     RuntimeError: Cannot read file or file cannot be deserialized to Circuit.
+    #[staticmethod]
+    pub fn from_bincode_file(path: &str) -> PyResult<Self> {
+        let bytes = std::fs::read(path).map_err(|err| {
+            PyRuntimeError::new_err(format!("Cannot read file {}: {}", path, err))
+        })?;
+        Ok(Self {
+            internal: deserialize(&bytes[..]).map_err(|_| {
+                PyRuntimeError::new_err(format!("File {} cannot be deserialized to Circuit", path))
+            })?,
+        })
+    }
+
+    #[cfg(feature = "msgpack")]
+    /// Return the MessagePack representation of the Circuit using the [rmp_serde] crate.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized Circuit (in MessagePack form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize Circuit to bytes.
+    pub fn to_msgpack(&self) -> PyResult<Py<PyByteArray>> {
+        let serialized = rmp_serde::to_vec_named(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize Circuit to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    #[cfg(feature = "msgpack")]
+    /// Convert the MessagePack representation of the Circuit to a Circuit using the [rmp_serde] crate.
+    ///
+    /// Args:
+    ///     data (ByteArray): The serialized Circuit (in MessagePack form).
+    ///
+    /// Returns:
+    ///     Circuit: The deserialized Circuit.
+    ///
+    /// Raises:
+    ///     ValueError: Input cannot be deserialized to Circuit.
+    #[staticmethod]
+    pub fn from_msgpack(data: &Bound<PyByteArray>) -> PyResult<Self> {
+        let bytes = data.to_vec();
+        Ok(Self {
+            internal: rmp_serde::from_slice(&bytes[..])
+                .map_err(|_| PyValueError::new_err("Input cannot be deserialized to Circuit"))?,
+        })
+    }
+
     /// Return the json representation of the Circuit.
     ///
     /// Returns:
@@ -291,7 +852,7 @@ impl CircuitWrapper {
     ///
     /// Raises:
     ///     ValueError: Cannot serialize Circuit to json.
-    fn to_json(&self) -> PyResult<String> {
+    pub fn to_json(&self) -> PyResult<String> {
         let serialized = serde_json::to_string(&self.internal)
             .map_err(|_| PyValueError::new_err("Cannot serialize Circuit to json"))?;
         Ok(serialized)
@@ -308,6 +869,21 @@ impl CircuitWrapper {
         serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
     }
 
+    #[cfg(feature = "json_schema")]
+    /// Return the json representation of the Circuit after validating it against the JsonSchema of the class.
+    ///
+    /// Returns:
+    ///     str: The serialized form of Circuit.
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize Circuit to json.
+    ///     RuntimeError: The serialized Circuit does not match the JsonSchema.
+    pub fn to_json_schema_validated(&self) -> PyResult<String> {
+        let serialized = self.to_json()?;
+        validate_circuit_json(&serialized)?;
+        Ok(serialized)
+    }
+
     #[cfg(feature = "json_schema")]
     /// Returns the current version of the qoqo library .
     ///
@@ -420,6 +996,58 @@ impl CircuitWrapper {
         })
     }
 
+    /// Return a copy of the Circuit with the Operation at the specified index replaced.
+    ///
+    /// Args:
+    ///     index (int): The index of the Operation to replace.
+    ///     new_operation (Operation): The Operation placed at the index.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit with the Operation replaced.
+    ///
+    /// Raises:
+    ///     TypeError: Cannot convert python object to Operation.
+    ///     IndexError: Index out of range.
+    pub fn replace_at(
+        &self,
+        index: usize,
+        new_operation: &Bound<PyAny>,
+    ) -> PyResult<CircuitWrapper> {
+        let new_operation = convert_pyany_to_operation(new_operation)
+            .map_err(|_| PyTypeError::new_err("Cannot convert python object to Operation"))?;
+        let mut internal = self.internal.clone();
+        let mut_reference_to_operation = internal
+            .get_mut(index)
+            .ok_or_else(|| PyIndexError::new_err(format!("Index {} out of range", index)))?;
+        *mut_reference_to_operation = new_operation;
+        Ok(CircuitWrapper { internal })
+    }
+
+    /// Split the Circuit into two Circuits at the given index.
+    ///
+    /// Args:
+    ///     index (int): The index of the first operation of the second Circuit.
+    ///
+    /// Returns:
+    ///     Tuple[Circuit, Circuit]: The operations with indices [0, index) and [index, len(circuit)).
+    ///
+    /// Raises:
+    ///     IndexError: Index out of range.
+    pub fn split_at(&self, index: usize) -> PyResult<(CircuitWrapper, CircuitWrapper)> {
+        if index > self.internal.len() {
+            return Err(PyIndexError::new_err(format!(
+                "Index {} out of range",
+                index
+            )));
+        }
+        let first: Circuit = self.internal.iter().take(index).cloned().collect();
+        let second: Circuit = self.internal.iter().skip(index).cloned().collect();
+        Ok((
+            CircuitWrapper { internal: first },
+            CircuitWrapper { internal: second },
+        ))
+    }
+
     /// Return a list of definitions in the Circuit.
     ///
     /// Definitions need to be unique.
@@ -462,15 +1090,17 @@ impl CircuitWrapper {
     ///
     /// Args:
     ///     tag (str): tag by which to filter operations.
+    ///     keep (bool): Whether to return the operations with the tag (default) or the operations without the tag.
     ///
     /// Returns:
-    ///     List[Operation]: A vector of the operations with the specified tag in the Circuit.
-    pub fn filter_by_tag(&self, tag: &str) -> PyResult<Vec<PyObject>> {
+    ///     List[Operation]: A vector of the operations with (or without) the specified tag in the Circuit.
+    #[pyo3(signature=(tag, keep = true))]
+    pub fn filter_by_tag(&self, tag: &str, keep: bool) -> PyResult<Vec<PyObject>> {
         let mut tagged: Vec<PyObject> = Vec::new();
         for op in self
             .internal
             .iter()
-            .filter(|x| x.tags().contains(&tag))
+            .filter(|x| x.tags().contains(&tag) == keep)
             .cloned()
             .map(convert_operation_to_pyobject)
         {
@@ -479,6 +1109,117 @@ impl CircuitWrapper {
         Ok(tagged)
     }
 
+    /// Return a copy of the Circuit containing only the operations with the given hqslang name.
+    ///
+    /// Args:
+    ///     name (str): The hqslang name of the operations that are kept.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit containing the operations with the hqslang name in their original order.
+    pub fn filter_by_hqslang(&self, name: &str) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self
+                .internal
+                .iter()
+                .filter(|x| x.hqslang() == name)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Return a copy of the Circuit with all PRAGMA operations removed.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit containing only the operations that are not PRAGMA operations.
+    pub fn remove_pragma_operations(&self) -> CircuitWrapper {
+        self.remove_pragma_operations_except(Vec::new())
+    }
+
+    /// Return a copy of the Circuit with all PRAGMA operations removed except the allowed types.
+    ///
+    /// Args:
+    ///     allowed_hqslangs (List[str]): The hqslang names of the PRAGMA operations that are kept.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit containing the operations that are not PRAGMA operations and the allowed PRAGMA operations.
+    pub fn remove_pragma_operations_except(&self, allowed_hqslangs: Vec<String>) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self
+                .internal
+                .iter()
+                .filter(|x| {
+                    !x.tags().contains(&"PragmaOperation")
+                        || allowed_hqslangs.iter().any(|name| name == x.hqslang())
+                })
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Return a list of operations acting on the given qubit.
+    ///
+    /// Operations involving all qubits are considered to act on the given qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which to filter operations.
+    ///
+    /// Returns:
+    ///     List[Operation]: The operations acting on the qubit in the order of the Circuit.
+    pub fn operations_affecting_qubit(&self, qubit: usize) -> PyResult<Vec<PyObject>> {
+        let mut affecting: Vec<PyObject> = Vec::new();
+        for op in self
+            .internal
+            .iter()
+            .filter(|x| match x.involved_qubits() {
+                InvolvedQubits::All => true,
+                InvolvedQubits::None => false,
+                InvolvedQubits::Set(qubits) => qubits.contains(&qubit),
+            })
+            .cloned()
+            .map(convert_operation_to_pyobject)
+        {
+            affecting.push(op?)
+        }
+        Ok(affecting)
+    }
+
+    /// Return a list of operations acting only on qubits in the given range.
+    ///
+    /// Operations involving all qubits are always included, operations involving no qubits are excluded.
+    ///
+    /// Args:
+    ///     start (int): The first qubit of the range.
+    ///     end (int): The qubit after the last qubit of the range.
+    ///
+    /// Returns:
+    ///     List[Operation]: The operations with all involved qubits q satisfying start <= q < end.
+    ///
+    /// Raises:
+    ///     ValueError: The start of the range is larger than the end.
+    pub fn operations_on_qubit_range(&self, start: usize, end: usize) -> PyResult<Vec<PyObject>> {
+        if start > end {
+            return Err(PyValueError::new_err(format!(
+                "Start of qubit range {} is larger than end {}",
+                start, end
+            )));
+        }
+        let mut in_range: Vec<PyObject> = Vec::new();
+        for op in self
+            .internal
+            .iter()
+            .filter(|x| match x.involved_qubits() {
+                InvolvedQubits::All => true,
+                InvolvedQubits::None => false,
+                InvolvedQubits::Set(qubits) => qubits.iter().all(|q| (start..end).contains(q)),
+            })
+            .cloned()
+            .map(convert_operation_to_pyobject)
+        {
+            in_range.push(op?)
+        }
+        Ok(in_range)
+    }
+
     /// Add an Operation to Circuit.
     ///
     /// Args:
@@ -491,6 +1232,25 @@ impl CircuitWrapper {
         Ok(())
     }
 
+    /// Return a new Circuit with the operations of another Circuit placed before this Circuit.
+    ///
+    /// Args:
+    ///     other (Circuit): The Circuit to prepend.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit `other` followed by this Circuit.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to Circuit.
+    pub fn prepend_circuit(&self, other: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
+        let other = convert_into_circuit(other).map_err(|x| {
+            PyTypeError::new_err(format!("Cannot convert python object to Circuit {:?}", x))
+        })?;
+        Ok(CircuitWrapper {
+            internal: other + &self.internal,
+        })
+    }
+
     /// Return a string containing a formatted (string) representation of the Circuit.
     ///
     /// Returns:
@@ -507,6 +1267,50 @@ impl CircuitWrapper {
         Ok(format!("{}", self.internal))
     }
 
+    /// Return an HTML table representation of the Circuit for rich display in Jupyter notebooks.
+    ///
+    /// Each operation is shown as a row with its number, hqslang name, involved qubits and parameters.
+    ///
+    /// Returns:
+    ///     str: The HTML representation of the Circuit.
+    fn _repr_html_(&self) -> String {
+        let mut html = String::from(
+            "<style>\n\
+             .qoqo-circuit { border-collapse: collapse; }\n\
+             .qoqo-circuit th, .qoqo-circuit td { padding: 4px 8px; text-align: left; }\n\
+             .qoqo-circuit tr:nth-child(even) { background-color: #f2f2f2; }\n\
+             .qoqo-circuit tr:nth-child(odd) { background-color: #ffffff; }\n\
+             </style>\n\
+             <table class=\"qoqo-circuit\">\n\
+             <thead><tr><th>#</th><th>Operation</th><th>Qubits</th><th>Parameters</th></tr></thead>\n\
+             <tbody>\n",
+        );
+        for (index, op) in self.internal.iter().enumerate() {
+            let qubits = match op.involved_qubits() {
+                InvolvedQubits::All => "All".to_string(),
+                InvolvedQubits::None => "None".to_string(),
+                InvolvedQubits::Set(set) => {
+                    let mut qubits: Vec<usize> = set.into_iter().collect();
+                    qubits.sort_unstable();
+                    qubits
+                        .iter()
+                        .map(|qubit| qubit.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                }
+            };
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                index,
+                escape_html(op.hqslang()),
+                escape_html(&qubits),
+                escape_html(&operation_parameters(op)),
+            ));
+        }
+        html.push_str("</tbody>\n</table>");
+        html
+    }
+
     /// Return the __richcmp__ magic method to perform rich comparison operations on Circuit.
     ///
     /// Args:
@@ -560,15 +1364,37 @@ impl CircuitWrapper {
 
     /// Return a copy of the Operation at a certain index of the Circuit.
     ///
+    /// Negative indices count from the end of the Circuit. When a slice is given
+    /// a new Circuit with the selected operations is returned.
+    ///
     /// Args:
-    ///     index (int): The index of the Operation to get in the Circuit.
+    ///     index (Union[int, slice]): The index or slice of the Operations to get in the Circuit.
     ///
     /// Returns:
-    ///     Operation: The operation at the given index (if it exists).
+    ///     Union[Operation, Circuit]: The operation at the given index or the Circuit with the selected operations.
     ///
     /// Raises:
     ///     IndexError: Index out of range.
-    fn __getitem__(&self, index: usize) -> PyResult<PyObject> {
+    ///     TypeError: Index is neither an integer nor a slice.
+    fn __getitem__(&self, index: &Bound<PyAny>) -> PyResult<PyObject> {
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let indices = slice.indices(self.internal.len() as std::os::raw::c_long)?;
+            let circuit_slice: Circuit = (0..indices.slicelength)
+                .filter_map(|step| {
+                    self.internal
+                        .get((indices.start + step * indices.step) as usize)
+                        .cloned()
+                })
+                .collect();
+            return Ok(CircuitWrapper {
+                internal: circuit_slice,
+            }
+            .into_py(index.py()));
+        }
+        let index: isize = index
+            .extract()
+            .map_err(|_| PyTypeError::new_err("Index must be an integer or a slice"))?;
+        let index = self.normalize_index(index)?;
         let operation = self
             .internal
             .get(index)
@@ -579,6 +1405,8 @@ impl CircuitWrapper {
 
     /// Set an Operation at the specified index in the Circuit.
     ///
+    /// Negative indices count from the end of the Circuit.
+    ///
     /// Args:
     ///     index (int): The index of the Operation to set in the Circuit.
     ///     value (Operation): The Operation to set in the Circuit.
@@ -586,9 +1414,10 @@ impl CircuitWrapper {
     /// Raises:
     ///     TypeError: Cannot convert python object to Operation.
     ///     IndexError: Index out of range.
-    fn __setitem__(&mut self, index: usize, value: &Bound<PyAny>) -> PyResult<()> {
+    fn __setitem__(&mut self, index: isize, value: &Bound<PyAny>) -> PyResult<()> {
         let operation = convert_pyany_to_operation(value)
             .map_err(|_| PyTypeError::new_err("Cannot convert python object to Operation"))?;
+        let index = self.normalize_index(index)?;
         let mut_reference = self
             .internal
             .get_mut(index)
@@ -597,6 +1426,27 @@ impl CircuitWrapper {
         Ok(())
     }
 
+    /// Remove the Operation at the specified index from the Circuit.
+    ///
+    /// Negative indices count from the end of the Circuit.
+    ///
+    /// Args:
+    ///     index (int): The index of the Operation to remove from the Circuit.
+    ///
+    /// Raises:
+    ///     IndexError: Index out of range.
+    fn __delitem__(&mut self, index: isize) -> PyResult<()> {
+        let index = self.normalize_index(index)?;
+        self.internal = self
+            .internal
+            .iter()
+            .enumerate()
+            .filter(|(position, _)| *position != index)
+            .map(|(_, op)| op.clone())
+            .collect();
+        Ok(())
+    }
+
     /// Implement the `+=` (__iadd__) magic method to add a Operation to a Circuit.
     ///
     /// Args:
@@ -663,6 +1513,31 @@ impl CircuitWrapper {
     }
 }
 
+/// Validate the json representation of a Circuit against the JsonSchema of [roqoqo::Circuit].
+///
+/// Raises a ValueError if the input is not valid json and a RuntimeError listing
+/// the validation errors if the input does not match the schema.
+#[cfg(feature = "json_schema")]
+pub(crate) fn validate_circuit_json(input: &str) -> PyResult<()> {
+    let value: serde_json::Value = serde_json::from_str(input)
+        .map_err(|err| PyValueError::new_err(format!("Input is not valid json: {}", err)))?;
+    let schema = serde_json::to_value(schemars::schema_for!(Circuit))
+        .expect("Unexpected failure to serialize schema");
+    let compiled_schema = jsonschema::JSONSchema::options()
+        .with_draft(jsonschema::Draft::Draft7)
+        .compile(&schema)
+        .map_err(|err| PyRuntimeError::new_err(format!("Cannot compile JsonSchema: {}", err)))?;
+    let result = compiled_schema.validate(&value);
+    if let Err(errors) = result {
+        let messages: Vec<String> = errors.map(|err| err.to_string()).collect();
+        return Err(PyRuntimeError::new_err(format!(
+            "Circuit json does not match the JsonSchema: {}",
+            messages.join("; ")
+        )));
+    }
+    Ok(())
+}
+
 /// Convert generic python object to [roqoqo::Circuit].
 ///
 /// Fallible conversion of generic python object to [roqoqo::Circuit].
@@ -704,11 +1579,428 @@ pub fn convert_into_circuit(input: &Bound<PyAny>) -> Result<Circuit, QoqoError>
     // }
 }
 
+/// Return the bincode representation of a list of Circuits using the [bincode] crate.
+///
+/// The Circuits are serialized together as one length-prefixed sequence.
+///
+/// Args:
+///     circuits (List[Circuit]): The Circuits to serialize.
+///
+/// Returns:
+///     ByteArray: The serialized Circuits (in [bincode] form).
+///
+/// Raises:
+///     RuntimeError: Cannot serialize Circuits to bytes.
+#[pyfunction]
+pub fn circuits_to_bincode(circuits: Vec<CircuitWrapper>) -> PyResult<Py<PyByteArray>> {
+    let circuits: Vec<Circuit> = circuits.into_iter().map(|c| c.internal).collect();
+    let serialized = serialize(&circuits)
+        .map_err(|_| PyRuntimeError::new_err("Cannot serialize Circuits to bytes"))?;
+    let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+        PyByteArray::new_bound(py, &serialized[..]).into()
+    });
+    Ok(b)
+}
+
+/// Convert the bincode representation of a list of Circuits to Circuits using the [bincode] crate.
+///
+/// Args:
+///     data (ByteArray): The serialized Circuits (in [bincode] form) created by circuits_to_bincode.
+///
+/// Returns:
+///     List[Circuit]: The deserialized Circuits.
+///
+/// Raises:
+///     RuntimeError: Input cannot be deserialized to a list of Circuits.
+#[pyfunction]
+pub fn circuits_from_bincode(data: &Bound<PyByteArray>) -> PyResult<Vec<CircuitWrapper>> {
+    let bytes: Vec<u8> = data.to_vec();
+    let circuits: Vec<Circuit> = deserialize(&bytes[..]).map_err(|err| {
+        PyRuntimeError::new_err(format!(
+            "Input cannot be deserialized to a list of Circuits: {}",
+            err
+        ))
+    })?;
+    Ok(circuits
+        .into_iter()
+        .map(|internal| CircuitWrapper { internal })
+        .collect())
+}
+
+/// Collect the sorted names of all symbolic parameters used in the operations of a circuit.
+///
+/// Only the CalculatorFloat parameters of the operations are inspected, other string fields
+/// like readout names or annotations are never interpreted as expressions.
+pub(crate) fn symbolic_parameter_names(circuit: &Circuit) -> Vec<String> {
+    let mut names: BTreeSet<String> = BTreeSet::new();
+    collect_circuit_variables(circuit, &[], &mut names);
+    names.into_iter().collect()
+}
+
+/// Field names of serialized operations holding CalculatorFloat values.
+///
+/// The values can be single CalculatorFloats, lists of them (`k_vector`, `free_parameters` of CallDefinedGate)
+/// or maps with CalculatorFloat values (`sleep_times`).
+const CALCULATOR_FLOAT_FIELDS: &[&str] = &[
+    "theta",
+    "phi",
+    "alpha_r",
+    "alpha_i",
+    "beta_r",
+    "beta_i",
+    "global_phase",
+    "spherical_theta",
+    "spherical_phi",
+    "axis_x",
+    "axis_y",
+    "axis_z",
+    "delta",
+    "delta_real",
+    "delta_imag",
+    "t",
+    "t_real",
+    "t_imag",
+    "u",
+    "x",
+    "y",
+    "z",
+    "k_vector",
+    "free_parameters",
+    "displacement",
+    "phase",
+    "squeezing",
+    "rate",
+    "dephasing_rate",
+    "depolarising_rate",
+    "execution_time",
+    "gate_time",
+    "noise_coefficient",
+    "repetitions",
+    "sleep_time",
+    "sleep_times",
+    "time",
+];
+
+/// Field names of serialized operations holding a Circuit or an optional Circuit.
+const CIRCUIT_FIELDS: &[&str] = &["circuit", "circuit_before", "circuit_after"];
+
+/// Values given to unset variables while searching an expression for further variables.
+///
+/// Several values are tried in turn in case an expression cannot be evaluated for one of them.
+const PLACEHOLDER_VALUES: [f64; 3] = [
+    0.5772156649015329,
+    std::f64::consts::SQRT_2,
+    std::f64::consts::E,
+];
+
+/// Collect the variables of all operations in a circuit that are not bound.
+fn collect_circuit_variables(circuit: &Circuit, bound: &[String], names: &mut BTreeSet<String>) {
+    for op in circuit.iter() {
+        collect_operation_variables(op, bound, names);
+    }
+}
+
+/// Collect the variables of the CalculatorFloat parameters of an operation that are not bound.
+///
+/// The free parameters of a GateDefinition are bound inside its circuit and the time-dependent
+/// parameters of an ApplyTimeDependentSpinHamiltonian are bound by its values.
+fn collect_operation_variables(op: &Operation, bound: &[String], names: &mut BTreeSet<String>) {
+    match op {
+        Operation::GateDefinition(definition) => {
+            let mut bound = bound.to_vec();
+            bound.extend(definition.free_parameters().iter().cloned());
+            collect_circuit_variables(definition.circuit(), &bound, names);
+        }
+        Operation::PragmaAnnotatedOp(annotated) => {
+            collect_operation_variables(&annotated.operation, bound, names);
+        }
+        #[cfg(feature = "unstable_analog_operations")]
+        Operation::ApplyConstantSpinHamiltonian(analog) => {
+            collect_calculator_float_variables(&analog.time, bound, names);
+            for (_, value) in analog.hamiltonian.iter() {
+                collect_calculator_float_variables(value, bound, names);
+            }
+        }
+        #[cfg(feature = "unstable_analog_operations")]
+        Operation::ApplyTimeDependentSpinHamiltonian(analog) => {
+            let mut bound = bound.to_vec();
+            bound.extend(analog.values().keys().cloned());
+            for (_, value) in analog.hamiltonian().iter() {
+                collect_calculator_float_variables(value, &bound, names);
+            }
+        }
+        _ if op.is_parametrized() => {
+            if let Ok(serde_json::Value::Object(variant)) = serde_json::to_value(op) {
+                for fields in variant.values() {
+                    if let serde_json::Value::Object(fields) = fields {
+                        collect_field_variables(fields, bound, names);
+                    }
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Collect the variables of the CalculatorFloat and Circuit fields of a serialized operation.
+fn collect_field_variables(
+    fields: &serde_json::Map<String, serde_json::Value>,
+    bound: &[String],
+    names: &mut BTreeSet<String>,
+) {
+    for (key, value) in fields {
+        if CIRCUIT_FIELDS.contains(&key.as_str()) {
+            if let Ok(Some(circuit)) = serde_json::from_value::<Option<Circuit>>(value.clone()) {
+                collect_circuit_variables(&circuit, bound, names);
+            }
+        } else if CALCULATOR_FLOAT_FIELDS.contains(&key.as_str()) {
+            collect_serialized_calculator_floats(value, bound, names);
+        }
+    }
+}
+
+/// Collect the variables of serialized CalculatorFloats, lists of CalculatorFloats or maps of CalculatorFloats.
+fn collect_serialized_calculator_floats(
+    value: &serde_json::Value,
+    bound: &[String],
+    names: &mut BTreeSet<String>,
+) {
+    match value {
+        serde_json::Value::String(expression) => {
+            collect_calculator_float_variables(
+                &CalculatorFloat::from(expression.as_str()),
+                bound,
+                names,
+            );
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                collect_serialized_calculator_floats(value, bound, names);
+            }
+        }
+        serde_json::Value::Object(values) => {
+            for value in values.values() {
+                collect_serialized_calculator_floats(value, bound, names);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Collect the variables of a symbolic CalculatorFloat that are not bound.
+///
+/// The expression is evaluated with the qoqo_calculator parser. Every variable reported as not set
+/// is recorded and given a placeholder value until the whole expression evaluates.
+fn collect_calculator_float_variables(
+    value: &CalculatorFloat,
+    bound: &[String],
+    names: &mut BTreeSet<String>,
+) {
+    let expression = match value {
+        CalculatorFloat::Str(expression) => expression,
+        CalculatorFloat::Float(_) => return,
+    };
+    for placeholder in PLACEHOLDER_VALUES {
+        let mut calculator = Calculator::new();
+        let mut variables: Vec<String> = Vec::new();
+        let evaluated = loop {
+            match calculator.parse_str(expression) {
+                Ok(_) => break true,
+                Err(CalculatorError::VariableNotSet { name }) if !variables.contains(&name) => {
+                    calculator.set_variable(&name, placeholder);
+                    variables.push(name);
+                }
+                Err(_) => break false,
+            }
+        };
+        names.extend(variables.into_iter().filter(|name| !bound.contains(name)));
+        if evaluated {
+            return;
+        }
+    }
+}
+
+/// Field names of serialized operations that describe qubits rather than parameters.
+const QUBIT_FIELDS: &[&str] = &[
+    "qubit",
+    "qubits",
+    "control",
+    "control_0",
+    "control_1",
+    "target",
+    "controlling_qubit",
+    "controlling_qubits",
+];
+
+/// Format the non-qubit fields of an operation as a comma separated list of `name=value` pairs.
+fn operation_parameters(op: &Operation) -> String {
+    let fields = match serde_json::to_value(op) {
+        Ok(serde_json::Value::Object(map)) => map.into_iter().next().map(|(_, inner)| inner),
+        _ => None,
+    };
+    match fields {
+        Some(serde_json::Value::Object(fields)) => fields
+            .iter()
+            .filter(|(name, _)| !QUBIT_FIELDS.contains(&name.as_str()))
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => String::new(),
+    }
+}
+
+/// Escape the characters of a string that have a special meaning in HTML.
+fn escape_html(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Convert a gate operation to the corresponding Pennylane operation.
+pub(crate) fn pennylane_operation<'py>(
+    qml: &Bound<'py, PyModule>,
+    operation: &Operation,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = qml.py();
+    let (name, parameters, wires): (&str, Vec<&CalculatorFloat>, Vec<usize>) = match operation {
+        Operation::Identity(op) => ("Identity", vec![], vec![*op.qubit()]),
+        Operation::Hadamard(op) => ("Hadamard", vec![], vec![*op.qubit()]),
+        Operation::PauliX(op) => ("PauliX", vec![], vec![*op.qubit()]),
+        Operation::PauliY(op) => ("PauliY", vec![], vec![*op.qubit()]),
+        Operation::PauliZ(op) => ("PauliZ", vec![], vec![*op.qubit()]),
+        Operation::RotateX(op) => ("RX", vec![op.theta()], vec![*op.qubit()]),
+        Operation::RotateY(op) => ("RY", vec![op.theta()], vec![*op.qubit()]),
+        Operation::RotateZ(op) => ("RZ", vec![op.theta()], vec![*op.qubit()]),
+        Operation::PhaseShiftState1(op) => ("PhaseShift", vec![op.theta()], vec![*op.qubit()]),
+        Operation::CNOT(op) => ("CNOT", vec![], vec![*op.control(), *op.target()]),
+        Operation::ControlledPauliY(op) => ("CY", vec![], vec![*op.control(), *op.target()]),
+        Operation::ControlledPauliZ(op) => ("CZ", vec![], vec![*op.control(), *op.target()]),
+        Operation::SWAP(op) => ("SWAP", vec![], vec![*op.control(), *op.target()]),
+        Operation::ControlledPhaseShift(op) => (
+            "ControlledPhaseShift",
+            vec![op.theta()],
+            vec![*op.control(), *op.target()],
+        ),
+        Operation::Toffoli(op) => (
+            "Toffoli",
+            vec![],
+            vec![*op.control_0(), *op.control_1(), *op.target()],
+        ),
+        _ => return pennylane_qubit_unitary(qml, operation),
+    };
+    let parameters = parameters
+        .into_iter()
+        .map(|parameter| {
+            parameter.float().copied().map_err(|_| {
+                PyValueError::new_err(format!(
+                    "Symbolic parameter {} of {} cannot be converted to Pennylane",
+                    parameter,
+                    operation.hqslang()
+                ))
+            })
+        })
+        .collect::<PyResult<Vec<f64>>>()?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("wires", wires)?;
+    qml.getattr(name)?
+        .call(PyTuple::new_bound(py, parameters), Some(&kwargs))
+}
+
+/// Convert a single- or two-qubit gate without a named Pennylane equivalent to a Pennylane QubitUnitary.
+fn pennylane_qubit_unitary<'py>(
+    qml: &Bound<'py, PyModule>,
+    operation: &Operation,
+) -> PyResult<Bound<'py, PyAny>> {
+    let (unitary, wires) = if let Ok(gate) = SingleQubitGateOperation::try_from(operation.clone()) {
+        (gate.unitary_matrix(), vec![*gate.qubit()])
+    } else if let Ok(gate) = TwoQubitGateOperation::try_from(operation.clone()) {
+        (gate.unitary_matrix(), vec![*gate.control(), *gate.target()])
+    } else {
+        return Err(PyValueError::new_err(format!(
+            "Operation {} cannot be converted to a Pennylane operation",
+            operation.hqslang()
+        )));
+    };
+    let unitary = unitary.map_err(|err| {
+        PyValueError::new_err(format!(
+            "Unitary matrix of {} cannot be computed: {:?}",
+            operation.hqslang(),
+            err
+        ))
+    })?;
+    qml.call_method1("QubitUnitary", (unitary.to_pyarray_bound(qml.py()), wires))
+}
+
+/// Convert a measurement operation to the corresponding Pennylane measurement.
+///
+/// Returns `None` for operations that are not converted to measurements.
+fn pennylane_measurement<'py>(
+    qml: &Bound<'py, PyModule>,
+    operation: &Operation,
+    circuit_qubits: &[usize],
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = qml.py();
+    let no_measurement_circuit = |circuit: &Option<Circuit>| -> PyResult<()> {
+        match circuit {
+            Some(_) => Err(PyValueError::new_err(format!(
+                "{} with a measurement circuit cannot be converted to Pennylane",
+                operation.hqslang()
+            ))),
+            None => Ok(()),
+        }
+    };
+    let wires_kwargs = |wires: Vec<usize>| -> PyResult<Bound<'py, PyDict>> {
+        let kwargs = PyDict::new_bound(py);
+        kwargs.set_item("wires", wires)?;
+        Ok(kwargs)
+    };
+    let measurement = match operation {
+        Operation::MeasureQubit(op) => qml
+            .getattr("sample")?
+            .call((), Some(&wires_kwargs(vec![*op.qubit()])?))?,
+        Operation::PragmaRepeatedMeasurement(op) => match op.qubit_mapping() {
+            Some(mapping) => {
+                let mut wires: Vec<(usize, usize)> = mapping
+                    .iter()
+                    .map(|(qubit, index)| (*index, *qubit))
+                    .collect();
+                wires.sort();
+                let wires: Vec<usize> = wires.into_iter().map(|(_, qubit)| qubit).collect();
+                qml.getattr("sample")?
+                    .call((), Some(&wires_kwargs(wires)?))?
+            }
+            None => qml.call_method0("sample")?,
+        },
+        Operation::PragmaGetStateVector(op) => {
+            no_measurement_circuit(op.circuit())?;
+            qml.call_method0("state")?
+        }
+        Operation::PragmaGetDensityMatrix(op) => {
+            no_measurement_circuit(op.circuit())?;
+            qml.getattr("density_matrix")?
+                .call((), Some(&wires_kwargs(circuit_qubits.to_vec())?))?
+        }
+        Operation::PragmaGetReducedDensityMatrix(op) => {
+            no_measurement_circuit(op.circuit())?;
+            qml.getattr("density_matrix")?
+                .call((), Some(&wires_kwargs(op.qubits().clone())?))?
+        }
+        Operation::PragmaGetOccupationProbability(op) => {
+            no_measurement_circuit(op.circuit())?;
+            qml.call_method0("probs")?
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(measurement))
+}
+
 /// Iterator for iterating over Operations in a Circuit.
 #[pyclass(name = "OperationIterator", module = "qoqo")]
 #[derive(Debug)]
 pub struct OperationIteratorWrapper {
-    internal: OperationIterator,
+    pub(crate) internal: OperationIterator,
 }
 
 #[pymethods]
@@ -733,4 +2025,12 @@ impl OperationIteratorWrapper {
             .next()
             .map(|op| convert_operation_to_pyobject(op).unwrap())
     }
+
+    /// Return the number of operations remaining in the iterator.
+    ///
+    /// Returns:
+    ///     int: The number of operations that have not been consumed yet.
+    fn __length_hint__(&self) -> usize {
+        self.internal.len()
+    }
 }
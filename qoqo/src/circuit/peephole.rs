@@ -0,0 +1,114 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Peephole optimization of circuits
+
+use roqoqo::operations::*;
+use roqoqo::Circuit;
+
+/// Lookup table of known simplifications of consecutive single-qubit gates acting on the same qubit.
+///
+/// Each entry maps a sequence of hqslang names to the (shorter) sequence of gates
+/// with the identical unitary matrix.
+const PEEPHOLE_TABLE: &[(&[&str], &[&str])] = &[
+    (&["Hadamard", "PauliZ", "Hadamard"], &["PauliX"]),
+    (&["Hadamard", "PauliX", "Hadamard"], &["PauliZ"]),
+    (&["TGate", "TGate"], &["SGate"]),
+    (&["SGate", "SGate"], &["PauliZ"]),
+    (&["Hadamard", "Hadamard"], &[]),
+    (&["PauliX", "PauliX"], &[]),
+    (&["PauliY", "PauliY"], &[]),
+    (&["PauliZ", "PauliZ"], &[]),
+];
+
+/// Applies a peephole optimization pass to a circuit.
+///
+/// A window of up to `window_size` consecutive operations is slid across the operations of the circuit.
+/// When the operations in the window are single-qubit gates acting on the same qubit and match an entry
+/// of the lookup table, they are replaced with the simplified sequence. Larger windows are tried first
+/// and replaced operations are not examined again in the same pass.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit that is optimized.
+/// * `window_size` - The maximum number of consecutive operations that are replaced at once.
+///
+/// # Returns
+///
+/// * `Circuit` - The optimized circuit.
+pub(crate) fn peephole_optimization(circuit: &Circuit, window_size: usize) -> Circuit {
+    let mut optimized_circuit = Circuit::new();
+    for definition in circuit.definitions() {
+        optimized_circuit.add_operation(definition.clone());
+    }
+    let operations = circuit.operations();
+    let mut index: usize = 0;
+    while index < operations.len() {
+        let max_window = window_size.min(operations.len() - index);
+        let mut replaced = false;
+        for size in (2..=max_window).rev() {
+            if let Some(replacement) = find_replacement(&operations[index..index + size]) {
+                for op in replacement {
+                    optimized_circuit.add_operation(op);
+                }
+                index += size;
+                replaced = true;
+                break;
+            }
+        }
+        if !replaced {
+            optimized_circuit.add_operation(operations[index].clone());
+            index += 1;
+        }
+    }
+    optimized_circuit
+}
+
+// Returns the replacement operations if the window matches an entry of the lookup table.
+fn find_replacement(window: &[Operation]) -> Option<Vec<Operation>> {
+    let qubit = single_qubit(&window[0])?;
+    if window[1..].iter().any(|op| single_qubit(op) != Some(qubit)) {
+        return None;
+    }
+    let (_, replacement) = PEEPHOLE_TABLE.iter().find(|(pattern, _)| {
+        pattern.len() == window.len()
+            && pattern
+                .iter()
+                .zip(window.iter())
+                .all(|(name, op)| *name == op.hqslang())
+    })?;
+    replacement
+        .iter()
+        .map(|name| create_gate(name, qubit))
+        .collect()
+}
+
+// Returns the qubit an operation acts on if it acts on exactly one qubit.
+fn single_qubit(operation: &Operation) -> Option<usize> {
+    match operation.involved_qubits() {
+        InvolvedQubits::Set(qubits) if qubits.len() == 1 => qubits.into_iter().next(),
+        _ => None,
+    }
+}
+
+// Creates the gate operation with the given hqslang name acting on the qubit.
+fn create_gate(name: &str, qubit: usize) -> Option<Operation> {
+    match name {
+        "Hadamard" => Some(Hadamard::new(qubit).into()),
+        "PauliX" => Some(PauliX::new(qubit).into()),
+        "PauliY" => Some(PauliY::new(qubit).into()),
+        "PauliZ" => Some(PauliZ::new(qubit).into()),
+        "SGate" => Some(SGate::new(qubit).into()),
+        "TGate" => Some(TGate::new(qubit).into()),
+        _ => None,
+    }
+}
@@ -17,13 +17,13 @@ use std::collections::HashSet;
 
 use crate::{QoqoError, QOQO_VERSION};
 use bincode::{deserialize, serialize};
-use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::exceptions::{PyIndexError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use roqoqo::{Circuit, CircuitDag, ROQOQO_VERSION};
 
 use crate::operations::{convert_operation_to_pyobject, convert_pyany_to_operation};
-use crate::CircuitWrapper;
+use crate::{CircuitWrapper, OperationIteratorWrapper};
 
 /// Module containing the CircuitDag class that represents the Directed Acyclic Graph (DAG)
 /// of a quantum circuit in qoqo.
@@ -105,9 +105,14 @@ impl CircuitDagWrapper {
     ///
     /// Returns:
     ///     self: The new CircuitDag.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to Circuit.
+    #[staticmethod]
     #[pyo3(text_signature = "(circuit)")]
-    pub fn from_circuit(&self, circuit: &Bound<PyAny>) -> PyResult<Self> {
-        let circuit = crate::convert_into_circuit(circuit).unwrap();
+    pub fn from_circuit(circuit: &Bound<PyAny>) -> PyResult<Self> {
+        let circuit = crate::convert_into_circuit(circuit)
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to Circuit"))?;
         Ok(Self {
             internal: CircuitDag::from(circuit),
         })
@@ -115,11 +120,24 @@ impl CircuitDagWrapper {
 
     /// Transforms the CircuitDag into a Circuit.
     ///
+    /// The Operations are added to the Circuit in topological order.
+    ///
+    /// Returns:
+    ///     Circuit: The Circuit containing the Operations of the CircuitDag.
+    ///
+    /// Raises:
+    ///     RuntimeError: The CircuitDag contains a cycle.
     #[pyo3(text_signature = "($self)")]
     pub fn to_circuit(&self) -> PyResult<CircuitWrapper> {
-        Ok(CircuitWrapper {
-            internal: Circuit::from(self.internal.clone()),
-        })
+        let mut circuit = Circuit::new();
+        for operation in self
+            .internal
+            .topological_sort()
+            .map_err(|err| PyRuntimeError::new_err(format!("{:?}", err)))?
+        {
+            circuit.add_operation(operation.clone());
+        }
+        Ok(CircuitWrapper { internal: circuit })
     }
 
     /// Add an Operation to the back of the CircuitDag, if necessary.
@@ -236,6 +254,40 @@ impl CircuitDagWrapper {
         par_bl_vec
     }
 
+    /// Returns the Operations of the CircuitDag in topological order.
+    ///
+    /// Each Operation is placed after all Operations it depends on.
+    ///
+    /// Returns:
+    ///     List[Operation]: The Operations in topological order.
+    ///
+    /// Raises:
+    ///     RuntimeError: The CircuitDag contains a cycle.
+    #[pyo3(text_signature = "($self)")]
+    pub fn topological_sort(&self) -> PyResult<Vec<PyObject>> {
+        self.internal
+            .topological_sort()
+            .map_err(|err| PyRuntimeError::new_err(format!("{:?}", err)))?
+            .into_iter()
+            .cloned()
+            .map(convert_operation_to_pyobject)
+            .collect()
+    }
+
+    /// Returns the Operations of the parallel blocks that can be executed simultaneously.
+    ///
+    /// Same as `parallel_blocks` but returning the Operations instead of their node indices.
+    ///
+    /// Returns:
+    ///     List[List[Operation]]: The Operations of each parallel block in execution order.
+    #[pyo3(text_signature = "($self)")]
+    pub fn parallel_operation_blocks(&self) -> PyResult<Vec<Vec<PyObject>>> {
+        self.parallel_blocks()
+            .into_iter()
+            .map(|block| block.into_iter().map(|index| self.get(index)).collect())
+            .collect()
+    }
+
     /// Given a NodeIndex, returns the Operation contained in the node of
     /// the CircuitDag.
     ///
@@ -257,6 +309,19 @@ impl CircuitDagWrapper {
         convert_operation_to_pyobject(operation)
     }
 
+    /// Create an iterator over the Operations of the CircuitDag in topological order.
+    ///
+    /// Returns:
+    ///     OperationIterator: The Operations of the CircuitDag in iterator form.
+    ///
+    /// Raises:
+    ///     RuntimeError: The CircuitDag contains a cycle.
+    fn __iter__(&self) -> PyResult<OperationIteratorWrapper> {
+        Ok(OperationIteratorWrapper {
+            internal: self.to_circuit()?.internal.into_iter(),
+        })
+    }
+
     /// Returns a copy of the CircuitDag (produces a deepcopy).
     ///
     /// Returns:
@@ -368,6 +433,24 @@ impl CircuitDagWrapper {
         self.internal.successors(node).map(|n| n.index()).collect()
     }
 
+    /// Returns the number of nodes (Operations) in the CircuitDag.
+    ///
+    /// Returns:
+    ///     int: The number of nodes.
+    #[pyo3(text_signature = "($self)")]
+    pub fn node_count(&self) -> usize {
+        self.internal.node_count()
+    }
+
+    /// Returns the number of edges (dependencies between Operations) in the CircuitDag.
+    ///
+    /// Returns:
+    ///     int: The number of edges.
+    #[pyo3(text_signature = "($self)")]
+    pub fn edge_count(&self) -> usize {
+        self.internal.edge_count()
+    }
+
     /// Returns the list of nodes of commuting operations in CircuitDag.
     ///
     /// Returns:
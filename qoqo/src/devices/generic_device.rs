@@ -48,6 +48,60 @@ impl GenericDeviceWrapper {
         })
     }
 
+    /// Return a copy of the device with the gate time of a single qubit gate set.
+    ///
+    /// Args:
+    ///     gate (str): hqslang name of the single-qubit-gate.
+    ///     qubit (int): The qubit for which the gate time is set.
+    ///     gate_time (float): The gate time for the given gate.
+    ///
+    /// Returns:
+    ///     GenericDevice: The device with the added gate.
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device
+    #[pyo3(text_signature = "(gate, qubit, gate_time)")]
+    pub fn add_single_qubit_gate(
+        &self,
+        gate: &str,
+        qubit: usize,
+        gate_time: f64,
+    ) -> PyResult<Self> {
+        let mut internal = self.internal.clone();
+        internal
+            .set_single_qubit_gate_time(gate, qubit, gate_time)
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Return a copy of the device with the gate time of a two qubit gate set.
+    ///
+    /// Args:
+    ///     gate (str): hqslang name of the two-qubit-gate.
+    ///     control (int): The control qubit for which the gate time is set.
+    ///     target (int): The target qubit for which the gate time is set.
+    ///     gate_time (float): The gate time for the given gate.
+    ///
+    /// Returns:
+    ///     GenericDevice: The device with the added gate.
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device
+    #[pyo3(text_signature = "(gate, control, target, gate_time)")]
+    pub fn add_two_qubit_gate(
+        &self,
+        gate: &str,
+        control: usize,
+        target: usize,
+        gate_time: f64,
+    ) -> PyResult<Self> {
+        let mut internal = self.internal.clone();
+        internal
+            .set_two_qubit_gate_time(gate, control, target, gate_time)
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        Ok(Self { internal })
+    }
+
     #[cfg(feature = "json_schema")]
     /// Return the JsonSchema for the json serialisation of the class.
     ///
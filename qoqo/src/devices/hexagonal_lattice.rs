@@ -0,0 +1,358 @@
+// Copyright © 2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use super::GenericDeviceWrapper;
+use bincode::{deserialize, serialize};
+use ndarray::Array2;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyByteArray;
+use qoqo_macros::devicewrapper;
+use roqoqo::devices::{Device, HexagonalLatticeDevice};
+#[cfg(feature = "json_schema")]
+use roqoqo::{operations::SupportedVersion, ROQOQO_VERSION};
+
+/// A hexagonal (honeycomb) lattice device where each qubit is linked to its three nearest neighbours.
+///
+/// The qubits are indexed in row-major format and the honeycomb is laid out as a brick wall: qubit (row, column)
+/// is linked to (row, column + 1) and, if row + column is even, to (row + 1, column).
+/// Qubits on the edge of the lattice have fewer neighbours.
+///
+/// Args:
+///     number_rows (int): The fixed number of rows in device.
+///     number_columns (int): Fixed number of columns in device.
+///     offset_rows (bool): Whether the vertical links between rows are shifted by one column,
+///         linking (row, column) to (row + 1, column) if row + column is odd.
+///     single_qubit_gates (List[str]): A list of 'hqslang' names of single-qubit-gates supported by the device.
+///     two_qubit_gates (List[str]): A list of 'hqslang' names of basic two-qubit-gates supported by the device.
+///     default_gate_time (float): The default startig gate time.
+#[pyclass(name = "HexagonalLatticeDevice", module = "devices")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexagonalLatticeDeviceWrapper {
+    /// Internal storage of [roqoqo::devices::HexagonalLatticeDevice]
+    pub internal: HexagonalLatticeDevice,
+}
+
+#[devicewrapper]
+impl HexagonalLatticeDeviceWrapper {
+    /// Create new HexagonalLatticeDevice device
+    ///
+    /// Args:
+    ///     number_rows (int): The fixed number of rows in device.
+    ///     number_columns (int): Fixed number of columns in device.
+    ///     offset_rows (bool): Whether the vertical links between rows are shifted by one column.
+    ///     single_qubit_gates (List[str]): A list of 'hqslang' names of single-qubit-gates supported by the device.
+    ///     two_qubit_gates (List[str]): A list of 'hqslang' names of basic two-qubit-gates supported by the device.
+    ///     default_gate_time (float): The default startig gate time.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    #[new]
+    #[pyo3(
+        text_signature = "(number_rows, number_columns, offset_rows, single_qubit_gates, two_qubit_gates, default_gate_time)"
+    )]
+    pub fn new(
+        number_rows: usize,
+        number_columns: usize,
+        offset_rows: bool,
+        single_qubit_gates: Vec<String>,
+        two_qubit_gates: Vec<String>,
+        default_gate_time: f64,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            internal: HexagonalLatticeDevice::new(
+                number_rows,
+                number_columns,
+                offset_rows,
+                &single_qubit_gates,
+                &two_qubit_gates,
+                default_gate_time,
+            ),
+        })
+    }
+
+    /// Return the number of rows in device.
+    ///
+    /// Returns:
+    ///     int: The number of rows.
+    ///
+    pub fn number_rows(&self) -> usize {
+        self.internal.number_rows()
+    }
+
+    /// Return number of columns in device.
+    ///
+    /// Returns:
+    ///     int: The number of columns.
+    ///
+    pub fn number_columns(&self) -> usize {
+        self.internal.number_columns()
+    }
+
+    /// Return whether the vertical links between rows of the device are shifted by one column.
+    ///
+    /// Returns:
+    ///     bool: True if (row, column) is linked to (row + 1, column) for odd row + column.
+    ///
+    pub fn offset_rows(&self) -> bool {
+        self.internal.offset_rows()
+    }
+
+    /// Return the (row, column) coordinates of a qubit in the hexagonal lattice.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the coordinates are returned.
+    ///
+    /// Returns:
+    ///     Tuple[int, int]: The row and column of the qubit.
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, /)")]
+    pub fn qubit_to_hex_coordinates(&self, qubit: usize) -> PyResult<(usize, usize)> {
+        self.check_qubit_in_device(qubit)?;
+        self.internal
+            .qubit_to_hex_coordinates(qubit)
+            .ok_or_else(|| PyValueError::new_err(format!("Qubit {} is not in device", qubit)))
+    }
+
+    /// Set gate time of all two-qubit gates of specific type
+    ///
+    /// Args:
+    ///     gate (str): The hqslang name of the two-qubit-gate.
+    ///     gate_time (float): Gate time for the given gate, valid for all qubits in the device.
+    ///
+    /// Returns:
+    ///     A qoqo Device with updated gate times.
+    ///
+    #[pyo3(text_signature = "(gate, gate_time, /)")]
+    pub fn set_all_two_qubit_gate_times(&self, gate: &str, gate_time: f64) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .set_all_two_qubit_gate_times(gate, gate_time),
+        }
+    }
+
+    /// Set gate time of all single-qubit gates of specific type
+    ///
+    /// Args:
+    ///     gate (str): The hqslang name of the single-qubit-gate.
+    ///     gate_time (float): New gate time.
+    ///
+    /// Returns:
+    ///     A qoqo Device with updated gate times.
+    ///
+    #[pyo3(text_signature = "(gate, gate_time, /)")]
+    pub fn set_all_single_qubit_gate_times(&self, gate: &str, gate_time: f64) -> Self {
+        Self {
+            internal: self
+                .internal
+                .clone()
+                .set_all_single_qubit_gate_times(gate, gate_time),
+        }
+    }
+
+    /// Set the decoherence rates for all qubits in the HexagonalLatticeDevice device.
+    ///
+    /// Args:
+    ///     rates (2darray):: Decoherence rates provided as (3x3)-matrix for all qubits in the device.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: The input parameter `rates` needs to be a (3x3)-matrix.
+    #[pyo3(text_signature = "(rates, /)")]
+    pub fn set_all_qubit_decoherence_rates(&self, rates: PyReadonlyArray2<f64>) -> PyResult<Self> {
+        let rates_matrix = rates.as_array().to_owned();
+        Ok(Self {
+            internal: self
+                .internal
+                .clone()
+                .set_all_qubit_decoherence_rates(rates_matrix)
+                .map_err(|_| {
+                    PyValueError::new_err("The input parameter `rates` needs to be a (3x3)-matrix.")
+                })?,
+        })
+    }
+
+    /// Adds qubit damping to noise rates.
+    ///
+    /// Args:
+    ///     damping (float): The damping rates.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    #[pyo3(text_signature = "(damping, /)")]
+    pub fn add_damping_all(&mut self, damping: f64) -> Self {
+        Self {
+            internal: self.internal.clone().add_damping_all(damping),
+        }
+    }
+
+    /// Adds qubit dephasing to noise rates.
+    ///
+    /// Args:
+    ///     dephasing (float): The dephasing rates.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    #[pyo3(text_signature = "(dephasing, /)")]
+    pub fn add_dephasing_all(&mut self, dephasing: f64) -> Self {
+        Self {
+            internal: self.internal.clone().add_dephasing_all(dephasing),
+        }
+    }
+
+    /// Adds qubit depolarising to noise rates.
+    ///
+    /// Args:
+    ///     depolarising (float): The depolarising rates.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    #[pyo3(text_signature = "(depolarising, /)")]
+    pub fn add_depolarising_all(&mut self, depolarising: f64) -> Self {
+        Self {
+            internal: self.internal.clone().add_depolarising_all(depolarising),
+        }
+    }
+
+    /// Adds damping to the noise rates of a single qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the damping is added.
+    ///     damping (float): The damping rates.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, damping, /)")]
+    pub fn add_damping_to_qubit(&self, qubit: usize, damping: f64) -> PyResult<Self> {
+        self.check_qubit_in_device(qubit)?;
+        let mut internal = self.internal.clone();
+        internal
+            .add_damping(qubit, damping)
+            .map_err(|err| PyValueError::new_err(format!("Cannot add decoherence: {}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Adds dephasing to the noise rates of a single qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the dephasing is added.
+    ///     dephasing (float): The dephasing rates.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, dephasing, /)")]
+    pub fn add_dephasing_to_qubit(&self, qubit: usize, dephasing: f64) -> PyResult<Self> {
+        self.check_qubit_in_device(qubit)?;
+        let mut internal = self.internal.clone();
+        internal
+            .add_dephasing(qubit, dephasing)
+            .map_err(|err| PyValueError::new_err(format!("Cannot add decoherence: {}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Adds depolarising to the noise rates of a single qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the depolarising is added.
+    ///     depolarising (float): The depolarising rates.
+    ///
+    /// Returns:
+    ///     HexagonalLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, depolarising, /)")]
+    pub fn add_depolarising_to_qubit(&self, qubit: usize, depolarising: f64) -> PyResult<Self> {
+        self.check_qubit_in_device(qubit)?;
+        let mut internal = self.internal.clone();
+        internal
+            .add_depolarising(qubit, depolarising)
+            .map_err(|err| PyValueError::new_err(format!("Cannot add decoherence: {}", err)))?;
+        Ok(Self { internal })
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(HexagonalLatticeDevice);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            HexagonalLatticeDevice::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
+impl HexagonalLatticeDeviceWrapper {
+    /// Fallible conversion of generic python object.
+    pub fn from_pyany(input: &Bound<PyAny>) -> PyResult<HexagonalLatticeDevice> {
+        if let Ok(try_downcast) = input.extract::<HexagonalLatticeDeviceWrapper>() {
+            Ok(try_downcast.internal)
+        } else {
+            let get_bytes = input.call_method0("to_bincode")?;
+            let bytes = get_bytes.extract::<Vec<u8>>()?;
+            deserialize(&bytes[..]).map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Cannot treat input as HexagonalLatticeDevice: {}",
+                    err
+                ))
+            })
+        }
+    }
+
+    /// Return an error if the qubit is not part of the device.
+    fn check_qubit_in_device(&self, qubit: usize) -> PyResult<()> {
+        let number_qubits = self.internal.number_qubits();
+        if qubit >= number_qubits {
+            return Err(PyValueError::new_err(format!(
+                "Qubit {} out of range for device of size {}",
+                qubit, number_qubits
+            )));
+        }
+        Ok(())
+    }
+}
@@ -21,7 +21,14 @@
 #[cfg(feature = "unstable_chain_with_environment")]
 use std::collections::HashMap;
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use roqoqo::devices::Device;
+use roqoqo::operations::{
+    MultiQubitGateOperation, Operate, OperateMultiQubit, OperateSingleQubit, OperateThreeQubit,
+    OperateTwoQubit, SingleQubitGateOperation, ThreeQubitGateOperation, TwoQubitGateOperation,
+};
+use roqoqo::Circuit;
 
 mod square_lattice;
 #[cfg(feature = "unstable_chain_with_environment")]
@@ -31,6 +38,10 @@ mod generic_device;
 pub use generic_device::GenericDeviceWrapper;
 mod all_to_all;
 pub use all_to_all::AllToAllDeviceWrapper;
+mod hexagonal_lattice;
+pub use hexagonal_lattice::HexagonalLatticeDeviceWrapper;
+mod linear_chain;
+pub use linear_chain::LinearChainDeviceWrapper;
 
 #[cfg(feature = "unstable_chain_with_environment")]
 /// A wrapper around a python object that implements the ChainWithEnvironment trait.
@@ -83,6 +94,100 @@ impl ChainWithEnvironmentDevice for ChainWithEnvironmentCapsule {
     }
 }
 
+/// Checks that all gate operations of a circuit can be executed on a device.
+///
+/// Non-gate operations like measurements, definitions and PRAGMAs are not checked.
+///
+/// # Arguments
+///
+/// * `device` - The device the circuit should be executed on.
+/// * `circuit` - The circuit that is validated.
+///
+/// # Returns
+///
+/// * `Ok(true)` - All gate operations are available on the device
+/// * `Err(PyValueError)` - At least one gate operation is not available, the message lists all offending operations
+pub(crate) fn validate_circuit_on_device<T: Device>(
+    device: &T,
+    circuit: &Circuit,
+) -> PyResult<bool> {
+    let mut errors: Vec<String> = Vec::new();
+    for operation in circuit.iter() {
+        let hqslang = operation.hqslang();
+        let (qubits, supported, available) =
+            if let Ok(gate) = SingleQubitGateOperation::try_from(operation) {
+                (
+                    vec![*gate.qubit()],
+                    device
+                        .single_qubit_gate_names()
+                        .iter()
+                        .any(|name| name == hqslang),
+                    device
+                        .single_qubit_gate_time(hqslang, gate.qubit())
+                        .is_some(),
+                )
+            } else if let Ok(gate) = TwoQubitGateOperation::try_from(operation) {
+                (
+                    vec![*gate.control(), *gate.target()],
+                    device
+                        .two_qubit_gate_names()
+                        .iter()
+                        .any(|name| name == hqslang),
+                    device
+                        .two_qubit_gate_time(hqslang, gate.control(), gate.target())
+                        .is_some(),
+                )
+            } else if let Ok(gate) = ThreeQubitGateOperation::try_from(operation) {
+                // Devices do not expose a list of supported three qubit gates
+                (
+                    vec![*gate.control_0(), *gate.control_1(), *gate.target()],
+                    true,
+                    device
+                        .three_qubit_gate_time(
+                            hqslang,
+                            gate.control_0(),
+                            gate.control_1(),
+                            gate.target(),
+                        )
+                        .is_some(),
+                )
+            } else if let Ok(gate) = MultiQubitGateOperation::try_from(operation) {
+                (
+                    gate.qubits().clone(),
+                    device
+                        .multi_qubit_gate_names()
+                        .iter()
+                        .any(|name| name == hqslang),
+                    device
+                        .multi_qubit_gate_time(hqslang, gate.qubits())
+                        .is_some(),
+                )
+            } else {
+                continue;
+            };
+        let reason = if !supported {
+            "gate is not supported by the device"
+        } else if available {
+            continue;
+        } else if qubits.iter().any(|qubit| *qubit >= device.number_qubits()) {
+            "qubit is not in the device"
+        } else if qubits.len() == 2 {
+            "qubits are not connected"
+        } else {
+            "gate is not available on these qubits"
+        };
+        errors.push(format!("{} on qubits {:?}: {}", hqslang, qubits, reason));
+    }
+    if errors.is_empty() {
+        Ok(true)
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Circuit cannot be executed on device:\n{}",
+            errors.join("\n")
+        )))
+    }
+}
+
 /// Devices in qoqo have two use cases:
 ///
 /// * Abstract devices: Contain abstract information for the model of a quantum computer and its parameters.
@@ -114,6 +219,8 @@ impl ChainWithEnvironmentDevice for ChainWithEnvironmentCapsule {
 ///     
 ///     AllToAllDevice
 ///     GenericDevice
+///     HexagonalLatticeDevice
+///     LinearChainDevice
 ///     SquareLatticeDevice
 
 #[pymodule]
@@ -121,5 +228,7 @@ pub fn devices(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<AllToAllDeviceWrapper>()?;
     module.add_class::<GenericDeviceWrapper>()?;
     module.add_class::<SquareLatticeDeviceWrapper>()?;
+    module.add_class::<HexagonalLatticeDeviceWrapper>()?;
+    module.add_class::<LinearChainDeviceWrapper>()?;
     Ok(())
 }
@@ -195,6 +195,69 @@ impl SquareLatticeDeviceWrapper {
         }
     }
 
+    /// Adds damping to the noise rates of a single qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the damping is added.
+    ///     damping (float): The damping rates.
+    ///
+    /// Returns:
+    ///     SquareLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, damping, /)")]
+    pub fn add_damping_to_qubit(&self, qubit: usize, damping: f64) -> PyResult<Self> {
+        self.check_qubit_in_device(qubit)?;
+        let mut internal = self.internal.clone();
+        internal
+            .add_damping(qubit, damping)
+            .map_err(|err| PyValueError::new_err(format!("Cannot add decoherence: {}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Adds dephasing to the noise rates of a single qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the dephasing is added.
+    ///     dephasing (float): The dephasing rates.
+    ///
+    /// Returns:
+    ///     SquareLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, dephasing, /)")]
+    pub fn add_dephasing_to_qubit(&self, qubit: usize, dephasing: f64) -> PyResult<Self> {
+        self.check_qubit_in_device(qubit)?;
+        let mut internal = self.internal.clone();
+        internal
+            .add_dephasing(qubit, dephasing)
+            .map_err(|err| PyValueError::new_err(format!("Cannot add decoherence: {}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Adds depolarising to the noise rates of a single qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit for which the depolarising is added.
+    ///     depolarising (float): The depolarising rates.
+    ///
+    /// Returns:
+    ///     SquareLatticeDevice
+    ///
+    /// Raises:
+    ///     PyValueError: Qubit is not in device.
+    #[pyo3(text_signature = "(qubit, depolarising, /)")]
+    pub fn add_depolarising_to_qubit(&self, qubit: usize, depolarising: f64) -> PyResult<Self> {
+        self.check_qubit_in_device(qubit)?;
+        let mut internal = self.internal.clone();
+        internal
+            .add_depolarising(qubit, depolarising)
+            .map_err(|err| PyValueError::new_err(format!("Cannot add decoherence: {}", err)))?;
+        Ok(Self { internal })
+    }
+
     #[cfg(feature = "json_schema")]
     /// Return the JsonSchema for the json serialisation of the class.
     ///
@@ -244,4 +307,16 @@ impl SquareLatticeDeviceWrapper {
             })
         }
     }
+
+    /// Return an error if the qubit is not part of the device.
+    fn check_qubit_in_device(&self, qubit: usize) -> PyResult<()> {
+        let number_qubits = self.internal.number_qubits();
+        if qubit >= number_qubits {
+            return Err(PyValueError::new_err(format!(
+                "Qubit {} out of range for device of size {}",
+                qubit, number_qubits
+            )));
+        }
+        Ok(())
+    }
 }
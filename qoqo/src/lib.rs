@@ -32,7 +32,10 @@ pub mod measurements;
 pub mod devices;
 
 mod circuit;
-pub use circuit::{convert_into_circuit, CircuitWrapper, OperationIteratorWrapper};
+pub use circuit::{
+    circuits_from_bincode, circuits_to_bincode, convert_into_circuit, CircuitWrapper,
+    OperationIteratorWrapper,
+};
 
 mod quantum_program;
 pub use quantum_program::{convert_into_quantum_program, QuantumProgramWrapper};
@@ -113,6 +116,8 @@ fn qoqo(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<QuantumProgramWrapper>()?;
     #[cfg(feature = "circuitdag")]
     module.add_class::<CircuitDagWrapper>()?;
+    module.add_function(wrap_pyfunction!(circuits_to_bincode, module)?)?;
+    module.add_function(wrap_pyfunction!(circuits_from_bincode, module)?)?;
     let wrapper = wrap_pymodule!(operations::operations);
     module.add_wrapped(wrapper)?;
     let wrapper2 = wrap_pymodule!(measurements::measurements);
@@ -15,10 +15,11 @@
 use super::PauliZProductInputWrapper;
 use crate::CircuitWrapper;
 use bincode::{deserialize, serialize};
+use numpy::PyReadonlyArray2;
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
-use roqoqo::measurements::PauliZProduct;
+use roqoqo::measurements::{PauliZProduct, ReadoutErrorMitigationInput};
 use roqoqo::prelude::*;
 use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use roqoqo::Circuit;
@@ -118,27 +119,7 @@ impl PauliZProductWrapper {
         float_registers: HashMap<String, FloatOutputRegister>,
         complex_registers: HashMap<String, ComplexOutputRegister>,
     ) -> PyResult<Option<HashMap<String, f64>>> {
-        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
-        let bit_registers_bool: PyResult<HashMap<String, Vec<Vec<bool>>>> =
-            input_bit_registers.extract::<HashMap<String, BitOutputRegister>>();
-        if let Ok(try_downcast) = bit_registers_bool {
-            bit_registers = try_downcast
-        } else {
-            let tmp_bit_registers =
-                input_bit_registers.extract::<HashMap<String, Vec<Vec<usize>>>>()?;
-            for (name, output_reg) in tmp_bit_registers {
-                let mut tmp_output_reg: Vec<Vec<bool>> = Vec::with_capacity(output_reg.len());
-                for reg in output_reg {
-                    tmp_output_reg.push(reg.into_iter().map(|x| !matches!(x, 0)).collect());
-                }
-                bit_registers.insert(name, tmp_output_reg);
-                // if t.is_some() {
-                //     return Err(PyRuntimeError::new_err(
-                //         "Unexpected repetition of key in bit_registers",
-                //     ));
-                // }
-            }
-        }
+        let bit_registers = convert_bit_registers(input_bit_registers)?;
         self.internal
             .evaluate(bit_registers, float_registers, complex_registers)
             .map_err(|x| {
@@ -149,6 +130,52 @@ impl PauliZProductWrapper {
             })
     }
 
+    /// Execute the PauliZ product measurement mitigating readout errors.
+    ///
+    /// The readout error matrix M is applied to every measured qubit,
+    /// where M[i][j] is the probability of obtaining the outcome i when the qubit is in state j.
+    /// The inverse of the matrix is applied to the raw measurement results
+    /// before the Pauli products are evaluated.
+    ///
+    /// Args:
+    ///     input_bit_registers (Dict[str, Union[List[List[int]], List[List[bool]]]]): The classical bit registers with the register name as key
+    ///     float_registers (Dict[str, List[List[float]]]): The classical float registers as a dictionary with the register name as key
+    ///     complex_registers (Dict[str, List[List[complex]]]): The classical complex registers as a dictionary with the register name as key
+    ///     readout_error_matrix (np.ndarray): The 2x2 column-stochastic readout error matrix
+    ///
+    /// Returns:
+    ///     Optional[Dict[str, float]]: The evaluated measurement.
+    ///
+    /// Raises:
+    ///     ValueError: The matrix is not an invertible 2x2 column-stochastic matrix.
+    ///     RuntimeError: Error evaluating PauliZ product measurement.
+    pub fn evaluate_with_readout_error_matrix(
+        &mut self,
+        input_bit_registers: &Bound<PyAny>,
+        float_registers: HashMap<String, FloatOutputRegister>,
+        complex_registers: HashMap<String, ComplexOutputRegister>,
+        readout_error_matrix: PyReadonlyArray2<f64>,
+    ) -> PyResult<Option<HashMap<String, f64>>> {
+        let mitigation = ReadoutErrorMitigationInput::new(
+            readout_error_matrix.as_array().to_owned(),
+        )
+        .map_err(|x| PyValueError::new_err(format!("Invalid readout error matrix: {:?}", x)))?;
+        let bit_registers = convert_bit_registers(input_bit_registers)?;
+        self.internal
+            .evaluate_with_readout_error_mitigation(
+                bit_registers,
+                float_registers,
+                complex_registers,
+                &mitigation,
+            )
+            .map_err(|x| {
+                PyRuntimeError::new_err(format!(
+                    "Error evaluating PauliZ product measurement {:?}",
+                    x
+                ))
+            })
+    }
+
     /// Return the collection of quantum circuits for the separate basis rotations.
     ///
     /// Returns:
@@ -398,3 +425,31 @@ impl PauliZProductWrapper {
         }
     }
 }
+
+/// Converts Python bit registers given as bools or integers to BitOutputRegisters.
+fn convert_bit_registers(
+    input_bit_registers: &Bound<PyAny>,
+) -> PyResult<HashMap<String, BitOutputRegister>> {
+    let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+    let bit_registers_bool: PyResult<HashMap<String, Vec<Vec<bool>>>> =
+        input_bit_registers.extract::<HashMap<String, BitOutputRegister>>();
+    if let Ok(try_downcast) = bit_registers_bool {
+        bit_registers = try_downcast
+    } else {
+        let tmp_bit_registers =
+            input_bit_registers.extract::<HashMap<String, Vec<Vec<usize>>>>()?;
+        for (name, output_reg) in tmp_bit_registers {
+            let mut tmp_output_reg: Vec<Vec<bool>> = Vec::with_capacity(output_reg.len());
+            for reg in output_reg {
+                tmp_output_reg.push(reg.into_iter().map(|x| !matches!(x, 0)).collect());
+            }
+            bit_registers.insert(name, tmp_output_reg);
+            // if t.is_some() {
+            //     return Err(PyRuntimeError::new_err(
+            //         "Unexpected repetition of key in bit_registers",
+            //     ));
+            // }
+        }
+    }
+    Ok(bit_registers)
+}
@@ -26,6 +26,8 @@ pub use overrotation::{
 };
 mod decoherence_on_idle;
 pub use decoherence_on_idle::DecoherenceOnIdleModelWrapper;
+mod phase_flip;
+pub use phase_flip::PhaseFlipNoiseModelWrapper;
 use pyo3::prelude::*;
 
 /// A collection of noise models that represent different types of noise that can be present in Quantum Computing hardware.
@@ -39,6 +41,7 @@ use pyo3::prelude::*;
 ///     SingleQubitOverrotationDescription
 ///     SingleQubitOverrotationOnGate
 ///     DecoherenceOnIdleModel
+///     PhaseFlipNoiseModel
 #[pymodule]
 pub fn noise_models(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<ContinuousDecoherenceModelWrapper>()?;
@@ -47,5 +50,6 @@ pub fn noise_models(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<SingleQubitOverrotationDescriptionWrapper>()?;
     module.add_class::<SingleQubitOverrotationOnGateWrapper>()?;
     module.add_class::<DecoherenceOnIdleModelWrapper>()?;
+    module.add_class::<PhaseFlipNoiseModelWrapper>()?;
     Ok(())
 }
@@ -0,0 +1,159 @@
+// Copyright © 2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::operations::PragmaDephasingWrapper;
+use pyo3::prelude::*;
+use qoqo_macros::noise_model_wrapper;
+use roqoqo::noise_models::{NoiseModel, PhaseFlipNoiseModel};
+#[cfg(feature = "json_schema")]
+use roqoqo::{operations::SupportedVersion, ROQOQO_VERSION};
+
+/// Noise model representing a single-parameter phase flip channel.
+///
+/// Each qubit undergoes the channel ρ → (1-p) ρ + p Z ρ Z, where p is the flip rate.
+/// The channel is applied with a PragmaDephasing whose dephasing probability equals the flip rate.
+///
+/// Args:
+///     flip_rate (float): The probability of a phase flip, between 0 and 0.5.
+///
+/// # Example
+///
+/// ```Python
+/// from qoqo.noise_models import PhaseFlipNoiseModel
+///
+/// model = PhaseFlipNoiseModel(0.1)
+/// pragma = model.pragma_for_qubit(0, 1.0)
+/// ```
+#[pyclass(frozen, name = "PhaseFlipNoiseModel")]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PhaseFlipNoiseModelWrapper {
+    internal: PhaseFlipNoiseModel,
+}
+
+#[noise_model_wrapper]
+impl PhaseFlipNoiseModelWrapper {
+    /// Create a new PhaseFlipNoiseModel.
+    ///
+    /// Args:
+    ///     flip_rate (float): The probability of a phase flip, between 0 and 0.5.
+    ///
+    /// Returns:
+    ///     PhaseFlipNoiseModel: The new noise model.
+    ///
+    /// Raises:
+    ///     ValueError: The flip rate is not between 0 and 0.5.
+    #[new]
+    pub fn new(flip_rate: f64) -> PyResult<Self> {
+        let internal = PhaseFlipNoiseModel::new(flip_rate)
+            .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))?;
+        Ok(PhaseFlipNoiseModelWrapper { internal })
+    }
+
+    /// Convert the bincode representation of the Noise-Model to a device using the bincode crate.
+    ///
+    /// Args:
+    ///     input (ByteArray): The serialized Noise-Model (in bincode form).
+    ///
+    /// Returns:
+    ///     The deserialized Noise-Model.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to byte array.
+    ///     ValueError: Input cannot be deserialized to selected Noise-Model.
+    #[staticmethod]
+    #[pyo3(text_signature = "(input)")]
+    pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<PhaseFlipNoiseModelWrapper> {
+        let bytes = input.as_gil_ref().extract::<Vec<u8>>().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err("Input cannot be converted to byte array")
+        })?;
+        let noise_model: NoiseModel = bincode::deserialize(&bytes[..]).map_err(|_| {
+            pyo3::exceptions::PyValueError::new_err("Input cannot be deserialized to Noise-Model.")
+        })?;
+        match noise_model {
+            NoiseModel::PhaseFlipNoiseModel(internal) => {
+                Ok(PhaseFlipNoiseModelWrapper { internal })
+            }
+            _ => Err(pyo3::exceptions::PyValueError::new_err(
+                "Input cannot be deserialized to selected Noise-Model.",
+            )),
+        }
+    }
+
+    /// Convert the json representation of a device to a Noise-Model.
+    ///
+    /// Args:
+    ///     input (str): The serialized device in json form.
+    ///
+    /// Returns:
+    ///     The deserialized device.
+    ///
+    /// Raises:
+    ///     ValueError: Input cannot be deserialized to selected Noise-Model.
+    #[staticmethod]
+    #[pyo3(text_signature = "(input)")]
+    pub fn from_json(input: &str) -> PyResult<PhaseFlipNoiseModelWrapper> {
+        let noise_model: NoiseModel = serde_json::from_str(input).map_err(|_| {
+            pyo3::exceptions::PyValueError::new_err("Input cannot be deserialized to Noise-Model.")
+        })?;
+        match noise_model {
+            NoiseModel::PhaseFlipNoiseModel(internal) => {
+                Ok(PhaseFlipNoiseModelWrapper { internal })
+            }
+            _ => Err(pyo3::exceptions::PyValueError::new_err(
+                "Input cannot be deserialized to selected Noise-Model.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(PhaseFlipNoiseModel);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    /// Return the probability of a phase flip.
+    ///
+    /// Returns:
+    ///     float: The flip rate of the model.
+    pub fn flip_rate(&self) -> f64 {
+        self.internal.flip_rate()
+    }
+
+    /// Return the PragmaDephasing applying the phase flip channel to a qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit the channel acts on.
+    ///     gate_time (float): The time over which the phase flip channel acts.
+    ///
+    /// Returns:
+    ///     PragmaDephasing: The PRAGMA with the dephasing rate matching the flip rate.
+    ///
+    /// Raises:
+    ///     ValueError: The gate time is not positive.
+    pub fn pragma_for_qubit(
+        &self,
+        qubit: usize,
+        gate_time: f64,
+    ) -> PyResult<PragmaDephasingWrapper> {
+        Ok(PragmaDephasingWrapper {
+            internal: self
+                .internal
+                .pragma_for_qubit(qubit, gate_time)
+                .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))?,
+        })
+    }
+}
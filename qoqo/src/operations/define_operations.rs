@@ -10,14 +10,12 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[cfg(feature = "unstable_operation_definition")]
 use crate::{convert_into_circuit, CircuitWrapper};
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError};
 use pyo3::prelude::*;
 use pyo3::types::PySet;
 use qoqo_macros::*;
 use roqoqo::operations::*;
-#[cfg(feature = "unstable_operation_definition")]
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
@@ -104,7 +102,60 @@ pub struct InputBit {
     value: bool,
 }
 
-#[cfg(feature = "unstable_operation_definition")]
+#[pymethods]
+impl InputBitWrapper {
+    /// Create the InputBit operations setting all bits of a register.
+    ///
+    /// Args:
+    ///     name (str): The name of the register that is set.
+    ///     values (List[bool]): The values the bits with indices 0 to len(values) - 1 are set to.
+    ///
+    /// Returns:
+    ///     List[InputBit]: One InputBit operation per value.
+    #[staticmethod]
+    pub fn from_register(name: &str, values: Vec<bool>) -> Vec<InputBitWrapper> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| InputBitWrapper {
+                internal: InputBit::new(name.to_string(), index, value),
+            })
+            .collect()
+    }
+}
+
+/// Prepend InputBit operations setting all bits of a register to a circuit.
+///
+/// Args:
+///     circuit (Circuit): The circuit the InputBit operations are prepended to.
+///     name (str): The name of the register that is set.
+///     values (List[bool]): The values the bits with indices 0 to len(values) - 1 are set to.
+///
+/// Returns:
+///     Circuit: The circuit starting with the InputBit operations.
+///
+/// Raises:
+///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+#[pyfunction]
+pub fn apply_to_circuit(
+    circuit: &Bound<PyAny>,
+    name: &str,
+    values: Vec<bool>,
+) -> PyResult<CircuitWrapper> {
+    let circuit = convert_into_circuit(circuit).map_err(|_| {
+        PyTypeError::new_err("Circuit argument cannot be converted to qoqo Circuit")
+    })?;
+    let mut new_circuit: Circuit = values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| InputBit::new(name.to_string(), index, value))
+        .collect();
+    new_circuit += circuit;
+    Ok(CircuitWrapper {
+        internal: new_circuit,
+    })
+}
+
 #[wrap(Operate, Define, OperateMultiQubit, JsonSchema)]
 /// GateDefinition is the Definition of a new custom gate.
 ///
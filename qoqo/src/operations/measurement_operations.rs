@@ -96,6 +96,42 @@ struct PragmaGetPauliProduct {
     circuit: Circuit,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA measurement operation returns the expectation values of several Pauli products.
+///
+/// The expectation value of the i-th Pauli product in `qubit_paulis_list` is written to the i-th entry
+/// of the readout register. All products are evaluated on a clone of the quantum register after applying
+/// the same measurement preparation circuit.
+///
+/// Args:
+///     qubit_paulis_list (List[Dict[int, int]]): The list of dictionaries of the pauli matrix to apply to each qubit in the form
+///                                               {qubit: pauli}. Allowed values to be provided for 'pauli' are: 0 = identity, 1 = PauliX, 2 = PauliY, 3 = PauliZ.
+///     readout (string): The name of the classical readout register.
+///     circuit (Circuit): The measurement preparation Circuit, applied on a copy of the register before measurement.
+///
+struct PragmaGetPauliProducts {
+    qubit_paulis_list: Vec<std::collections::HashMap<usize, usize>>,
+    readout: String,
+    circuit: Circuit,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// This PRAGMA measurement operation returns the reduced density matrix of a subset of qubits.
+///
+/// The qubits not in `qubits` are traced out. The order of `qubits` determines the order
+/// of the qubits in the reduced density matrix.
+///
+/// Args:
+///     readout (string): The name of the classical readout register.
+///     qubits (List[int]): The qubits of the reduced density matrix.
+///     circuit (Optional[Circuit]): The measurement preparation Circuit, applied on a copy of the register before measurement.
+///
+struct PragmaGetReducedDensityMatrix {
+    readout: String,
+    qubits: Vec<usize>,
+    circuit: Option<Circuit>,
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 #[derive(Eq)]
 /// This PRAGMA measurement operation returns a measurement record for N repeated measurements.
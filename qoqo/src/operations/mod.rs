@@ -83,6 +83,8 @@ pub fn operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PragmaGetDensityMatrixWrapper>()?;
     m.add_class::<PragmaGetOccupationProbabilityWrapper>()?;
     m.add_class::<PragmaGetPauliProductWrapper>()?;
+    m.add_class::<PragmaGetPauliProductsWrapper>()?;
+    m.add_class::<PragmaGetReducedDensityMatrixWrapper>()?;
     m.add_class::<PragmaRepeatedMeasurementWrapper>()?;
     m.add_class::<PragmaSetNumberOfMeasurementsWrapper>()?;
     m.add_class::<PragmaSetStateVectorWrapper>()?;
@@ -93,6 +95,7 @@ pub fn operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PragmaStopParallelBlockWrapper>()?;
     m.add_class::<PragmaGlobalPhaseWrapper>()?;
     m.add_class::<PragmaSleepWrapper>()?;
+    m.add_class::<PragmaQubitSleepWrapper>()?;
     m.add_class::<PragmaActiveResetWrapper>()?;
     m.add_class::<PragmaStartDecompositionBlockWrapper>()?;
     m.add_class::<PragmaStopDecompositionBlockWrapper>()?;
@@ -170,10 +173,25 @@ pub fn operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<SingleExcitationStoreWrapper>()?;
     m.add_class::<SingleExcitationLoadWrapper>()?;
     m.add_class::<CZQubitResonatorWrapper>()?;
-    #[cfg(feature = "unstable_operation_definition")]
     m.add_class::<GateDefinitionWrapper>()?;
-    #[cfg(feature = "unstable_operation_definition")]
     m.add_class::<CallDefinedGateWrapper>()?;
+    // 1.14
+    m.add_class::<PragmaSetStabilizerStateWrapper>()?;
+    m.add_class::<PragmaCustomTwoQubitGateWrapper>()?;
+    m.add_class::<MultiQubitXXWrapper>()?;
+    m.add_class::<RXXWrapper>()?;
+    m.add_class::<RYYWrapper>()?;
+    m.add_class::<RZZWrapper>()?;
+    m.add_class::<ControlledHadamardWrapper>()?;
+    m.add_class::<SqrtCNOTWrapper>()?;
+    m.add_class::<InvSqrtCNOTWrapper>()?;
+    m.add_class::<RotateAxisWrapper>()?;
+    m.add_class::<ZXCouplingWrapper>()?;
+    m.add_class::<PragmaMultiControlledCircuitWrapper>()?;
+    m.add_class::<QFTWrapper>()?;
+    m.add_class::<MultiQubitCNOTWrapper>()?;
+    m.add_class::<PragmaSimulationRepetitionsWrapper>()?;
+    m.add_function(wrap_pyfunction!(apply_to_circuit, m)?)?;
 
     Ok(())
 }
@@ -65,6 +65,351 @@ pub struct MultiQubitZZ {
     theta: CalculatorFloat,
 }
 
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(
+    Operate,
+    Rotate,
+    OperateMultiQubit,
+    OperateGate,
+    OperateMultiQubitGate,
+    JsonSchema
+)]
+/// The multi qubit Pauli-X-Product gate.
+///
+/// The gate applies the rotation under the product of Pauli X operators on multiple qubits.
+/// In mathematical terms the gate applies exp(-i * theta/2 * X_i0 * X_i1 * ... * X_in).
+///
+/// Args:
+///     qubits (List[int]): The qubits involved in the multi qubit Pauli-X-Product gate.
+///     theta (CalculatorFloat): The angle of the multi qubit Pauli-X-Product gate.
+pub struct MultiQubitXX {
+    /// The qubits involved in the multi qubit Pauli-X-Product gate.
+    qubits: Vec<usize>,
+    /// The angle of the multi qubit Pauli-X-Product gate.
+    theta: CalculatorFloat,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(
+    Operate,
+    OperateMultiQubit,
+    OperateGate,
+    OperateMultiQubitGate,
+    JsonSchema
+)]
+/// The quantum Fourier transform (QFT) on multiple qubits.
+///
+/// The gate applies the discrete Fourier transform to the amplitudes of the qubits,
+/// with the first qubit in `qubits` being the most significant one.
+///
+/// Args:
+///     qubits (List[int]): The qubits involved in the QFT.
+///     inverse (bool): Whether the inverse QFT is applied.
+///     with_swaps (bool): Whether the SWAP network reversing the order of the qubits is included.
+pub struct QFT {
+    /// The qubits involved in the QFT.
+    qubits: Vec<usize>,
+    /// Whether the inverse QFT is applied.
+    inverse: bool,
+    /// Whether the SWAP network reversing the order of the qubits is included.
+    with_swaps: bool,
+}
+
+#[pymethods]
+impl QFTWrapper {
+    /// Return the QFT expanded into Hadamard, ControlledPhaseShift and SWAP gates.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit implementing the QFT.
+    pub fn unroll(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.circuit(),
+        }
+    }
+}
+
+/// The multi qubit CNOT gate (fan-out CNOT).
+///
+/// The first qubit is the control qubit, all other qubits are target qubits.
+/// When the control qubit is in state 1 a PauliX gate is applied to every target qubit.
+///
+/// Args:
+///     qubits (List[int]): The qubits involved in the gate, the first qubit is the control qubit.
+#[allow(clippy::upper_case_acronyms)]
+#[pyclass(name = "MultiQubitCNOT", module = "qoqo")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiQubitCNOTWrapper {
+    /// Internal storage of [roqoqo::MultiQubitCNOT]
+    pub internal: MultiQubitCNOT,
+}
+
+insert_pyany_to_operation!(
+    "MultiQubitCNOT" =>{
+        let qbts = op.call_method0("qubits")
+                    .map_err(|_| QoqoError::ConversionError)?;
+        let qubits: Vec<usize> = qbts.extract()
+                .map_err(|_| QoqoError::ConversionError)?;
+        Ok(MultiQubitCNOT::new(qubits).into())
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::MultiQubitCNOT(internal) => {
+        {
+            let pyref: Py<MultiQubitCNOTWrapper> =
+                Py::new(py, MultiQubitCNOTWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl MultiQubitCNOTWrapper {
+    /// Create a new MultiQubitCNOT.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits involved in the gate, the first qubit is the control qubit.
+    ///
+    /// Returns:
+    ///     self: The new MultiQubitCNOT.
+    ///
+    /// Raises:
+    ///     ValueError: Fewer than two qubits were given.
+    #[new]
+    fn new(qubits: Vec<usize>) -> PyResult<Self> {
+        if qubits.len() < 2 {
+            return Err(PyValueError::new_err(format!(
+                "MultiQubitCNOT needs at least two qubits, got {}.",
+                qubits.len()
+            )));
+        }
+        Ok(Self {
+            internal: MultiQubitCNOT::new(qubits),
+        })
+    }
+
+    /// Return list of qubits of the multi qubit operation in order of descending significance.
+    ///
+    /// Returns:
+    ///     List[int]: The qubits of the operation.
+    fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().clone()
+    }
+
+    /// Return the unitary matrix of the gate.
+    ///
+    /// Returns:
+    ///     np.ndarray: The unitary matrix of the gate.
+    ///
+    /// Raises:
+    ///     ValueError: The unitary matrix could not be constructed.
+    fn unitary_matrix(&self) -> PyResult<Py<PyArray2<Complex64>>> {
+        Python::with_gil(|py| -> PyResult<Py<PyArray2<Complex64>>> {
+            Ok(self
+                .internal
+                .unitary_matrix()
+                .map_err(|x| PyValueError::new_err(format!("{:?}", x)))?
+                .to_pyarray_bound(py)
+                .as_gil_ref()
+                .into())
+        })
+    }
+
+    /// Return the circuit implementing the gate with CNOT gates.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit implementing the gate.
+    fn circuit(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.circuit(),
+        }
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the operation.
+    fn involved_qubits(&self) -> PyObject {
+        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, self.internal.qubits())
+                .unwrap()
+                .to_object(py)
+        });
+        pyobject
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the Operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Substitute the symbolic parameters in a clone of the operation according to the input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    PyRuntimeError::new_err(format!("Parameter Substitution failed: {:?}", x))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the MultiQubitCNOT operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     MultiQubitCNOT: A deep copy of self.
+    fn __copy__(&self) -> MultiQubitCNOTWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the operation.
+    ///
+    /// Returns:
+    ///     MultiQubitCNOT: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: Py<PyAny>) -> MultiQubitCNOTWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on MultiQubitCNOT.
+    ///
+    /// Args:
+    ///     self: The MultiQubitCNOT object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other: Operation =
+            crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "Right hand side cannot be converted to Operation",
+                )
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(MultiQubitCNOT);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            MultiQubitCNOT::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
 /// The gate to be replaced by a gate defined with GateDefinition gate.
 /// The gate applies a gate previously defined by GateDefinition with the name gate_name.
 ///
@@ -74,7 +419,6 @@ pub struct MultiQubitZZ {
 ///                           (get replaced in order of apppearance in gate defintion).
 ///     free_parameters (List[CalculatorFloat]) : List of float values that replace the free parameters in the internal definition of the called gate
 ///                                             (get replaced in order of apppearance in gate defintion).
-#[cfg(feature = "unstable_operation_definition")]
 #[pyclass(name = "CallDefinedGate", module = "qoqo")]
 #[derive(Debug, Clone, PartialEq)]
 pub struct CallDefinedGateWrapper {
@@ -82,7 +426,6 @@ pub struct CallDefinedGateWrapper {
     pub internal: CallDefinedGate,
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 insert_pyany_to_operation!(
     "CallDefinedGate" =>{
         let gatenm = op.call_method0("gate_name")
@@ -103,7 +446,6 @@ insert_pyany_to_operation!(
         Ok(CallDefinedGate::new(gate_name, qubits, free_parameters).into())
     }
 );
-#[cfg(feature = "unstable_operation_definition")]
 insert_operation_to_pyobject!(
     Operation::CallDefinedGate(internal) => {
         {
@@ -115,7 +457,6 @@ insert_operation_to_pyobject!(
     }
 );
 
-#[cfg(feature = "unstable_operation_definition")]
 #[pymethods]
 impl CallDefinedGateWrapper {
     /// Create a new CallDefinedGate.
@@ -15,7 +15,7 @@ use crate::{convert_into_circuit, CircuitWrapper};
 use ndarray::{Array1, Array2};
 use num_complex::Complex64;
 use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use pyo3::types::PySet;
@@ -44,6 +44,19 @@ struct PragmaSetNumberOfMeasurements {
     readout: String,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+#[derive(Eq)]
+/// This PRAGMA operation sets the number of repetitions of stochastic simulations of the circuit.
+///
+/// This is used for simulator backends that sample stochastic processes, for example when
+/// simulating noise with quantum trajectories, and need to know how often the simulation is repeated.
+///
+/// Args:
+///     repetitions (int): The number of repetitions of the simulation.
+pub struct PragmaSimulationRepetitions {
+    repetitions: usize,
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// This PRAGMA measurement operation returns the statevector of a quantum register.
 ///
@@ -437,6 +450,42 @@ impl PragmaSetDensityMatrixWrapper {
         }
     }
 
+    /// Create a PragmaSetDensityMatrix from a pure state vector.
+    ///
+    /// The state vector is normalised before the density matrix |ψ><ψ| is constructed.
+    ///
+    /// Args:
+    ///     statevector (List[complex]): The pure state vector of the qubit register.
+    ///
+    /// Returns:
+    ///     PragmaSetDensityMatrix: The density matrix of the pure state.
+    ///
+    /// Raises:
+    ///     TypeError: Statevector cannot be converted to a 1d array of complex numbers.
+    ///     ValueError: Statevector has zero norm.
+    #[staticmethod]
+    fn from_statevector(statevector: &Bound<PyAny>) -> PyResult<Self> {
+        let statevector: Array1<Complex64> = PragmaSetStateVectorWrapper::new(statevector)?
+            .internal
+            .statevector()
+            .clone();
+        let norm: f64 = statevector.iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return Err(PyValueError::new_err(
+                "Statevector has zero norm and cannot be normalised",
+            ));
+        }
+        let statevector: Array1<Complex64> = statevector.map(|c| c / norm);
+        let dimension = statevector.len();
+        let density_matrix: Array2<Complex64> =
+            Array2::from_shape_fn((dimension, dimension), |(i, j)| {
+                statevector[i] * statevector[j].conj()
+            });
+        Ok(Self {
+            internal: PragmaSetDensityMatrix::new(density_matrix),
+        })
+    }
+
     /// Return the set density matrix.
     ///
     /// Returns:
@@ -679,6 +728,28 @@ struct PragmaBoostNoise {
     noise_coefficient: CalculatorFloat,
 }
 
+#[pymethods]
+impl PragmaBoostNoiseWrapper {
+    /// Return a copy of the PRAGMA operation with the noise coefficient multiplied by a factor.
+    ///
+    /// Args:
+    ///     factor (CalculatorFloat): The factor by which the noise coefficient is multiplied.
+    ///
+    /// Returns:
+    ///     PragmaBoostNoise: The PRAGMA operation with the scaled noise coefficient.
+    ///
+    /// Raises:
+    ///     TypeError: Argument factor cannot be converted to CalculatorFloat.
+    pub fn scale(&self, factor: &Bound<PyAny>) -> PyResult<PragmaBoostNoiseWrapper> {
+        let factor = convert_into_calculator_float(factor).map_err(|_| {
+            PyTypeError::new_err("Argument factor cannot be converted to CalculatorFloat")
+        })?;
+        Ok(PragmaBoostNoiseWrapper {
+            internal: PragmaBoostNoise::new(self.internal.noise_coefficient().clone() * factor),
+        })
+    }
+}
+
 #[wrap(Operate, OperateMultiQubit, OperatePragma, JsonSchema)]
 /// This PRAGMA operation signals the STOP of a parallel execution block.
 ///
@@ -690,6 +761,25 @@ struct PragmaStopParallelBlock {
     execution_time: CalculatorFloat,
 }
 
+#[pymethods]
+impl PragmaStopParallelBlockWrapper {
+    /// Return a copy of the PRAGMA operation with a symbolic execution time.
+    ///
+    /// Args:
+    ///     expression (str): The symbolic expression for the execution time of the block.
+    ///
+    /// Returns:
+    ///     PragmaStopParallelBlock: The PRAGMA operation with the symbolic execution time.
+    pub fn with_symbolic_execution_time(&self, expression: &str) -> PragmaStopParallelBlockWrapper {
+        PragmaStopParallelBlockWrapper {
+            internal: PragmaStopParallelBlock::new(
+                self.internal.qubits().clone(),
+                CalculatorFloat::from(expression),
+            ),
+        }
+    }
+}
+
 #[wrap(Operate, JsonSchema)]
 /// The global phase PRAGMA operation.
 ///
@@ -716,6 +806,273 @@ pub struct PragmaSleep {
     sleep_time: CalculatorFloat,
 }
 
+/// This PRAGMA operation makes the quantum hardware wait a qubit-specific amount of time.
+///
+/// In contrast to PragmaSleep every qubit can be assigned its own sleep time.
+///
+/// Args:
+///     sleep_times (Dict[int, CalculatorFloat]): The time each qubit sleeps in seconds.
+#[pyclass(name = "PragmaQubitSleep", module = "qoqo.operations")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PragmaQubitSleepWrapper {
+    /// PragmaQubitSleep to be wrapped and converted to Python.
+    pub internal: PragmaQubitSleep,
+}
+
+insert_pyany_to_operation!(
+    "PragmaQubitSleep" =>{
+        let times = op.call_method0("sleep_times")
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let times: HashMap<usize, Bound<PyAny>> = times.extract()
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let mut sleep_times: HashMap<usize, CalculatorFloat> = HashMap::new();
+        for (qubit, time) in times.iter() {
+            sleep_times.insert(*qubit, convert_into_calculator_float(time).map_err(|_| QoqoError::ConversionError)?);
+        }
+        Ok(PragmaQubitSleep::new(sleep_times).into())
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::PragmaQubitSleep(internal) => {
+        {
+            let pyref: Py<PragmaQubitSleepWrapper> =
+                Py::new(py, PragmaQubitSleepWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl PragmaQubitSleepWrapper {
+    /// Create a PragmaQubitSleep.
+    ///
+    /// Args:
+    ///     sleep_times (Dict[int, CalculatorFloat]): The time each qubit sleeps in seconds.
+    ///
+    /// Returns:
+    ///     self: The new PragmaQubitSleep.
+    ///
+    /// Raises:
+    ///     TypeError: A sleep time cannot be converted to CalculatorFloat.
+    #[new]
+    fn new(sleep_times: HashMap<usize, Bound<PyAny>>) -> PyResult<Self> {
+        let mut internal_times: HashMap<usize, CalculatorFloat> = HashMap::new();
+        for (qubit, time) in sleep_times.iter() {
+            let time = convert_into_calculator_float(time).map_err(|_| {
+                PyTypeError::new_err("Argument sleep time cannot be converted to CalculatorFloat")
+            })?;
+            internal_times.insert(*qubit, time);
+        }
+        Ok(Self {
+            internal: PragmaQubitSleep::new(internal_times),
+        })
+    }
+
+    /// Return the sleep times of the qubits.
+    ///
+    /// Returns:
+    ///     Dict[int, CalculatorFloat]: The time each qubit sleeps in seconds.
+    fn sleep_times(&self) -> HashMap<usize, CalculatorFloatWrapper> {
+        self.internal
+            .sleep_times()
+            .iter()
+            .map(|(qubit, time)| {
+                (
+                    *qubit,
+                    CalculatorFloatWrapper {
+                        internal: time.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, self.internal.sleep_times().keys())
+                .unwrap()
+                .to_object(py)
+        });
+        pyobject
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the Operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    PyRuntimeError::new_err(format!("Parameter Substitution failed: {:?}", x))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaQubitSleep: A deep copy of self.
+    fn __copy__(&self) -> PragmaQubitSleepWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaQubitSleep: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> PragmaQubitSleepWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaQubitSleep.
+    ///
+    /// Args:
+    ///     self: The PragmaQubitSleep object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other: Operation =
+            crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "Right hand side cannot be converted to Operation",
+                )
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(PragmaQubitSleep);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            PragmaQubitSleep::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
 #[wrap(Operate, OperateSingleQubit, OperatePragma, JsonSchema)]
 #[derive(Eq)]
 /// This PRAGMA operation resets the chosen qubit to the zero state.
@@ -1151,13 +1508,66 @@ impl PragmaGeneralNoiseWrapper {
         })
     }
 
-    /// List all involved qubits.
+    /// Check that a rate matrix describes a physically valid noise channel.
     ///
-    /// Returns:
-    ///     Set[int]: The involved qubits of the PRAGMA operation.
-    fn involved_qubits(&self) -> PyObject {
-        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
-            PySet::new_bound(py, &[*self.internal.qubit()])
+    /// The rate matrix needs to be a symmetric 3x3 matrix with non-negative diagonal entries
+    /// that is positive semi-definite (all eigenvalues are non-negative within a tolerance of 1e-10).
+    ///
+    /// Args:
+    ///     rates (Array2[float]): The rate matrix M.
+    ///
+    /// Raises:
+    ///     ValueError: The rate matrix is not a valid rate matrix.
+    #[staticmethod]
+    fn validate_rates_matrix(rates: PyReadonlyArray2<f64>) -> PyResult<()> {
+        let tolerance = 1e-10;
+        let rates = rates.as_array();
+        if rates.shape() != [3, 3] {
+            return Err(PyValueError::new_err(format!(
+                "Rates matrix needs to be a 3x3 matrix, got shape {:?}",
+                rates.shape()
+            )));
+        }
+        for i in 0..3 {
+            if rates[[i, i]] < -tolerance {
+                return Err(PyValueError::new_err(format!(
+                    "Diagonal entry {} of rates matrix is negative: {}",
+                    i,
+                    rates[[i, i]]
+                )));
+            }
+            for j in (i + 1)..3 {
+                if (rates[[i, j]] - rates[[j, i]]).abs() > tolerance {
+                    return Err(PyValueError::new_err("Rates matrix is not symmetric"));
+                }
+            }
+        }
+        // A symmetric matrix is positive semi-definite if and only if all its principal minors
+        // are non-negative, which for a 3x3 matrix avoids an explicit eigenvalue decomposition.
+        let minor =
+            |i: usize, j: usize| rates[[i, i]] * rates[[j, j]] - rates[[i, j]] * rates[[j, i]];
+        let determinant = rates[[0, 0]] * minor(1, 2)
+            - rates[[0, 1]] * (rates[[1, 0]] * rates[[2, 2]] - rates[[1, 2]] * rates[[2, 0]])
+            + rates[[0, 2]] * (rates[[1, 0]] * rates[[2, 1]] - rates[[1, 1]] * rates[[2, 0]]);
+        if minor(0, 1) < -tolerance
+            || minor(0, 2) < -tolerance
+            || minor(1, 2) < -tolerance
+            || determinant < -tolerance
+        {
+            return Err(PyValueError::new_err(
+                "Rates matrix is not positive semi-definite",
+            ));
+        }
+        Ok(())
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, &[*self.internal.qubit()])
                 .unwrap()
                 .to_object(py)
         });
@@ -1369,6 +1779,22 @@ pub struct PragmaControlledCircuit {
     circuit: Circuit,
 }
 
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// A circuit controlled by multiple qubits.
+///
+/// The circuit is applied when all controlling qubits are in state 1.
+/// Note that this is a unitary operation (for example a Toffoli gate
+/// is equivalent to a PragmaMultiControlledCircuit([0, 1], [PauliX(2)])) but it cannot be represented
+/// by a unitary operation in qoqo for arbitrary circuits.
+///
+/// Args:
+///     controlling_qubits (List[int]): - The qubits controlling circuit application.
+///     circuit (Circuit): - The circuit executed if the condition is met.
+pub struct PragmaMultiControlledCircuit {
+    controlling_qubits: Vec<usize>,
+    circuit: Circuit,
+}
+
 #[pyclass(name = "PragmaChangeDevice", module = "qoqo.operations")]
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// A wrapper around backend specific PRAGMA operations capable of changing a device.
@@ -1448,6 +1874,30 @@ impl PragmaChangeDeviceWrapper {
         Ok(b)
     }
 
+    /// Return the wrapped operation deserialized to a qoqo operation.
+    ///
+    /// Only works when the wrapped operation was serialized as a roqoqo Operation.
+    ///
+    /// Returns:
+    ///     Operation: The wrapped operation.
+    ///
+    /// Raises:
+    ///     ValueError: The wrapped operation cannot be deserialized to a qoqo operation.
+    fn wrapped_operation_deserialized(&self) -> PyResult<PyObject> {
+        let operation: Operation = bincode::deserialize(&self.internal.wrapped_operation[..])
+            .map_err(|_| {
+                PyValueError::new_err("Wrapped operation cannot be deserialized to Operation")
+            })?;
+        if operation.hqslang() != self.internal.wrapped_hqslang {
+            return Err(PyValueError::new_err(format!(
+                "Deserialized operation {} does not match wrapped operation {}",
+                operation.hqslang(),
+                self.internal.wrapped_hqslang
+            )));
+        }
+        convert_operation_to_pyobject(operation)
+    }
+
     /// List all involved qubits.
     ///
     /// Returns:
@@ -1711,6 +2161,19 @@ impl PragmaAnnotatedOpWrapper {
         self.internal.annotation.clone()
     }
 
+    /// Return a copy of the PragmaAnnotatedOp with a different annotation.
+    ///
+    /// Args:
+    ///     new_annotation (str): The annotation replacing the current one.
+    ///
+    /// Returns:
+    ///     PragmaAnnotatedOp: The same annotated Operation with the new annotation.
+    fn replace_annotation(&self, new_annotation: String) -> PragmaAnnotatedOpWrapper {
+        PragmaAnnotatedOpWrapper {
+            internal: PragmaAnnotatedOp::new(*self.internal.operation.clone(), new_annotation),
+        }
+    }
+
     /// List all involved qubits.
     ///
     /// Returns:
@@ -1916,48 +2379,750 @@ impl PragmaAnnotatedOpWrapper {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::operations::*;
-    use bincode::serialize;
-    use roqoqo::operations::*;
-    use std::collections::HashSet;
+/// Module containing the PragmaSetStabilizerState class.
+#[pymodule]
+fn pragma_set_stabilizer_state(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_class::<PragmaSetStabilizerStateWrapper>()?;
+    Ok(())
+}
 
-    /// Test involved_qubits function for Pragmas with All
-    #[test]
-    fn test_pyo3_involved_qubits_all_change_device() {
-        let wrapped: Operation = PragmaActiveReset::new(0).into();
-        let input_definition: Operation = PragmaChangeDevice::new(&wrapped).unwrap().into();
+#[pyclass(name = "PragmaSetStabilizerState", module = "qoqo.operations")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// This PRAGMA operation sets the state of a quantum register to a stabilizer state.
+///
+/// The stabilizer state is defined by its stabilizer generators, each given as a Pauli string
+/// over the characters I, X, Y and Z. The k-th character of a generator acts on qubit k.
+/// For instance, to initialize the phi-plus Bell state, we pass the following generators to
+/// the PRAGMA:
+///     stabilizer_generators = ["XX", "ZZ"]
+///
+/// Args:
+///     stabilizer_generators (List[str]): The stabilizer generators of the state.
+pub struct PragmaSetStabilizerStateWrapper {
+    /// PragmaSetStabilizerState to be wrapped and converted to Python.
+    pub internal: PragmaSetStabilizerState,
+}
 
-        pyo3::prepare_freethreaded_python();
-        Python::with_gil(|py| {
-            let operation = convert_operation_to_pyobject(input_definition).unwrap();
-            let to_involved = operation.call_method0(py, "involved_qubits").unwrap();
-            let involved_op: HashSet<String> =
-                HashSet::extract_bound(to_involved.bind(py)).unwrap();
-            let mut involved_param: HashSet<String> = HashSet::new();
-            involved_param.insert("All".to_owned());
-            assert_eq!(involved_op, involved_param);
+insert_pyany_to_operation!(
+    "PragmaSetStabilizerState" =>{
+        let generators_op = op.call_method0("stabilizer_generators")
+                              .map_err(|_| QoqoError::ConversionError)?;
+        let stabilizer_generators: Vec<String> = generators_op.extract()
+                              .map_err(|_| QoqoError::ConversionError)?;
+        Ok(PragmaSetStabilizerState::new(stabilizer_generators).into())
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::PragmaSetStabilizerState(internal) => {
+        {
+            let pyref: Py<PragmaSetStabilizerStateWrapper> =
+                Py::new(py, PragmaSetStabilizerStateWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
 
-            assert!(PragmaChangeDeviceWrapper::new().is_err());
+/// Checks that the stabilizer generators define a valid n-qubit stabilizer state.
+///
+/// The generators need to be Pauli strings of equal length n over {I, X, Y, Z},
+/// there need to be exactly n of them, at least one, and they need to commute pairwise
+/// and be independent.
+fn validate_stabilizer_generators(stabilizer_generators: &[String]) -> PyResult<()> {
+    let number_qubits = stabilizer_generators.len();
+    if number_qubits == 0 {
+        return Err(PyValueError::new_err(
+            "At least one stabilizer generator is needed.",
+        ));
+    }
+    for generator in stabilizer_generators.iter() {
+        if generator.len() != number_qubits {
+            return Err(PyValueError::new_err(format!(
+                "Stabilizer generator {} does not act on {} qubits. Exactly n generators are needed for an n-qubit state.",
+                generator, number_qubits
+            )));
+        }
+        if let Some(c) = generator
+            .chars()
+            .find(|c| !matches!(c, 'I' | 'X' | 'Y' | 'Z'))
+        {
+            return Err(PyValueError::new_err(format!(
+                "Stabilizer generator {} contains invalid Pauli operator {}.",
+                generator, c
+            )));
+        }
+    }
+    for (index, first) in stabilizer_generators.iter().enumerate() {
+        for second in stabilizer_generators.iter().skip(index + 1) {
+            // Two Pauli strings commute when they anticommute on an even number of qubits
+            let anticommuting_positions = first
+                .chars()
+                .zip(second.chars())
+                .filter(|(a, b)| *a != 'I' && *b != 'I' && a != b)
+                .count();
+            if anticommuting_positions % 2 != 0 {
+                return Err(PyValueError::new_err(format!(
+                    "Stabilizer generators {} and {} do not commute.",
+                    first, second
+                )));
+            }
+        }
+    }
+    for (index, generator) in stabilizer_generators.iter().enumerate() {
+        if stabilizer_generators[..index].contains(generator) {
+            return Err(PyValueError::new_err(format!(
+                "Stabilizer generator {} is given more than once.",
+                generator
+            )));
+        }
+    }
+    if stabilizer_generators_rank(stabilizer_generators) < number_qubits {
+        return Err(PyValueError::new_err(
+            "Stabilizer generators are not independent.",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the rank over GF(2) of the binary representation of the stabilizer generators.
+///
+/// Each Pauli string is mapped to a row of X-bits followed by Z-bits (Y sets both),
+/// signs are not part of the generators and do not change the rank.
+fn stabilizer_generators_rank(stabilizer_generators: &[String]) -> usize {
+    let number_qubits = stabilizer_generators.len();
+    let mut rows: Vec<Vec<bool>> = stabilizer_generators
+        .iter()
+        .map(|generator| {
+            let mut row = vec![false; 2 * number_qubits];
+            for (qubit, pauli) in generator.chars().enumerate() {
+                row[qubit] = matches!(pauli, 'X' | 'Y');
+                row[number_qubits + qubit] = matches!(pauli, 'Z' | 'Y');
+            }
+            row
         })
+        .collect();
+    let mut rank = 0;
+    for column in 0..2 * number_qubits {
+        if let Some(pivot) = (rank..rows.len()).find(|row| rows[*row][column]) {
+            rows.swap(rank, pivot);
+            let pivot_row = rows[rank].clone();
+            for row in rows.iter_mut().skip(rank + 1) {
+                if row[column] {
+                    row.iter_mut()
+                        .zip(pivot_row.iter())
+                        .for_each(|(entry, pivot_entry)| *entry ^= *pivot_entry);
+                }
+            }
+            rank += 1;
+        }
     }
+    rank
+}
 
-    #[test]
-    fn test_pyo3_format_repr_change_device() {
-        let wrapped: Operation = PragmaActiveReset::new(0).into();
-        let input_measurement: Operation = PragmaChangeDevice::new(&wrapped).unwrap().into();
-        let format_repr = format!("PragmaChangeDevice {{ wrapped_tags: {:?}, wrapped_hqslang: {:?}, wrapped_operation: {:?} }}", wrapped.tags(), wrapped.hqslang(), serialize(&wrapped).unwrap());
+#[pymethods]
+impl PragmaSetStabilizerStateWrapper {
+    /// Create a PragmaSetStabilizerState.
+    ///
+    /// Args:
+    ///     stabilizer_generators (List[str]): The stabilizer generators of the state as Pauli strings.
+    ///
+    /// Returns:
+    ///     self: The new PragmaSetStabilizerState.
+    ///
+    /// Raises:
+    ///     ValueError: The generators are not n independent, mutually commuting n-qubit Pauli strings.
+    #[new]
+    fn new(stabilizer_generators: Vec<String>) -> PyResult<Self> {
+        validate_stabilizer_generators(&stabilizer_generators)?;
+        Ok(Self {
+            internal: PragmaSetStabilizerState::new(stabilizer_generators),
+        })
+    }
 
-        pyo3::prepare_freethreaded_python();
-        Python::with_gil(|py| {
-            let operation = convert_operation_to_pyobject(input_measurement).unwrap();
-            let to_format = operation.call_method1(py, "__format__", ("",)).unwrap();
-            let format_op: String = String::extract_bound(to_format.bind(py)).unwrap();
-            let to_repr = operation.call_method0(py, "__repr__").unwrap();
-            let repr_op: String = String::extract_bound(to_repr.bind(py)).unwrap();
-            assert_eq!(format_op, format_repr);
-            assert_eq!(repr_op, format_repr);
+    /// Return the stabilizer generators.
+    ///
+    /// Returns:
+    ///     List[str]: The stabilizer generators of the state.
+    fn stabilizer_generators(&self) -> Vec<String> {
+        self.internal.stabilizer_generators().clone()
+    }
+
+    /// List all involved qubits (here, all).
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, &["All"]).unwrap().to_object(py)
+        });
+        pyobject
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaSetStabilizerState: A deep copy of self.
+    fn __copy__(&self) -> PragmaSetStabilizerStateWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaSetStabilizerState: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> PragmaSetStabilizerStateWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaSetStabilizerState.
+    ///
+    /// Args:
+    ///     self: The PragmaSetStabilizerState object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other = crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "Right hand side cannot be converted to Operation",
+            )
+        })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(PragmaSetStabilizerState);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            PragmaSetStabilizerState::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
+/// Module containing the PragmaCustomTwoQubitGate class.
+#[pymodule]
+fn pragma_custom_two_qubit_gate(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_class::<PragmaCustomTwoQubitGateWrapper>()?;
+    Ok(())
+}
+
+#[pyclass(name = "PragmaCustomTwoQubitGate", module = "qoqo.operations")]
+#[derive(Clone, Debug, PartialEq)]
+/// This PRAGMA operation applies an arbitrary two-qubit unitary gate.
+///
+/// The gate is defined by its 4x4 unitary matrix with qubit_0 as the least significant qubit.
+/// It can be used for native hardware gates that do not fit into the existing gate operations.
+///
+/// Args:
+///     qubit_0 (int): The first qubit the gate acts on.
+///     qubit_1 (int): The second qubit the gate acts on.
+///     unitary (np.ndarray): The 4x4 unitary matrix of the gate.
+pub struct PragmaCustomTwoQubitGateWrapper {
+    /// PragmaCustomTwoQubitGate to be wrapped and converted to Python.
+    pub internal: PragmaCustomTwoQubitGate,
+}
+
+insert_pyany_to_operation!(
+    "PragmaCustomTwoQubitGate" =>{
+        let qubit_0 = op.call_method0("qubit_0")
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let qubit_0: usize = qubit_0.extract()
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let qubit_1 = op.call_method0("qubit_1")
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let qubit_1: usize = qubit_1.extract()
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let unitary = op.call_method0("to_matrix")
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let unitary_op: PyReadonlyArray2<Complex64> = unitary.extract()
+                      .map_err(|_| QoqoError::ConversionError)?;
+        let unitary_array: Array2<Complex64> = unitary_op.as_array().to_owned();
+        Ok(PragmaCustomTwoQubitGate::new(qubit_0, qubit_1, unitary_array).into())
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::PragmaCustomTwoQubitGate(internal) => {
+        {
+            let pyref: Py<PragmaCustomTwoQubitGateWrapper> =
+                Py::new(py, PragmaCustomTwoQubitGateWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl PragmaCustomTwoQubitGateWrapper {
+    /// Create a PragmaCustomTwoQubitGate.
+    ///
+    /// Args:
+    ///     qubit_0 (int): The first qubit the gate acts on.
+    ///     qubit_1 (int): The second qubit the gate acts on.
+    ///     unitary (np.ndarray): The 4x4 unitary matrix of the gate.
+    ///
+    /// Returns:
+    ///     self: The new PragmaCustomTwoQubitGate.
+    ///
+    /// Raises:
+    ///     TypeError: The unitary could not be converted to a complex matrix.
+    ///     ValueError: The matrix is not a 4x4 unitary matrix.
+    #[new]
+    fn new(qubit_0: usize, qubit_1: usize, unitary: &Bound<PyAny>) -> PyResult<Self> {
+        let unitary: Array2<Complex64> =
+            if let Ok(extracted) = unitary.extract::<PyReadonlyArray2<Complex64>>() {
+                extracted.as_array().to_owned()
+            } else if let Ok(extracted) = unitary.extract::<PyReadonlyArray2<f64>>() {
+                let matrix: Array2<f64> = extracted.as_array().to_owned();
+                matrix.map(|f| Complex64::new(*f, 0.0))
+            } else if let Ok(extracted) = unitary.extract::<PyReadonlyArray2<isize>>() {
+                let matrix: Array2<isize> = extracted.as_array().to_owned();
+                matrix.map(|f| Complex64::new((*f) as f64, 0.0))
+            } else {
+                return Err(PyTypeError::new_err(
+                    "Unitary could not be converted to a 2d array of complex numbers.",
+                ));
+            };
+        if unitary.shape() != [4, 4] {
+            return Err(PyValueError::new_err(format!(
+                "Unitary needs to be a 4x4 matrix, got shape {:?}.",
+                unitary.shape()
+            )));
+        }
+        let product = unitary.t().map(|x| x.conj()).dot(&unitary);
+        let identity: Array2<Complex64> = Array2::eye(4);
+        if product
+            .iter()
+            .zip(identity.iter())
+            .any(|(a, b)| (a - b).norm() > 1e-9)
+        {
+            return Err(PyValueError::new_err("Matrix is not unitary."));
+        }
+        Ok(Self {
+            internal: PragmaCustomTwoQubitGate::new(qubit_0, qubit_1, unitary),
+        })
+    }
+
+    /// Return the first qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     int: The first qubit.
+    fn qubit_0(&self) -> usize {
+        *self.internal.qubit_0()
+    }
+
+    /// Return the second qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     int: The second qubit.
+    fn qubit_1(&self) -> usize {
+        *self.internal.qubit_1()
+    }
+
+    /// Return the unitary matrix of the gate.
+    ///
+    /// Returns:
+    ///     np.ndarray: The 4x4 unitary matrix of the gate.
+    fn to_matrix(&self) -> Py<PyArray2<Complex64>> {
+        Python::with_gil(|py| -> Py<PyArray2<Complex64>> {
+            self.internal.unitary().to_pyarray_bound(py).unbind()
+        })
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, &[*self.internal.qubit_0(), *self.internal.qubit_1()])
+                .unwrap()
+                .to_object(py)
+        });
+        pyobject
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaCustomTwoQubitGate: A deep copy of self.
+    fn __copy__(&self) -> PragmaCustomTwoQubitGateWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaCustomTwoQubitGate: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> PragmaCustomTwoQubitGateWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaCustomTwoQubitGate.
+    ///
+    /// Args:
+    ///     self: The PragmaCustomTwoQubitGate object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other = crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(
+                "Right hand side cannot be converted to Operation",
+            )
+        })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(PragmaCustomTwoQubitGate);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            PragmaCustomTwoQubitGate::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operations::*;
+    use bincode::serialize;
+    use roqoqo::operations::*;
+    use std::collections::HashSet;
+
+    /// Test involved_qubits function for Pragmas with All
+    #[test]
+    fn test_pyo3_involved_qubits_all_change_device() {
+        let wrapped: Operation = PragmaActiveReset::new(0).into();
+        let input_definition: Operation = PragmaChangeDevice::new(&wrapped).unwrap().into();
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let operation = convert_operation_to_pyobject(input_definition).unwrap();
+            let to_involved = operation.call_method0(py, "involved_qubits").unwrap();
+            let involved_op: HashSet<String> =
+                HashSet::extract_bound(to_involved.bind(py)).unwrap();
+            let mut involved_param: HashSet<String> = HashSet::new();
+            involved_param.insert("All".to_owned());
+            assert_eq!(involved_op, involved_param);
+
+            assert!(PragmaChangeDeviceWrapper::new().is_err());
+        })
+    }
+
+    #[test]
+    fn test_pyo3_format_repr_change_device() {
+        let wrapped: Operation = PragmaActiveReset::new(0).into();
+        let input_measurement: Operation = PragmaChangeDevice::new(&wrapped).unwrap().into();
+        let format_repr = format!("PragmaChangeDevice {{ wrapped_tags: {:?}, wrapped_hqslang: {:?}, wrapped_operation: {:?} }}", wrapped.tags(), wrapped.hqslang(), serialize(&wrapped).unwrap());
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let operation = convert_operation_to_pyobject(input_measurement).unwrap();
+            let to_format = operation.call_method1(py, "__format__", ("",)).unwrap();
+            let format_op: String = String::extract_bound(to_format.bind(py)).unwrap();
+            let to_repr = operation.call_method0(py, "__repr__").unwrap();
+            let repr_op: String = String::extract_bound(to_repr.bind(py)).unwrap();
+            assert_eq!(format_op, format_repr);
+            assert_eq!(repr_op, format_repr);
+        })
+    }
+
+    #[test]
+    fn test_pyo3_wrapped_operation_deserialized_change_device() {
+        let wrapped: Operation = PragmaActiveReset::new(0).into();
+        let input_operation: Operation = PragmaChangeDevice::new(&wrapped).unwrap().into();
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let operation = convert_operation_to_pyobject(input_operation).unwrap();
+            let deserialized = operation
+                .call_method0(py, "wrapped_operation_deserialized")
+                .unwrap();
+            let comparison = bool::extract_bound(
+                deserialized
+                    .call_method1(
+                        py,
+                        "__eq__",
+                        (convert_operation_to_pyobject(wrapped).unwrap(),),
+                    )
+                    .unwrap()
+                    .bind(py),
+            )
+            .unwrap();
+            assert!(comparison);
+
+            let mut invalid =
+                PragmaChangeDevice::new(&Operation::from(PragmaActiveReset::new(0))).unwrap();
+            invalid.wrapped_operation = vec![255, 255];
+            let operation = convert_operation_to_pyobject(invalid.into()).unwrap();
+            let error = operation.call_method0(py, "wrapped_operation_deserialized");
+            assert!(error.is_err());
         })
     }
 
@@ -516,3 +516,480 @@ struct GPi2 {
 struct Identity {
     qubit: usize,
 }
+
+/// Implements a rotation around an arbitrary axis given in cartesian coordinates.
+///
+/// .. math::
+///     U = \exp\left(-i \frac{\theta}{2} (v_x X + v_y Y + v_z Z)\right)
+///
+/// with the rotation axis normalized to unit length
+///
+/// .. math::
+///  (v_x, v_y, v_z) = \frac{(x, y, z)}{\sqrt{x^2 + y^2 + z^2}} \ .
+///
+/// The axis components given on construction do not need to be normalized,
+/// the normalized components are stored.
+///
+/// Args:
+///     qubit (int): The qubit the unitary gate is applied to.
+///     theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+///     axis_x (CalculatorFloat): The x component of the rotation axis.
+///     axis_y (CalculatorFloat): The y component of the rotation axis.
+///     axis_z (CalculatorFloat): The z component of the rotation axis.
+///
+/// Raises:
+///     ValueError: The rotation axis has zero length.
+#[pyclass(name = "RotateAxis", module = "qoqo.operations")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotateAxisWrapper {
+    /// Internal storage of [roqoqo::RotateAxis]
+    pub internal: RotateAxis,
+}
+
+insert_pyany_to_operation!(
+    "RotateAxis" =>{
+        if let Ok(try_downcast) = op.extract::<RotateAxisWrapper>() {
+            Ok(try_downcast.internal.into())
+        } else {
+            let qbt = op.call_method0("qubit")
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let qubit: usize = qbt.extract()
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let th = op.call_method0("theta")
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let theta = convert_into_calculator_float(&th)
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let ax = op.call_method0("axis_x")
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let axis_x = convert_into_calculator_float(&ax)
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let ay = op.call_method0("axis_y")
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let axis_y = convert_into_calculator_float(&ay)
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let az = op.call_method0("axis_z")
+                        .map_err(|_| QoqoError::ConversionError)?;
+            let axis_z = convert_into_calculator_float(&az)
+                        .map_err(|_| QoqoError::ConversionError)?;
+            Ok(RotateAxis::new(qubit, theta, axis_x, axis_y, axis_z)
+                .map_err(|_| QoqoError::ConversionError)?
+                .into())
+        }
+    }
+);
+insert_operation_to_pyobject!(
+    Operation::RotateAxis(internal) => {
+        {
+            let pyref: Py<RotateAxisWrapper> =
+                Py::new(py, RotateAxisWrapper { internal }).unwrap();
+            let pyobject: PyObject = pyref.to_object(py);
+            Ok(pyobject)
+        }
+    }
+);
+
+#[pymethods]
+impl RotateAxisWrapper {
+    /// Create a new RotateAxis.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit the unitary gate is applied to.
+    ///     theta (CalculatorFloat): The angle :math:`\theta` of the rotation.
+    ///     axis_x (CalculatorFloat): The x component of the rotation axis.
+    ///     axis_y (CalculatorFloat): The y component of the rotation axis.
+    ///     axis_z (CalculatorFloat): The z component of the rotation axis.
+    ///
+    /// Returns:
+    ///     self: The new RotateAxis with the normalized rotation axis.
+    ///
+    /// Raises:
+    ///     TypeError: Argument cannot be converted to CalculatorFloat.
+    ///     ValueError: The rotation axis has zero length.
+    #[new]
+    fn new(
+        qubit: usize,
+        theta: &Bound<PyAny>,
+        axis_x: &Bound<PyAny>,
+        axis_y: &Bound<PyAny>,
+        axis_z: &Bound<PyAny>,
+    ) -> PyResult<Self> {
+        let convert = |value: &Bound<PyAny>| -> PyResult<CalculatorFloat> {
+            convert_into_calculator_float(value).map_err(|x| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Argument cannot be converted to CalculatorFloat {:?}",
+                    x
+                ))
+            })
+        };
+        let internal = RotateAxis::new(
+            qubit,
+            convert(theta)?,
+            convert(axis_x)?,
+            convert(axis_y)?,
+            convert(axis_z)?,
+        )
+        .map_err(|x| PyValueError::new_err(format!("{:?}", x)))?;
+        Ok(Self { internal })
+    }
+
+    /// Return the qubit the operation acts on.
+    ///
+    /// Returns:
+    ///     int: The qubit of the operation.
+    fn qubit(&self) -> usize {
+        *self.internal.qubit()
+    }
+
+    /// Return the angle of the rotation.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The angle :math:`\theta` of the rotation.
+    fn theta(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.theta().clone(),
+        }
+    }
+
+    /// Return the x component of the normalized rotation axis.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The x component of the normalized rotation axis.
+    fn axis_x(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.axis_x().clone(),
+        }
+    }
+
+    /// Return the y component of the normalized rotation axis.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The y component of the normalized rotation axis.
+    fn axis_y(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.axis_y().clone(),
+        }
+    }
+
+    /// Return the z component of the normalized rotation axis.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The z component of the normalized rotation axis.
+    fn axis_z(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.axis_z().clone(),
+        }
+    }
+
+    /// Return the global phase :math:`g` of the unitary gate.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The global phase of the gate.
+    fn global_phase(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.global_phase(),
+        }
+    }
+
+    /// Return the property alpha_r :math:`\alpha_r` of the unitary gate.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The real part of the on-diagonal elements of the unitary matrix.
+    fn alpha_r(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.alpha_r(),
+        }
+    }
+
+    /// Return the property alpha_i :math:`\alpha_i` of the unitary gate.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The imaginary part of the on-diagonal elements of the unitary matrix.
+    fn alpha_i(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.alpha_i(),
+        }
+    }
+
+    /// Return the property beta_r :math:`\beta_r` of the unitary gate.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The real part of the off-diagonal elements of the unitary matrix.
+    fn beta_r(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.beta_r(),
+        }
+    }
+
+    /// Return the property beta_i :math:`\beta_i` of the unitary gate.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The imaginary part of the off-diagonal elements of the unitary matrix.
+    fn beta_i(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.beta_i(),
+        }
+    }
+
+    /// Multiply two compatible operations implementing OperateSingleQubitGate.
+    ///
+    /// Args:
+    ///     other (Operation): An Operation implementing OperateSingleQubitGate.
+    ///
+    /// Returns:
+    ///     Operation: The multiplied single qubit gate.
+    ///
+    /// Raises:
+    ///     TypeError: Right hand side cannot be converted to Operation.
+    ///     RuntimeError: The multiplication failed.
+    fn mul(&self, other: &Bound<PyAny>) -> PyResult<SingleQubitGateWrapper> {
+        let other: Operation =
+            crate::operations::convert_pyany_to_operation(other).map_err(|x| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Right hand side cannot be converted to Operation {:?}",
+                    x
+                ))
+            })?;
+        let other_converted: SingleQubitGateOperation = other.try_into().map_err(|x| {
+            PyRuntimeError::new_err(format!(
+                "Conversion to SingleQubitGateOperation failed {:?}",
+                x
+            ))
+        })?;
+        let multiplied = self
+            .internal
+            .mul(&other_converted)
+            .map_err(|x| PyRuntimeError::new_err(format!("Multiplication failed {:?}", x)))?;
+        Ok(SingleQubitGateWrapper {
+            internal: multiplied,
+        })
+    }
+
+    /// Return the unitary matrix of the gate.
+    ///
+    /// Returns:
+    ///     np.ndarray: The unitary matrix of the gate.
+    ///
+    /// Raises:
+    ///     ValueError: The unitary matrix could not be constructed.
+    fn unitary_matrix(&self) -> PyResult<Py<PyArray2<Complex64>>> {
+        Python::with_gil(|py| -> PyResult<Py<PyArray2<Complex64>>> {
+            Ok(self
+                .internal
+                .unitary_matrix()
+                .map_err(|x| {
+                    PyValueError::new_err(format!(
+                        "Error symbolic operation cannot return float unitary matrix {:?}",
+                        x
+                    ))
+                })?
+                .to_pyarray_bound(py)
+                .as_gil_ref()
+                .into())
+        })
+    }
+
+    /// Return the rotated gate raised to a power.
+    ///
+    /// Args:
+    ///     power (CalculatorFloat): The exponent of the power operation.
+    ///
+    /// Returns:
+    ///     self: The gate raised to the power of `power`.
+    fn powercf(&self, power: CalculatorFloatWrapper) -> Self {
+        Self {
+            internal: self.internal.powercf(power.internal),
+        }
+    }
+
+    #[cfg(feature = "overrotate")]
+    /// Return a clone of the gate with the rotation angle statistically overrotated.
+    fn overrotate(&self, amplitude: &f64, variance: &f64) -> Self {
+        Self {
+            internal: self.internal.overrotate(amplitude, variance),
+        }
+    }
+
+    /// List all involved qubits.
+    ///
+    /// Returns:
+    ///     Set[int]: The involved qubits of the operation.
+    fn involved_qubits(&self) -> PyObject {
+        let pyobject: PyObject = Python::with_gil(|py| -> PyObject {
+            PySet::new_bound(py, &[*self.internal.qubit()])
+                .unwrap()
+                .to_object(py)
+        });
+        pyobject
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     List[str]: The tags of the Operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Substitute the symbolic parameters in a clone of the operation according to the input.
+    ///
+    /// Args:
+    ///     substitution_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed or the substituted rotation axis has zero length.
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    PyRuntimeError::new_err(format!("Parameter Substitution failed: {:?}", x))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the RotateAxis operation.
+    ///
+    /// Args:
+    ///     mapping (Dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the operation.
+    ///
+    /// Returns:
+    ///     self: The operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    fn remap_qubits(&self, mapping: HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|x| PyRuntimeError::new_err(format!("Qubit remapping failed: {:?}", x)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     RotateAxis: A deep copy of self.
+    fn __copy__(&self) -> RotateAxisWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the operation.
+    ///
+    /// Returns:
+    ///     RotateAxis: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> RotateAxisWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on RotateAxis.
+    ///
+    /// Args:
+    ///     self: The RotateAxis object.
+    ///     other: The object to compare self to.
+    ///     op: Type of comparison.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other: Operation =
+            crate::operations::convert_pyany_to_operation(other).map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "Right hand side cannot be converted to Operation",
+                )
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => {
+                Ok(Operation::from(self.internal.clone()) == other)
+            }
+            pyo3::class::basic::CompareOp::Ne => {
+                Ok(Operation::from(self.internal.clone()) != other)
+            }
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(RotateAxis);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Returns the current version of the qoqo library .
+    ///
+    /// Returns:
+    ///     str: The current version of the library.
+    #[staticmethod]
+    pub fn current_version() -> String {
+        ROQOQO_VERSION.to_string()
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the minimum version of qoqo that supports this object.
+    ///
+    /// Returns:
+    ///     str: The minimum version of the qoqo library to deserialize this object.
+    pub fn min_supported_version(&self) -> String {
+        let min_version: (u32, u32, u32) =
+            RotateAxis::minimum_supported_roqoqo_version(&self.internal);
+        format!("{}.{}.{}", min_version.0, min_version.1, min_version.2)
+    }
+}
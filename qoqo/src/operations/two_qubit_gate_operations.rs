@@ -207,6 +207,102 @@ pub struct VariableMSXX {
     theta: CalculatorFloat,
 }
 
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(
+    Operate,
+    OperateTwoQubit,
+    Rotate,
+    OperateGate,
+    OperateTwoQubitGate,
+    JsonSchema
+)]
+/// The two-qubit rotation gate under the XX Ising coupling.
+///
+/// Applies the unitary exp(-i * theta/2 * X_control X_target) to two qubits.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         \cos(\theta/2) & 0 & 0 & -i \sin(\theta/2) \\\\
+///         0 & \cos(\theta/2) & -i \sin(\theta/2) & 0 \\\\
+///         0 & -i \sin(\theta/2) & \cos(\theta/2) & 0 \\\\
+///         -i \sin(\theta/2) & 0 & 0 & \cos(\theta/2)
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+///     target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+///     theta (CalculatorFloat): The rotation angle :math:`\theta`.
+///
+pub struct RXX {
+    control: usize,
+    target: usize,
+    theta: CalculatorFloat,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(
+    Operate,
+    OperateTwoQubit,
+    Rotate,
+    OperateGate,
+    OperateTwoQubitGate,
+    JsonSchema
+)]
+/// The two-qubit rotation gate under the YY Ising coupling.
+///
+/// Applies the unitary exp(-i * theta/2 * Y_control Y_target) to two qubits.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         \cos(\theta/2) & 0 & 0 & i \sin(\theta/2) \\\\
+///         0 & \cos(\theta/2) & -i \sin(\theta/2) & 0 \\\\
+///         0 & -i \sin(\theta/2) & \cos(\theta/2) & 0 \\\\
+///         i \sin(\theta/2) & 0 & 0 & \cos(\theta/2)
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+///     target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+///     theta (CalculatorFloat): The rotation angle :math:`\theta`.
+///
+pub struct RYY {
+    control: usize,
+    target: usize,
+    theta: CalculatorFloat,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(
+    Operate,
+    OperateTwoQubit,
+    Rotate,
+    OperateGate,
+    OperateTwoQubitGate,
+    JsonSchema
+)]
+/// The two-qubit rotation gate under the ZZ Ising coupling.
+///
+/// Applies the unitary exp(-i * theta/2 * Z_control Z_target) to two qubits.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         e^{-i \theta/2} & 0 & 0 & 0 \\\\
+///         0 & e^{i \theta/2} & 0 & 0 \\\\
+///         0 & 0 & e^{i \theta/2} & 0 \\\\
+///         0 & 0 & 0 & e^{-i \theta/2}
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+///     target (int): The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+///     theta (CalculatorFloat): The rotation angle :math:`\theta`.
+///
+pub struct RZZ {
+    control: usize,
+    target: usize,
+    theta: CalculatorFloat,
+}
+
 #[allow(clippy::upper_case_acronyms)]
 #[wrap(
     Operate,
@@ -685,3 +781,105 @@ pub struct EchoCrossResonance {
     control: usize,
     target: usize,
 }
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(Operate, OperateTwoQubit, OperateGate, OperateTwoQubitGate, JsonSchema)]
+#[derive(Eq)]
+/// The controlled Hadamard quantum operation.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         1 & 0 & 0 & 0 \\\\
+///         0 & 1 & 0 & 0 \\\\
+///         0 & 0 & \frac{1}{\sqrt{2}} & \frac{1}{\sqrt{2}} \\\\
+///         0 & 0 & \frac{1}{\sqrt{2}} & \frac{-1}{\sqrt{2}}
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Hadamard gate on the target qubit.
+///     target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the Hadamard gate is applied to.
+///
+pub struct ControlledHadamard {
+    control: usize,
+    target: usize,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(Operate, OperateTwoQubit, OperateGate, OperateTwoQubitGate, JsonSchema)]
+#[derive(Eq)]
+/// The square root CNOT quantum operation.
+///
+/// Square root version of the CNOT gate so that SqrtCNOT * SqrtCNOT = CNOT.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         1 & 0 & 0 & 0 \\\\
+///         0 & 1 & 0 & 0 \\\\
+///         0 & 0 & \frac{1+i}{2} & \frac{1-i}{2} \\\\
+///         0 & 0 & \frac{1-i}{2} & \frac{1+i}{2}
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the gate on the target qubit.
+///     target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the gate is applied to.
+///
+pub struct SqrtCNOT {
+    control: usize,
+    target: usize,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(Operate, OperateTwoQubit, OperateGate, OperateTwoQubitGate, JsonSchema)]
+#[derive(Eq)]
+/// The inverse square root CNOT quantum operation.
+///
+/// InvSqrtCNOT * SqrtCNOT = Identity.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         1 & 0 & 0 & 0 \\\\
+///         0 & 1 & 0 & 0 \\\\
+///         0 & 0 & \frac{1-i}{2} & \frac{1+i}{2} \\\\
+///         0 & 0 & \frac{1+i}{2} & \frac{1-i}{2}
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the gate on the target qubit.
+///     target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the gate is applied to.
+///
+pub struct InvSqrtCNOT {
+    control: usize,
+    target: usize,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[wrap(
+    Operate,
+    OperateTwoQubit,
+    Rotate,
+    OperateGate,
+    OperateTwoQubitGate,
+    JsonSchema
+)]
+/// The ZX coupling gate.
+///
+/// Applies the unitary exp(-i * theta * Z_control X_target) to two qubits.
+///
+/// .. math::
+///     U = \begin{pmatrix}
+///         \cos(\theta) & -i \sin(\theta) & 0 & 0 \\\\
+///         -i \sin(\theta) & \cos(\theta) & 0 & 0 \\\\
+///         0 & 0 & \cos(\theta) & i \sin(\theta) \\\\
+///         0 & 0 & i \sin(\theta) & \cos(\theta)
+///         \end{pmatrix}
+///
+/// Args:
+///     control (int): The index of the most significant qubit in the unitary representation. Here, the qubit the Pauli Z operator acts on.
+///     target (int): The index of the least significant qubit in the unitary representation. Here, the qubit the Pauli X operator acts on.
+///     theta (CalculatorFloat): The coupling angle :math:`\theta`.
+///
+pub struct ZXCoupling {
+    control: usize,
+    target: usize,
+    theta: CalculatorFloat,
+}
@@ -10,19 +10,22 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
+use crate::circuit::{pennylane_operation, symbolic_parameter_names};
 use crate::measurements::{
     CheatedPauliZProductWrapper, CheatedWrapper, ClassicalRegisterWrapper, PauliZProductWrapper,
 };
-use crate::{QoqoError, QOQO_VERSION};
+use crate::{convert_into_circuit, CircuitWrapper, QoqoError, QOQO_VERSION};
 use bincode::{deserialize, serialize};
-use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::exceptions::{PyIndexError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
-use roqoqo::measurements::Measure;
+use pyo3::types::{PyByteArray, PyCFunction, PyDict, PyTuple};
+use roqoqo::measurements::{Measure, PauliProductsToExpVal, PauliZProduct};
+use roqoqo::operations::Operate;
 #[cfg(feature = "json_schema")]
 use roqoqo::operations::SupportedVersion;
+use roqoqo::Circuit;
 use roqoqo::QuantumProgram;
 use roqoqo::ROQOQO_VERSION;
 
@@ -189,6 +192,20 @@ impl QuantumProgramWrapper {
         }
     }
 
+    /// Returns the name of the measurement type of the QuantumProgram.
+    ///
+    /// Returns:
+    ///     str: The measurement type, i.e. PauliZProduct, CheatedPauliZProduct, Cheated or ClassicalRegister.
+    pub fn measurement_type(&self) -> &'static str {
+        match self.internal {
+            QuantumProgram::PauliZProduct { .. } => "PauliZProduct",
+            QuantumProgram::CheatedPauliZProduct { .. } => "CheatedPauliZProduct",
+            QuantumProgram::Cheated { .. } => "Cheated",
+            QuantumProgram::ClassicalRegister { .. } => "ClassicalRegister",
+            _ => panic!("Unknown type of QuantumProgram"),
+        }
+    }
+
     /// Returns the input_parameter_names attribute of the qoqo QuantumProgram.
     ///
     /// Returns:
@@ -215,6 +232,245 @@ impl QuantumProgramWrapper {
         }
     }
 
+    /// Returns the number of input parameters of the qoqo QuantumProgram.
+    ///
+    /// Returns:
+    ///     int: The number of input parameter names.
+    pub fn input_parameter_count(&self) -> usize {
+        self.input_parameter_names().len()
+    }
+
+    /// Returns whether the qoqo QuantumProgram has any input parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the QuantumProgram has at least one input parameter name.
+    pub fn has_parameters(&self) -> bool {
+        !self.input_parameter_names().is_empty()
+    }
+
+    /// Returns the circuits of the measurement of the qoqo QuantumProgram.
+    ///
+    /// Returns:
+    ///     List[Circuit]: The circuits that are executed after the constant circuit.
+    pub fn circuits(&self) -> Vec<CircuitWrapper> {
+        let circuits: Vec<Circuit> = match &self.internal {
+            QuantumProgram::PauliZProduct { measurement, .. } => {
+                measurement.circuits().cloned().collect()
+            }
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => {
+                measurement.circuits().cloned().collect()
+            }
+            QuantumProgram::Cheated { measurement, .. } => {
+                measurement.circuits().cloned().collect()
+            }
+            QuantumProgram::ClassicalRegister { measurement, .. } => {
+                measurement.circuits().cloned().collect()
+            }
+            _ => panic!("Unknown type of QuantumProgram"),
+        };
+        circuits
+            .into_iter()
+            .map(|internal| CircuitWrapper { internal })
+            .collect()
+    }
+
+    /// Returns the number of circuits of the measurement of the qoqo QuantumProgram.
+    ///
+    /// The constant circuit is not counted.
+    ///
+    /// Returns:
+    ///     int: The number of circuits that are executed after the constant circuit.
+    pub fn circuit_count(&self) -> usize {
+        match &self.internal {
+            QuantumProgram::PauliZProduct { measurement, .. } => measurement.circuits().count(),
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => {
+                measurement.circuits().count()
+            }
+            QuantumProgram::Cheated { measurement, .. } => measurement.circuits().count(),
+            QuantumProgram::ClassicalRegister { measurement, .. } => measurement.circuits().count(),
+            _ => panic!("Unknown type of QuantumProgram"),
+        }
+    }
+
+    /// Returns the circuit at the given index in the circuits of the measurement.
+    ///
+    /// Args:
+    ///     index (int): The index of the circuit.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit at the index.
+    ///
+    /// Raises:
+    ///     IndexError: Index out of range.
+    pub fn get_circuit(&self, index: usize) -> PyResult<CircuitWrapper> {
+        let circuit: Option<&Circuit> = match &self.internal {
+            QuantumProgram::PauliZProduct { measurement, .. } => measurement.circuits().nth(index),
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => {
+                measurement.circuits().nth(index)
+            }
+            QuantumProgram::Cheated { measurement, .. } => measurement.circuits().nth(index),
+            QuantumProgram::ClassicalRegister { measurement, .. } => {
+                measurement.circuits().nth(index)
+            }
+            _ => panic!("Unknown type of QuantumProgram"),
+        };
+        circuit
+            .map(|internal| CircuitWrapper {
+                internal: internal.clone(),
+            })
+            .ok_or_else(|| PyIndexError::new_err(format!("Index {} out of range", index)))
+    }
+
+    /// Returns the constant circuit of the measurement of the qoqo QuantumProgram.
+    ///
+    /// Returns:
+    ///     Optional[Circuit]: The constant circuit executed before each circuit, if set.
+    pub fn constant_circuit(&self) -> Option<CircuitWrapper> {
+        let constant_circuit: &Option<Circuit> = match &self.internal {
+            QuantumProgram::PauliZProduct { measurement, .. } => measurement.constant_circuit(),
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => {
+                measurement.constant_circuit()
+            }
+            QuantumProgram::Cheated { measurement, .. } => measurement.constant_circuit(),
+            QuantumProgram::ClassicalRegister { measurement, .. } => measurement.constant_circuit(),
+            _ => panic!("Unknown type of QuantumProgram"),
+        };
+        constant_circuit
+            .clone()
+            .map(|internal| CircuitWrapper { internal })
+    }
+
+    /// Check that no circuit of the QuantumProgram contains symbolic parameters.
+    ///
+    /// The constant circuit and all circuits of the measurement are checked.
+    ///
+    /// Raises:
+    ///     RuntimeError: The QuantumProgram contains symbolic parameters, listing their names.
+    pub fn assert_no_symbolic_parameters(&self) -> PyResult<()> {
+        let circuits: Vec<&Circuit> = match &self.internal {
+            QuantumProgram::PauliZProduct { measurement, .. } => measurement
+                .constant_circuit()
+                .iter()
+                .chain(measurement.circuits())
+                .collect(),
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => measurement
+                .constant_circuit()
+                .iter()
+                .chain(measurement.circuits())
+                .collect(),
+            QuantumProgram::Cheated { measurement, .. } => measurement
+                .constant_circuit()
+                .iter()
+                .chain(measurement.circuits())
+                .collect(),
+            QuantumProgram::ClassicalRegister { measurement, .. } => measurement
+                .constant_circuit()
+                .iter()
+                .chain(measurement.circuits())
+                .collect(),
+            _ => panic!("Unknown type of QuantumProgram"),
+        };
+        let names: BTreeSet<String> = circuits
+            .into_iter()
+            .flat_map(symbolic_parameter_names)
+            .collect();
+        if names.is_empty() {
+            return Ok(());
+        }
+        Err(PyRuntimeError::new_err(format!(
+            "QuantumProgram contains unresolved symbolic parameters: {:?}",
+            names.into_iter().collect::<Vec<String>>()
+        )))
+    }
+
+    /// Returns a copy of the QuantumProgram with new input parameter names.
+    ///
+    /// The original QuantumProgram is not changed.
+    ///
+    /// Args:
+    ///     input_parameter_names (List[str]): The new input parameter names.
+    ///
+    /// Returns:
+    ///     QuantumProgram: The QuantumProgram with the new input parameter names.
+    pub fn with_input_parameter_names(&self, input_parameter_names: Vec<String>) -> Self {
+        let mut internal = self.internal.clone();
+        match &mut internal {
+            QuantumProgram::PauliZProduct {
+                measurement: _,
+                input_parameter_names: names,
+            } => *names = input_parameter_names,
+            QuantumProgram::CheatedPauliZProduct {
+                measurement: _,
+                input_parameter_names: names,
+            } => *names = input_parameter_names,
+            QuantumProgram::Cheated {
+                measurement: _,
+                input_parameter_names: names,
+            } => *names = input_parameter_names,
+            QuantumProgram::ClassicalRegister {
+                measurement: _,
+                input_parameter_names: names,
+            } => *names = input_parameter_names,
+            _ => panic!("Unknown type of QuantumProgram"),
+        }
+        Self { internal }
+    }
+
+    /// Returns a copy of the QuantumProgram with a new measurement.
+    ///
+    /// The input parameter names are kept, the original QuantumProgram is not changed.
+    ///
+    /// Args:
+    ///     measurement: The new measurement of the QuantumProgram.
+    ///
+    /// Returns:
+    ///     QuantumProgram: The QuantumProgram with the new measurement.
+    ///
+    /// Raises:
+    ///     TypeError: measurement is not of type Measurement.
+    pub fn with_measurement(&self, measurement: &Bound<PyAny>) -> PyResult<Self> {
+        Self::new(measurement, self.input_parameter_names())
+    }
+
+    /// Returns a copy of the QuantumProgram with a new constant circuit.
+    ///
+    /// The original QuantumProgram is not changed.
+    ///
+    /// Args:
+    ///     circuit (Optional[Circuit]): The new constant circuit, None removes the constant circuit.
+    ///
+    /// Returns:
+    ///     QuantumProgram: The QuantumProgram with the new constant circuit.
+    ///
+    /// Raises:
+    ///     TypeError: circuit is not of type Circuit.
+    pub fn with_constant_circuit(&self, circuit: Option<&Bound<PyAny>>) -> PyResult<Self> {
+        let new_constant_circuit = match circuit {
+            Some(circuit) => Some(
+                convert_into_circuit(circuit)
+                    .map_err(|_| PyTypeError::new_err("circuit is not of type Circuit"))?,
+            ),
+            None => None,
+        };
+        let mut internal = self.internal.clone();
+        match &mut internal {
+            QuantumProgram::PauliZProduct { measurement, .. } => {
+                measurement.constant_circuit = new_constant_circuit
+            }
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => {
+                measurement.constant_circuit = new_constant_circuit
+            }
+            QuantumProgram::Cheated { measurement, .. } => {
+                measurement.constant_circuit = new_constant_circuit
+            }
+            QuantumProgram::ClassicalRegister { measurement, .. } => {
+                measurement.constant_circuit = new_constant_circuit
+            }
+            _ => panic!("Unknown type of QuantumProgram"),
+        }
+        Ok(Self { internal })
+    }
+
     /// Runs the QuantumProgram and returns expectation values.
     ///
     /// Runs the quantum programm for a given set of parameters passed in the same order as the parameters
@@ -284,6 +540,103 @@ impl QuantumProgramWrapper {
         }
     }
 
+    /// Convert the QuantumProgram to a Pennylane QNode.
+    ///
+    /// The QNode wraps a function taking one float argument for each of the `input_parameter_names`.
+    /// When called, the parameters are substituted into the QuantumProgram, the gate operations of the
+    /// constant circuit and the measurement circuit are applied on the device and the expectation value
+    /// of the measurement is returned. Gate operations are converted as in `Circuit.to_pennylane_tape`.
+    /// PragmaLoop operations are flattened, all other PRAGMA operations are ignored.
+    ///
+    /// Only PauliZProduct measurements with a single circuit and a single linear expectation value are supported.
+    ///
+    /// Args:
+    ///     device (pennylane.Device): The Pennylane device the QNode is executed on.
+    ///
+    /// Returns:
+    ///     pennylane.QNode: The QNode returning the expectation value of the measurement.
+    ///
+    /// Raises:
+    ///     ImportError: Pennylane is not installed.
+    ///     TypeError: The measurement of the QuantumProgram is not a PauliZProduct measurement.
+    ///     ValueError: The measurement does not have a single circuit and a single linear expectation value.
+    pub fn to_pennylane_qnode<'py>(
+        &self,
+        device: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = device.py();
+        let (measurement, input_parameter_names) = match &self.internal {
+            QuantumProgram::PauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => (measurement.clone(), input_parameter_names.clone()),
+            _ => {
+                return Err(PyTypeError::new_err(
+                    "Only QuantumPrograms with a PauliZProduct measurement can be converted to a Pennylane QNode",
+                ))
+            }
+        };
+        if measurement.circuits.len() != 1 {
+            return Err(PyValueError::new_err(format!(
+                "Only PauliZProduct measurements with a single circuit can be converted to a Pennylane QNode, {} circuits given",
+                measurement.circuits.len()
+            )));
+        }
+        let observable_terms = pennylane_observable_terms(&measurement)?;
+        let qml = py.import_bound("pennylane")?;
+
+        let apply_program = PyCFunction::new_closure_bound(
+            py,
+            None,
+            None,
+            move |args: &Bound<PyTuple>, _kwargs: Option<&Bound<PyDict>>| -> PyResult<PyObject> {
+                let py = args.py();
+                let parameters: Vec<f64> = args.extract()?;
+                if parameters.len() != input_parameter_names.len() {
+                    return Err(PyValueError::new_err(format!(
+                        "Wrong number of parameters {} parameters expected {} parameters given",
+                        input_parameter_names.len(),
+                        parameters.len()
+                    )));
+                };
+                let substituted_parameters: HashMap<String, f64> = input_parameter_names
+                    .iter()
+                    .zip(parameters.iter())
+                    .map(|(key, value)| (key.clone(), *value))
+                    .collect();
+                let substituted_measurement = measurement
+                    .substitute_parameters(substituted_parameters)
+                    .map_err(|err| {
+                        PyRuntimeError::new_err(format!("Applying parameters failed {:?}", err))
+                    })?;
+                let qml = py.import_bound("pennylane")?;
+                if let Some(constant_circuit) = substituted_measurement.constant_circuit.as_ref() {
+                    apply_pennylane_operations(&qml, constant_circuit)?;
+                }
+                for circuit in substituted_measurement.circuits.iter() {
+                    apply_pennylane_operations(&qml, circuit)?;
+                }
+                let mut coefficients: Vec<f64> = Vec::with_capacity(observable_terms.len());
+                let mut observables: Vec<Bound<PyAny>> = Vec::with_capacity(observable_terms.len());
+                for (coefficient, qubits) in observable_terms.iter() {
+                    coefficients.push(*coefficient);
+                    observables.push(pauli_z_product_observable(&qml, qubits)?);
+                }
+                let hamiltonian = qml.call_method1("Hamiltonian", (coefficients, observables))?;
+                Ok(qml.call_method1("expval", (hamiltonian,))?.unbind())
+            },
+        )?;
+        // The QNode inspects the signature of the function, which is not available for a builtin closure
+        let namespace = PyDict::new_bound(py);
+        namespace.set_item("_apply_program", apply_program)?;
+        let function = py.eval_bound(
+            "lambda *parameters: _apply_program(*parameters)",
+            Some(&namespace),
+            None,
+        )?;
+        qml.call_method1("QNode", (function, device))
+    }
+
     /// Return a copy of the QuantumProgram (copy here produces a deepcopy).
     ///
     /// Returns:
@@ -355,8 +708,11 @@ impl QuantumProgramWrapper {
             .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
         Ok(Self {
-            internal: deserialize(&bytes[..]).map_err(|_| {
-                PyValueError::new_err("Input cannot be deserialized to QuantumProgram")
+            internal: deserialize(&bytes[..]).map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Input cannot be deserialized to QuantumProgram: {}",
+                    err
+                ))
             })?,
         })
     }
@@ -387,8 +743,11 @@ impl QuantumProgramWrapper {
     #[staticmethod]
     fn from_json(input: &str) -> PyResult<Self> {
         Ok(Self {
-            internal: serde_json::from_str(input).map_err(|_| {
-                PyValueError::new_err("Input cannot be deserialized to QuantumProgram")
+            internal: serde_json::from_str(input).map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Input cannot be deserialized to QuantumProgram: {}",
+                    err
+                ))
             })?,
         })
     }
@@ -458,6 +817,73 @@ impl QuantumProgramWrapper {
     }
 }
 
+// Helper function returning the coefficients and qubits of the Pauli products of the single linear expectation value.
+fn pennylane_observable_terms(measurement: &PauliZProduct) -> PyResult<Vec<(f64, Vec<usize>)>> {
+    let exp_vals: Vec<&PauliProductsToExpVal> =
+        measurement.input.measured_exp_vals.values().collect();
+    let linear = match exp_vals.as_slice() {
+        [PauliProductsToExpVal::Linear(linear)] => linear,
+        _ => {
+            return Err(PyValueError::new_err(
+                "Only PauliZProduct measurements with a single linear expectation value can be converted to a Pennylane QNode",
+            ))
+        }
+    };
+    let qubit_masks: HashMap<usize, &Vec<usize>> = measurement
+        .input
+        .pauli_product_qubit_masks
+        .values()
+        .flat_map(|masks| masks.iter().map(|(index, qubits)| (*index, qubits)))
+        .collect();
+    let mut terms: Vec<(f64, Vec<usize>)> = Vec::with_capacity(linear.len());
+    for (index, coefficient) in linear.iter() {
+        let qubits = qubit_masks.get(index).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Pauli product {} is not defined in the measurement input",
+                index
+            ))
+        })?;
+        terms.push((*coefficient, (*qubits).clone()));
+    }
+    Ok(terms)
+}
+
+// Helper function returning the Pennylane observable of a product of PauliZ operators.
+fn pauli_z_product_observable<'py>(
+    qml: &Bound<'py, PyModule>,
+    qubits: &[usize],
+) -> PyResult<Bound<'py, PyAny>> {
+    match qubits.split_first() {
+        None => qml.call_method1("Identity", (0,)),
+        Some((first, rest)) => {
+            let mut observable = qml.call_method1("PauliZ", (*first,))?;
+            for qubit in rest {
+                let factor = qml.call_method1("PauliZ", (*qubit,))?;
+                observable = observable.call_method1("__matmul__", (factor,))?;
+            }
+            Ok(observable)
+        }
+    }
+}
+
+// Helper function applying the gate operations of a circuit on the active Pennylane tape.
+fn apply_pennylane_operations(qml: &Bound<PyModule>, circuit: &Circuit) -> PyResult<()> {
+    let circuit = circuit.flatten_pragma_loops().map_err(|err| {
+        PyRuntimeError::new_err(format!("Cannot flatten PragmaLoop operations: {:?}", err))
+    })?;
+    for operation in circuit.iter() {
+        let tags = operation.tags();
+        if tags.contains(&"PragmaOperation")
+            || tags.contains(&"Measurement")
+            || tags.contains(&"Definition")
+        {
+            continue;
+        }
+        pennylane_operation(qml, operation)?;
+    }
+    Ok(())
+}
+
 /// Convert generic python object to [roqoqo::QuantumProgram].
 ///
 /// Fallible conversion of generic python object to [roqoqo::QuantumProgram].
@@ -10,17 +10,20 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyRuntimeError};
 use pyo3::prelude::*;
+use pyo3::types::PyByteArray;
 use qoqo::measurements::{PauliZProductInputWrapper, PauliZProductWrapper};
 use qoqo::operations::{
     convert_operation_to_pyobject, PragmaOverrotationWrapper, RotateXWrapper, RotateYWrapper,
 };
-use qoqo::{CircuitWrapper, OperationIteratorWrapper, QOQO_VERSION};
+use qoqo::{
+    circuits_from_bincode, circuits_to_bincode, CircuitWrapper, OperationIteratorWrapper,
+    QOQO_VERSION,
+};
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations::Operation;
 use roqoqo::operations::*;
-#[cfg(feature = "json_schema")]
 use roqoqo::Circuit;
 use roqoqo::ROQOQO_VERSION;
 use std::collections::{HashMap, HashSet};
@@ -119,6 +122,69 @@ fn test_substitute_parameters() {
     })
 }
 
+/// Test make_concrete function of Circuit
+#[test]
+fn test_make_concrete() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [
+            Operation::from(RotateX::new(0, CalculatorFloat::from("theta"))),
+            Operation::from(RotateZ::new(1, CalculatorFloat::from("phi"))),
+        ] {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let substitution_dict: HashMap<String, f64> = HashMap::from([
+            ("theta".to_string(), 1.0),
+            ("phi".to_string(), 2.0),
+            ("unused".to_string(), 3.0),
+        ]);
+        let concrete = circuit
+            .call_method1("make_concrete", (substitution_dict,))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert!(!concrete.internal.is_parametrized());
+        let mut comparison_circuit = Circuit::new();
+        comparison_circuit += RotateX::new(0, CalculatorFloat::from(1.0));
+        comparison_circuit += RotateZ::new(1, CalculatorFloat::from(2.0));
+        assert_eq!(concrete.internal, comparison_circuit);
+
+        let substitution_dict: HashMap<String, f64> = HashMap::from([("theta".to_string(), 1.0)]);
+        let error = circuit
+            .call_method1("make_concrete", (substitution_dict,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyRuntimeError>(py));
+        assert!(error.to_string().contains("phi"));
+        assert!(!error.to_string().contains("theta"));
+
+        // The text of an annotation is not a symbolic parameter
+        let annotated_circuit = new_circuit(py);
+        let operation = Operation::from(PragmaAnnotatedOp::new(
+            Operation::from(RotateX::new(0, CalculatorFloat::from("theta"))),
+            "needs calibration".to_string(),
+        ));
+        annotated_circuit
+            .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+            .unwrap();
+        let substitution_dict: HashMap<String, f64> = HashMap::from([("theta".to_string(), 1.0)]);
+        let concrete = annotated_circuit
+            .call_method1("make_concrete", (substitution_dict,))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut comparison_circuit = Circuit::new();
+        comparison_circuit += PragmaAnnotatedOp::new(
+            Operation::from(RotateX::new(0, CalculatorFloat::from(1.0))),
+            "needs calibration".to_string(),
+        );
+        assert_eq!(concrete.internal, comparison_circuit);
+    })
+}
+
 /// Test remap_qubits function of Circuit
 #[test]
 fn test_remap_qubits() {
@@ -156,6 +222,502 @@ fn test_remap_qubits() {
     })
 }
 
+/// Test apply_qubit_permutation function of Circuit
+#[test]
+fn test_apply_qubit_permutation() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(1, 2)),
+            Operation::from(RotateX::new(2, CalculatorFloat::from(1.0))),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let permuted = circuit
+            .call_method1("apply_qubit_permutation", (vec![2_usize, 0, 1],))
+            .unwrap();
+
+        let expected_circuit = new_circuit(py);
+        let expected_operations = vec![
+            Operation::from(Hadamard::new(2)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(RotateX::new(1, CalculatorFloat::from(1.0))),
+        ];
+        for operation in expected_operations {
+            expected_circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let comparison = bool::extract_bound(
+            &permuted
+                .call_method1("__eq__", (expected_circuit,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Repeated index
+        let result = circuit.call_method1("apply_qubit_permutation", (vec![0_usize, 0, 1],));
+        assert!(result.is_err());
+        // Out of range index
+        let result = circuit.call_method1("apply_qubit_permutation", (vec![0_usize, 1, 3],));
+        assert!(result.is_err());
+        // Permutation too short for the involved qubits
+        let result = circuit.call_method1("apply_qubit_permutation", (vec![1_usize, 0],));
+        assert!(result.is_err());
+    })
+}
+
+/// Test normalize_qubit_indices function of Circuit
+#[test]
+fn test_normalize_qubit_indices() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(3)),
+            Operation::from(CNOT::new(0, 7)),
+            Operation::from(RotateX::new(7, CalculatorFloat::from(1.0))),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let (normalized, mapping): (Bound<PyAny>, HashMap<usize, usize>) = circuit
+            .call_method0("normalize_qubit_indices")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(mapping, HashMap::from([(0, 0), (3, 1), (7, 2)]));
+
+        let expected_circuit = new_circuit(py);
+        let expected_operations = vec![
+            Operation::from(Hadamard::new(1)),
+            Operation::from(CNOT::new(0, 2)),
+            Operation::from(RotateX::new(2, CalculatorFloat::from(1.0))),
+        ];
+        for operation in expected_operations {
+            expected_circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let comparison = bool::extract_bound(
+            &normalized
+                .call_method1("__eq__", (&expected_circuit,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // The inverted mapping recovers the original qubit indices; it is completed
+        // to a permutation as required by remap_qubits
+        let mut inverse_mapping: HashMap<usize, usize> =
+            mapping.into_iter().map(|(old, new)| (new, old)).collect();
+        inverse_mapping.insert(3, 1);
+        inverse_mapping.insert(7, 2);
+        let recovered = normalized
+            .call_method1("remap_qubits", (inverse_mapping,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&recovered.call_method1("__eq__", (&circuit,)).unwrap()).unwrap();
+        assert!(comparison);
+    })
+}
+
+/// Test flatten_pragma_loops function of Circuit
+#[test]
+fn test_flatten_pragma_loops() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut inner = roqoqo::Circuit::new();
+        inner += PauliX::new(1);
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(PragmaLoop::new(CalculatorFloat::from(3.0), inner)),
+            Operation::from(PauliZ::new(0)),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let flattened = circuit.call_method0("flatten_pragma_loops").unwrap();
+        let mut comparison_circuit = roqoqo::Circuit::new();
+        comparison_circuit += Hadamard::new(0);
+        comparison_circuit += PauliX::new(1);
+        comparison_circuit += PauliX::new(1);
+        comparison_circuit += PauliX::new(1);
+        comparison_circuit += PauliZ::new(0);
+        assert_eq!(
+            flattened.extract::<CircuitWrapper>().unwrap().internal,
+            comparison_circuit
+        );
+
+        let symbolic_loop = Operation::from(PragmaLoop::new(
+            CalculatorFloat::from("repetitions"),
+            roqoqo::Circuit::new(),
+        ));
+        circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(symbolic_loop).unwrap(),),
+            )
+            .unwrap();
+        let result = circuit.call_method0("flatten_pragma_loops");
+        assert!(result.is_err());
+    })
+}
+
+/// Test inverse function of Circuit
+#[test]
+fn test_inverse() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(RotateZ::new(1, CalculatorFloat::from(0.5))),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let inverse = circuit.call_method0("inverse").unwrap();
+        let mut comparison_circuit = roqoqo::Circuit::new();
+        comparison_circuit += RotateZ::new(1, CalculatorFloat::from(-0.5));
+        comparison_circuit += CNOT::new(0, 1);
+        comparison_circuit += Hadamard::new(0);
+        assert_eq!(
+            inverse.extract::<CircuitWrapper>().unwrap().internal,
+            comparison_circuit
+        );
+
+        let double_inverse = inverse.call_method0("inverse").unwrap();
+        let comparison =
+            bool::extract_bound(&double_inverse.call_method1("__eq__", (&circuit,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+
+        let measurement = Operation::from(MeasureQubit::new(0, "ro".to_string(), 0));
+        circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(measurement).unwrap(),),
+            )
+            .unwrap();
+        assert!(circuit.call_method0("inverse").is_err());
+    })
+}
+
+/// Test adjoint function of Circuit
+#[test]
+fn test_adjoint() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(RotateX::new(1, CalculatorFloat::from(0.3))),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let adjoint = circuit.call_method0("adjoint").unwrap();
+        let inverse = circuit.call_method0("inverse").unwrap();
+        let comparison =
+            bool::extract_bound(&adjoint.call_method1("__eq__", (&inverse,)).unwrap()).unwrap();
+        assert!(comparison);
+        let double_adjoint = adjoint.call_method0("adjoint").unwrap();
+        let comparison =
+            bool::extract_bound(&double_adjoint.call_method1("__eq__", (&circuit,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+
+        let damping = Operation::from(PragmaDamping::new(0, 1.0.into(), 0.1.into()));
+        circuit
+            .call_method1("add", (convert_operation_to_pyobject(damping).unwrap(),))
+            .unwrap();
+        let error = circuit.call_method0("adjoint").unwrap_err();
+        assert!(error.is_instance_of::<PyRuntimeError>(py));
+    })
+}
+
+/// Test peephole_optimization function of Circuit
+#[test_case(
+    vec![TGate::new(0).into(), TGate::new(0).into(), TGate::new(0).into(), TGate::new(0).into()],
+    vec![SGate::new(0).into(), SGate::new(0).into()];
+    "tgates")]
+#[test_case(
+    vec![Hadamard::new(1).into(), PauliZ::new(1).into(), Hadamard::new(1).into()],
+    vec![PauliX::new(1).into()];
+    "hzh")]
+#[test_case(
+    vec![Hadamard::new(0).into(), PauliZ::new(1).into(), Hadamard::new(0).into()],
+    vec![Hadamard::new(0).into(), PauliZ::new(1).into(), Hadamard::new(0).into()];
+    "different qubits")]
+#[test_case(
+    vec![TGate::new(0).into(), RotateX::new(0, 1.0.into()).into(), TGate::new(0).into()],
+    vec![TGate::new(0).into(), RotateX::new(0, 1.0.into()).into(), TGate::new(0).into()];
+    "not simplifiable")]
+fn test_peephole_optimization(operations: Vec<Operation>, optimized: Vec<Operation>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let result = circuit
+            .call_method0("peephole_optimization")
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let comparison_circuit: roqoqo::Circuit = optimized.into_iter().collect();
+        assert_eq!(result.internal, comparison_circuit);
+    })
+}
+
+/// Test involved_qubits and max_qubit_index functions of Circuit
+#[test]
+fn test_involved_qubits_max_qubit_index() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let involved: HashSet<usize> = circuit
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(involved.is_empty());
+        let max_qubit: Option<usize> = circuit
+            .call_method0("max_qubit_index")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(max_qubit, None);
+
+        let operations = vec![
+            Operation::from(DefinitionBit::new("ro".to_string(), 2, true)),
+            Operation::from(Hadamard::new(4)),
+            Operation::from(CNOT::new(0, 2)),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let involved: HashSet<usize> = circuit
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(involved, HashSet::from([0, 2, 4]));
+        let max_qubit: Option<usize> = circuit
+            .call_method0("max_qubit_index")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(max_qubit, Some(4));
+
+        let measurement =
+            Operation::from(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None));
+        circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(measurement).unwrap(),),
+            )
+            .unwrap();
+        let involved: HashSet<String> = circuit
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(involved, HashSet::from(["All".to_string()]));
+        let max_qubit: Option<usize> = circuit
+            .call_method0("max_qubit_index")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(max_qubit, Some(4));
+    })
+}
+
+/// Test depth and gate_count_by_qubit functions of Circuit
+#[test]
+fn test_depth_gate_count_by_qubit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let depth: usize = circuit.call_method0("depth").unwrap().extract().unwrap();
+        assert_eq!(depth, 0);
+        let counts: HashMap<usize, usize> = circuit
+            .call_method0("gate_count_by_qubit")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(counts.is_empty());
+
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(Hadamard::new(1)),
+            Operation::from(PauliX::new(3)),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let depth: usize = circuit.call_method0("depth").unwrap().extract().unwrap();
+        assert_eq!(depth, 3);
+        let counts: HashMap<usize, usize> = circuit
+            .call_method0("gate_count_by_qubit")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(counts, HashMap::from([(0, 2), (1, 2), (3, 1)]));
+    })
+}
+
+/// Test gate_volume function of Circuit
+#[test]
+fn test_gate_volume() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(Hadamard::new(1)),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let gate_volume: usize = circuit
+            .call_method0("gate_volume")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(gate_volume, 6);
+
+        let parallel_circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &parallel_circuit, 0, 4);
+        let gate_volume: usize = parallel_circuit
+            .call_method0("gate_volume")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(gate_volume, 4);
+    })
+}
+
+/// Test critical_path_gates function of Circuit
+#[test]
+fn test_critical_path_gates() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+        ];
+        for operation in operations.iter() {
+            circuit
+                .call_method1(
+                    "add",
+                    (convert_operation_to_pyobject(operation.clone()).unwrap(),),
+                )
+                .unwrap();
+        }
+        let path: Vec<PyObject> = circuit
+            .call_method0("critical_path_gates")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(path.len(), 3);
+        for (op, expected) in path.iter().zip(operations.into_iter()) {
+            let comparison = bool::extract_bound(
+                &op.bind(py)
+                    .call_method1(
+                        "__eq__",
+                        (convert_operation_to_pyobject(expected).unwrap(),),
+                    )
+                    .unwrap(),
+            )
+            .unwrap();
+            assert!(comparison);
+        }
+
+        let empty_path: Vec<PyObject> = new_circuit(py)
+            .call_method0("critical_path_gates")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(empty_path.is_empty());
+    })
+}
+
+/// Test constant_folding function of Circuit
+#[test]
+fn test_constant_folding() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(RotateZ::new(0, CalculatorFloat::from(0.0))),
+            Operation::from(PauliX::new(0)),
+            Operation::from(RotateX::new(1, CalculatorFloat::from(1.0))),
+            Operation::from(PauliX::new(0)),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let folded = circuit
+            .call_method0("constant_folding")
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut comparison_circuit = roqoqo::Circuit::new();
+        comparison_circuit += RotateX::new(1, CalculatorFloat::from(1.0));
+        assert_eq!(folded.internal, comparison_circuit);
+
+        let parametrized_operation =
+            Operation::from(RotateZ::new(0, CalculatorFloat::from("theta")));
+        circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(parametrized_operation).unwrap(),),
+            )
+            .unwrap();
+        let result = circuit.call_method0("constant_folding");
+        assert!(result.is_err());
+    })
+}
+
 /// Test count_occurences function of Circuit
 #[test]
 fn test_count_occurences() {
@@ -237,6 +799,277 @@ fn test_get_operation_types() {
     })
 }
 
+/// Test count_operations_by_hqslang function of Circuit
+#[test]
+fn test_count_operations_by_hqslang() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let counts = HashMap::<String, usize>::extract_bound(
+            &circuit.call_method0("count_operations_by_hqslang").unwrap(),
+        )
+        .unwrap();
+        assert!(counts.is_empty());
+
+        for operation in [
+            Operation::from(Hadamard::new(0)),
+            Operation::from(Hadamard::new(1)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(RotateZ::new(1, CalculatorFloat::from(0.5))),
+            Operation::from(CNOT::new(1, 0)),
+        ] {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        let counts = HashMap::<String, usize>::extract_bound(
+            &circuit.call_method0("count_operations_by_hqslang").unwrap(),
+        )
+        .unwrap();
+        let expected: HashMap<String, usize> = HashMap::from([
+            ("Hadamard".to_string(), 2),
+            ("CNOT".to_string(), 2),
+            ("RotateZ".to_string(), 1),
+        ]);
+        assert_eq!(counts, expected);
+    })
+}
+
+/// Test to_pennylane_tape function of Circuit
+#[test]
+fn test_to_pennylane_tape() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // The conversion can only be tested when pennylane is installed
+        if py.import_bound("pennylane").is_err() {
+            return;
+        }
+        let mut circuit = Circuit::new();
+        circuit += DefinitionComplex::new("ro".to_string(), 4, true);
+        circuit += Hadamard::new(0);
+        circuit += CNOT::new(0, 1);
+        circuit += PragmaGetStateVector::new("ro".to_string(), None);
+        let circuit = Py::new(py, CircuitWrapper { internal: circuit }).unwrap();
+
+        let tape = circuit.call_method0(py, "to_pennylane_tape").unwrap();
+        let num_wires: usize = tape.getattr(py, "num_wires").unwrap().extract(py).unwrap();
+        assert_eq!(num_wires, 2);
+        let names: Vec<String> = tape
+            .getattr(py, "operations")
+            .unwrap()
+            .bind(py)
+            .iter()
+            .unwrap()
+            .map(|op| op.unwrap().getattr("name").unwrap().extract().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Hadamard".to_string(), "CNOT".to_string()]);
+        let number_measurements: usize = tape
+            .getattr(py, "measurements")
+            .unwrap()
+            .bind(py)
+            .len()
+            .unwrap();
+        assert_eq!(number_measurements, 1);
+
+        let mut invalid_circuit = Circuit::new();
+        invalid_circuit += PragmaDamping::new(0, 1.0.into(), 0.1.into());
+        let invalid_circuit = Py::new(
+            py,
+            CircuitWrapper {
+                internal: invalid_circuit,
+            },
+        )
+        .unwrap();
+        let error = invalid_circuit
+            .call_method0(py, "to_pennylane_tape")
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
+
+/// Test has_measurements and has_definitions functions of Circuit
+#[test]
+fn test_has_measurements_definitions() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let has_measurements =
+            bool::extract_bound(&circuit.call_method0("has_measurements").unwrap()).unwrap();
+        let has_definitions =
+            bool::extract_bound(&circuit.call_method0("has_definitions").unwrap()).unwrap();
+        assert!(!has_measurements);
+        assert!(!has_definitions);
+
+        let measure = convert_operation_to_pyobject(Operation::from(MeasureQubit::new(
+            0,
+            "ro".to_string(),
+            0,
+        )))
+        .unwrap();
+        circuit.call_method1("add", (measure,)).unwrap();
+        let has_measurements =
+            bool::extract_bound(&circuit.call_method0("has_measurements").unwrap()).unwrap();
+        let has_definitions =
+            bool::extract_bound(&circuit.call_method0("has_definitions").unwrap()).unwrap();
+        assert!(has_measurements);
+        assert!(!has_definitions);
+
+        let definition_circuit = new_circuit(py);
+        let definition = convert_operation_to_pyobject(Operation::from(DefinitionBit::new(
+            "ro".to_string(),
+            1,
+            true,
+        )))
+        .unwrap();
+        definition_circuit
+            .call_method1("add", (definition,))
+            .unwrap();
+        let has_measurements =
+            bool::extract_bound(&definition_circuit.call_method0("has_measurements").unwrap())
+                .unwrap();
+        let has_definitions =
+            bool::extract_bound(&definition_circuit.call_method0("has_definitions").unwrap())
+                .unwrap();
+        assert!(!has_measurements);
+        assert!(has_definitions);
+    })
+}
+
+/// Test is_unitary function of Circuit
+#[test]
+fn test_is_unitary() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let bell_circuit = new_circuit(py);
+        for operation in [
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+        ] {
+            bell_circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+        assert!(bool::extract_bound(&bell_circuit.call_method0("is_unitary").unwrap()).unwrap());
+
+        let measured_circuit = bell_circuit.call_method0("__copy__").unwrap();
+        let measurement = Operation::from(MeasureQubit::new(0, "ro".to_string(), 0));
+        measured_circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(measurement).unwrap(),),
+            )
+            .unwrap();
+        assert!(
+            !bool::extract_bound(&measured_circuit.call_method0("is_unitary").unwrap()).unwrap()
+        );
+
+        let phase_circuit = bell_circuit.call_method0("__copy__").unwrap();
+        let global_phase = Operation::from(PragmaGlobalPhase::new(CalculatorFloat::from(0.5)));
+        phase_circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(global_phase).unwrap(),),
+            )
+            .unwrap();
+        assert!(!bool::extract_bound(&phase_circuit.call_method0("is_unitary").unwrap()).unwrap());
+    })
+}
+
+/// Test summary function of Circuit
+#[test]
+fn test_summary() {
+    let added_op1 = Operation::from(DefinitionBit::new("ro".to_string(), 1, false));
+    let added_op2 = Operation::from(RotateX::new(0, CalculatorFloat::from("theta")));
+    let added_op3 = Operation::from(CNOT::new(0, 2));
+    let added_op4 = Operation::from(RotateZ::new(2, CalculatorFloat::from("2 * alpha + theta")));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for op in [added_op1, added_op2, added_op3, added_op4] {
+            let operation = convert_operation_to_pyobject(op).unwrap();
+            circuit.call_method1("add", (operation,)).unwrap();
+        }
+
+        let summary: HashMap<String, PyObject> =
+            circuit.call_method0("summary").unwrap().extract().unwrap();
+        let mut keys: Vec<&str> = summary.keys().map(|key| key.as_str()).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            vec![
+                "depth",
+                "involved_qubits",
+                "is_parametrized",
+                "number_of_operations",
+                "operation_counts",
+                "symbolic_parameters"
+            ]
+        );
+        let number_of_operations: usize = summary["number_of_operations"].extract(py).unwrap();
+        assert_eq!(number_of_operations, 4);
+        let operation_counts: HashMap<String, usize> =
+            summary["operation_counts"].extract(py).unwrap();
+        let mut expected_counts: HashMap<String, usize> = HashMap::new();
+        expected_counts.insert("DefinitionBit".to_string(), 1);
+        expected_counts.insert("RotateX".to_string(), 1);
+        expected_counts.insert("CNOT".to_string(), 1);
+        expected_counts.insert("RotateZ".to_string(), 1);
+        assert_eq!(operation_counts, expected_counts);
+        let involved_qubits: Vec<usize> = summary["involved_qubits"].extract(py).unwrap();
+        assert_eq!(involved_qubits, vec![0, 2]);
+        let depth: usize = summary["depth"].extract(py).unwrap();
+        assert_eq!(depth, 3);
+        let is_parametrized: bool = summary["is_parametrized"].extract(py).unwrap();
+        assert!(is_parametrized);
+        let symbolic_parameters: Vec<String> = summary["symbolic_parameters"].extract(py).unwrap();
+        assert_eq!(
+            symbolic_parameters,
+            vec!["alpha".to_string(), "theta".to_string()]
+        );
+
+        let empty_summary: HashMap<String, PyObject> = new_circuit(py)
+            .call_method0("summary")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(empty_summary.len(), 6);
+        let number_of_operations: usize =
+            empty_summary["number_of_operations"].extract(py).unwrap();
+        assert_eq!(number_of_operations, 0);
+        let operation_counts: HashMap<String, usize> =
+            empty_summary["operation_counts"].extract(py).unwrap();
+        assert!(operation_counts.is_empty());
+        let involved_qubits: Vec<usize> = empty_summary["involved_qubits"].extract(py).unwrap();
+        assert!(involved_qubits.is_empty());
+        let depth: usize = empty_summary["depth"].extract(py).unwrap();
+        assert_eq!(depth, 0);
+        let is_parametrized: bool = empty_summary["is_parametrized"].extract(py).unwrap();
+        assert!(!is_parametrized);
+        let symbolic_parameters: Vec<String> =
+            empty_summary["symbolic_parameters"].extract(py).unwrap();
+        assert!(symbolic_parameters.is_empty());
+
+        // Annotations do not contribute symbolic parameters
+        let annotated_circuit = new_circuit(py);
+        let operation = Operation::from(PragmaAnnotatedOp::new(
+            Operation::from(RotateZ::new(0, CalculatorFloat::from("theta"))),
+            "needs calibration".to_string(),
+        ));
+        annotated_circuit
+            .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+            .unwrap();
+        let annotated_summary: HashMap<String, PyObject> = annotated_circuit
+            .call_method0("summary")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let symbolic_parameters: Vec<String> = annotated_summary["symbolic_parameters"]
+            .extract(py)
+            .unwrap();
+        assert_eq!(symbolic_parameters, vec!["theta".to_string()]);
+    })
+}
+
 /// Test copy and deepcopy functions of Circuit
 #[test]
 fn test_copy_deepcopy() {
@@ -336,6 +1169,124 @@ fn test_to_from_bincode() {
     })
 }
 
+/// Test circuits_to_bincode and circuits_from_bincode functions
+#[test]
+fn test_circuits_to_from_bincode() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuits: Vec<CircuitWrapper> = (0..1000)
+            .map(|i| {
+                let mut circuit = CircuitWrapper::new();
+                circuit.internal += RotateX::new(0, CalculatorFloat::from(0.001 * i as f64));
+                circuit.internal += CNOT::new(0, 1);
+                circuit
+            })
+            .collect();
+        let to_bincode = wrap_pyfunction!(circuits_to_bincode, py).unwrap();
+        let from_bincode = wrap_pyfunction!(circuits_from_bincode, py).unwrap();
+
+        let serialised = to_bincode.call1((circuits.clone(),)).unwrap();
+        let deserialised: Vec<CircuitWrapper> = from_bincode
+            .call1((&serialised,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(deserialised, circuits);
+
+        let empty = to_bincode.call1((Vec::<CircuitWrapper>::new(),)).unwrap();
+        let deserialised: Vec<CircuitWrapper> =
+            from_bincode.call1((&empty,)).unwrap().extract().unwrap();
+        assert!(deserialised.is_empty());
+
+        let wrong_format = PyByteArray::new_bound(py, b"not a list of circuits");
+        let error = from_bincode.call1((wrong_format,)).unwrap_err();
+        assert!(error.is_instance_of::<PyRuntimeError>(py));
+    })
+}
+
+/// Test to_msgpack and from_msgpack functions of Circuit
+#[cfg(feature = "msgpack")]
+#[test]
+fn test_to_from_msgpack() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(DefinitionBit::new("ro".to_string(), 2, true)),
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(RotateZ::new(1, CalculatorFloat::from("theta"))),
+            Operation::from(RotateX::new(0, CalculatorFloat::from(0.5))),
+            Operation::from(MeasureQubit::new(0, "ro".to_string(), 0)),
+            Operation::from(MeasureQubit::new(1, "ro".to_string(), 1)),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let serialized = circuit.call_method0("to_msgpack").unwrap();
+        let deserialized = circuit
+            .call_method1("from_msgpack", (&serialized,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&deserialized.call_method1("__eq__", (&circuit,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+
+        let json: String = circuit.call_method0("to_json").unwrap().extract().unwrap();
+        let msgpack_len = serialized.downcast::<PyByteArray>().unwrap().len();
+        assert!(msgpack_len < json.len());
+
+        let invalid = PyByteArray::new_bound(py, &[0xc1_u8, 0x00]);
+        let error = circuit.call_method1("from_msgpack", (invalid,));
+        assert!(error.is_err());
+    })
+}
+
+/// Test to_bincode_file and from_bincode_file functions of Circuit
+#[test]
+fn test_to_from_bincode_file() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = CircuitWrapper::new();
+        for i in 0..10000 {
+            circuit.internal += RotateX::new(i % 10, CalculatorFloat::from(0.001 * i as f64));
+        }
+        let circuit = Bound::new(py, circuit).unwrap();
+        let directory = std::env::temp_dir();
+        let path = directory.join("qoqo_test_to_from_bincode_file.bin");
+        let path = path.to_str().unwrap();
+
+        circuit.call_method1("to_bincode_file", (path,)).unwrap();
+        let circuit_type = py.get_type_bound::<CircuitWrapper>();
+        let deserialised = circuit_type
+            .call_method1("from_bincode_file", (path,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&deserialised.call_method1("__eq__", (&circuit,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+
+        let malformed_path = directory.join("qoqo_test_from_bincode_file_malformed.bin");
+        std::fs::write(&malformed_path, b"not a circuit").unwrap();
+        let error = circuit_type
+            .call_method1("from_bincode_file", (malformed_path.to_str().unwrap(),))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyRuntimeError>(py));
+
+        let missing_path = directory.join("qoqo_test_from_bincode_file_missing.bin");
+        let error = circuit_type
+            .call_method1("from_bincode_file", (missing_path.to_str().unwrap(),))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyRuntimeError>(py));
+
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(malformed_path).unwrap();
+    })
+}
+
 #[test]
 fn test_value_error_bincode() {
     pyo3::prepare_freethreaded_python();
@@ -378,6 +1329,12 @@ fn test_to_from_json() {
 
         // testing 'from_json' and 'to_json' functions
         let serialised = &circuit.call_method0("to_json").unwrap();
+        // the json form is human readable and carries the roqoqo version metadata
+        let serialised_string: String = serialised.extract().unwrap();
+        assert!(serialised_string.contains("\"RotateX\""));
+        assert!(serialised_string.contains("\"PragmaRepeatedMeasurement\""));
+        assert!(serialised_string.contains("\"_roqoqo_version\""));
+        assert!(serialised_string.contains("\"major_version\":1"));
         let new = new_circuit(py);
         let deserialised = new.call_method1("from_json", (serialised,)).unwrap();
 
@@ -437,6 +1394,35 @@ fn test_json_schema() {
     });
 }
 
+/// Test to_json_schema_validated function of Circuit
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_to_json_schema_validated() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 4);
+
+        let validated: String =
+            String::extract_bound(&circuit.call_method0("to_json_schema_validated").unwrap())
+                .unwrap();
+        let serialized: String =
+            String::extract_bound(&circuit.call_method0("to_json").unwrap()).unwrap();
+        assert_eq!(validated, serialized);
+
+        // A NaN angle is serialized as null by serde_json, which does not match the JsonSchema
+        let invalid_circuit = new_circuit(py);
+        let operation =
+            convert_operation_to_pyobject(Operation::from(RotateX::new(0, f64::NAN.into())))
+                .unwrap();
+        invalid_circuit.call_method1("add", (operation,)).unwrap();
+        let error = invalid_circuit
+            .call_method0("to_json_schema_validated")
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyRuntimeError>(py));
+    });
+}
+
 ///  Test single index set and write access using "get" function
 #[test]
 fn test_single_index_access_get() {
@@ -477,6 +1463,72 @@ fn test_single_index_access_get() {
     })
 }
 
+/// Test negative indices, slices and deletion with the item magic methods of Circuit
+#[test]
+fn test_getitem_setitem_delitem() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 4);
+
+        let last = circuit.call_method1("__getitem__", (-1,)).unwrap();
+        let expected = convert_operation_to_pyobject(Operation::from(RotateX::new(
+            3,
+            CalculatorFloat::from(3),
+        )))
+        .unwrap();
+        let comparison =
+            bool::extract_bound(&last.call_method1("__eq__", (expected,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(circuit.call_method1("__getitem__", (4,)).is_err());
+        let error = circuit.call_method1("__getitem__", (-5,)).unwrap_err();
+        assert!(error.is_instance_of::<PyIndexError>(py));
+
+        let slice = pyo3::types::PySlice::new_bound(py, 1, 4, 2);
+        let sliced = circuit.call_method1("__getitem__", (slice,)).unwrap();
+        let expected = new_circuit(py);
+        for qubit in [1, 3] {
+            let operation = convert_operation_to_pyobject(Operation::from(RotateX::new(
+                qubit,
+                CalculatorFloat::from(qubit as f64),
+            )))
+            .unwrap();
+            expected.call_method1("add", (operation,)).unwrap();
+        }
+        let comparison =
+            bool::extract_bound(&sliced.call_method1("__eq__", (&expected,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let operation = convert_operation_to_pyobject(Operation::from(PauliX::new(5))).unwrap();
+        circuit
+            .call_method1("__setitem__", (-2, operation.clone()))
+            .unwrap();
+        let comp_op = circuit.call_method1("__getitem__", (2,)).unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (&operation,)).unwrap()).unwrap();
+        assert!(comparison);
+        let error = circuit
+            .call_method1("__setitem__", (-5, operation))
+            .unwrap_err();
+        assert!(error.is_instance_of::<PyIndexError>(py));
+
+        circuit.call_method1("__delitem__", (0,)).unwrap();
+        circuit.call_method1("__delitem__", (-1,)).unwrap();
+        assert_eq!(circuit.len().unwrap(), 2);
+        let comp_op = circuit.call_method1("__getitem__", (0,)).unwrap();
+        let expected = convert_operation_to_pyobject(Operation::from(RotateX::new(
+            1,
+            CalculatorFloat::from(1),
+        )))
+        .unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (expected,)).unwrap()).unwrap();
+        assert!(comparison);
+        let error = circuit.call_method1("__delitem__", (2,)).unwrap_err();
+        assert!(error.is_instance_of::<PyIndexError>(py));
+    })
+}
+
 /// Test get_slice property of Circuit
 #[test]
 fn test_get_slice() {
@@ -542,6 +1594,110 @@ fn test_get_slice() {
     })
 }
 
+/// Test replace_at function of Circuit
+#[test]
+fn test_replace_at() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 5);
+
+        for index in [0_usize, 2, 4] {
+            let new_operation =
+                convert_operation_to_pyobject(Operation::from(Hadamard::new(index))).unwrap();
+            let replaced = circuit
+                .call_method1("replace_at", (index, &new_operation))
+                .unwrap();
+            assert_eq!(replaced.len().unwrap(), 5);
+
+            let mut comparison_circuit = Circuit::new();
+            for position in 0..5_usize {
+                if position == index {
+                    comparison_circuit += Hadamard::new(index);
+                } else {
+                    comparison_circuit +=
+                        RotateX::new(position, CalculatorFloat::from(position as f64));
+                }
+            }
+            let comparison_circuit = Bound::new(
+                py,
+                CircuitWrapper {
+                    internal: comparison_circuit,
+                },
+            )
+            .unwrap();
+            let comparison = bool::extract_bound(
+                &replaced
+                    .call_method1("__eq__", (&comparison_circuit,))
+                    .unwrap(),
+            )
+            .unwrap();
+            assert!(comparison);
+        }
+        // The original circuit is left unchanged
+        let operation = circuit.call_method1("__getitem__", (0,)).unwrap();
+        let comparison_operation =
+            convert_operation_to_pyobject(Operation::from(RotateX::new(0, 0.0.into()))).unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .call_method1("__eq__", (&comparison_operation,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        let new_operation =
+            convert_operation_to_pyobject(Operation::from(Hadamard::new(0))).unwrap();
+        match circuit.call_method1("replace_at", (5, &new_operation)) {
+            Err(x) => assert!(x.is_instance_of::<PyIndexError>(py)),
+            _ => panic!("Wrong error"),
+        }
+    })
+}
+
+/// Test split_at function of Circuit
+#[test]
+fn test_split_at() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 5);
+
+        for index in 0..=5_usize {
+            let (first, second): (Bound<PyAny>, Bound<PyAny>) = circuit
+                .call_method1("split_at", (index,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(first.len().unwrap(), index);
+            assert_eq!(second.len().unwrap(), 5 - index);
+            let concatenated = first.call_method1("__add__", (&second,)).unwrap();
+            let comparison =
+                bool::extract_bound(&concatenated.call_method1("__eq__", (&circuit,)).unwrap())
+                    .unwrap();
+            assert!(comparison);
+        }
+
+        let empty = new_circuit(py);
+        let (first, second): (Bound<PyAny>, Bound<PyAny>) = empty
+            .call_method1("split_at", (0,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(first.len().unwrap(), 0);
+        assert_eq!(second.len().unwrap(), 0);
+
+        match circuit.call_method1("split_at", (6,)) {
+            Err(x) => assert!(x.is_instance_of::<PyIndexError>(py)),
+            _ => panic!("Wrong error"),
+        }
+        match empty.call_method1("split_at", (1,)) {
+            Err(x) => assert!(x.is_instance_of::<PyIndexError>(py)),
+            _ => panic!("Wrong error"),
+        }
+    })
+}
+
 /// Test definitions function of Circuit
 #[test]
 fn test_definitions() {
@@ -590,6 +1746,54 @@ fn test_operations() {
     })
 }
 
+/// Test remove_pragma_operations and remove_pragma_operations_except functions of Circuit
+#[test]
+fn test_remove_pragma_operations() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operations = vec![
+            Operation::from(Hadamard::new(0)),
+            Operation::from(PragmaDepolarising::new(
+                0,
+                CalculatorFloat::from(0.1),
+                CalculatorFloat::from(0.01),
+            )),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(PragmaSleep::new(vec![0, 1], CalculatorFloat::from(1e-6))),
+        ];
+        for operation in operations {
+            circuit
+                .call_method1("add", (convert_operation_to_pyobject(operation).unwrap(),))
+                .unwrap();
+        }
+
+        let stripped = circuit.call_method0("remove_pragma_operations").unwrap();
+        let mut comparison_circuit = Circuit::new();
+        comparison_circuit += Hadamard::new(0);
+        comparison_circuit += CNOT::new(0, 1);
+        assert_eq!(
+            stripped.extract::<CircuitWrapper>().unwrap().internal,
+            comparison_circuit
+        );
+
+        let stripped = circuit
+            .call_method1(
+                "remove_pragma_operations_except",
+                (vec!["PragmaSleep".to_string()],),
+            )
+            .unwrap();
+        let mut comparison_circuit = Circuit::new();
+        comparison_circuit += Hadamard::new(0);
+        comparison_circuit += CNOT::new(0, 1);
+        comparison_circuit += PragmaSleep::new(vec![0, 1], CalculatorFloat::from(1e-6));
+        assert_eq!(
+            stripped.extract::<CircuitWrapper>().unwrap().internal,
+            comparison_circuit
+        );
+    })
+}
+
 /// Test filter_by_tag function of Circuit
 #[test]
 fn test_filter_by_tag() {
@@ -628,10 +1832,181 @@ fn test_filter_by_tag() {
                 .unwrap(),
         )
         .unwrap();
+        assert!(comparison);
+
+        let comp_op = circuit
+            .call_method1("filter_by_tag", ("Definition", false))
+            .unwrap();
+        let comparison = bool::extract_bound(
+            &comp_op
+                .call_method1("__eq__", (vec![rotatex_0, rotatex_1],))
+                .unwrap(),
+        )
+        .unwrap();
         assert!(comparison)
     })
 }
 
+/// Test filter_by_hqslang function of Circuit
+#[test]
+fn test_filter_by_hqslang() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let expected = new_circuit(py);
+        let operations = vec![
+            Operation::from(RotateX::new(0, 0.1.into())),
+            Operation::from(PauliX::new(1)),
+            Operation::from(PragmaGlobalPhase::new(0.2.into())),
+            Operation::from(RotateX::new(1, 0.3.into())),
+        ];
+        for operation in operations {
+            let is_rotatex = operation.hqslang() == "RotateX";
+            let operation = convert_operation_to_pyobject(operation).unwrap();
+            circuit.call_method1("add", (&operation,)).unwrap();
+            if is_rotatex {
+                expected.call_method1("add", (&operation,)).unwrap();
+            }
+        }
+
+        let filtered = circuit
+            .call_method1("filter_by_hqslang", ("RotateX",))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&filtered.call_method1("__eq__", (&expected,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let filtered = circuit
+            .call_method1("filter_by_hqslang", ("PragmaDamping",))
+            .unwrap();
+        assert_eq!(filtered.len().unwrap(), 0);
+    })
+}
+
+/// Test operations_affecting_qubit function of Circuit
+#[test]
+fn test_operations_affecting_qubit() {
+    let statevector = ndarray::array![
+        num_complex::Complex64::new(1.0, 0.0),
+        num_complex::Complex64::new(0.0, 0.0),
+        num_complex::Complex64::new(0.0, 0.0),
+        num_complex::Complex64::new(0.0, 0.0)
+    ];
+    let hadamard_0 = Operation::from(Hadamard::new(0));
+    let cnot = Operation::from(CNOT::new(0, 1));
+    let hadamard_1 = Operation::from(Hadamard::new(1));
+    let pragma = Operation::from(PragmaSetStateVector::new(statevector));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [&hadamard_0, &cnot, &hadamard_1, &pragma] {
+            circuit
+                .call_method1(
+                    "add",
+                    (convert_operation_to_pyobject(operation.clone()).unwrap(),),
+                )
+                .unwrap();
+        }
+
+        let expected: Vec<PyObject> = [hadamard_0.clone(), cnot.clone(), pragma.clone()]
+            .into_iter()
+            .map(|op| convert_operation_to_pyobject(op).unwrap())
+            .collect();
+        let affecting = circuit
+            .call_method1("operations_affecting_qubit", (0,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&affecting.call_method1("__eq__", (expected,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let expected: Vec<PyObject> = [pragma]
+            .into_iter()
+            .map(|op| convert_operation_to_pyobject(op).unwrap())
+            .collect();
+        let affecting = circuit
+            .call_method1("operations_affecting_qubit", (5,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&affecting.call_method1("__eq__", (expected,)).unwrap()).unwrap();
+        assert!(comparison);
+    })
+}
+
+/// Test operations_on_qubit_range function of Circuit
+#[test]
+fn test_operations_on_qubit_range() {
+    let hadamard_0 = Operation::from(Hadamard::new(0));
+    let cnot_01 = Operation::from(CNOT::new(0, 1));
+    let cnot_12 = Operation::from(CNOT::new(1, 2));
+    let cnot_23 = Operation::from(CNOT::new(2, 3));
+    let paulix_3 = Operation::from(PauliX::new(3));
+    let definition = Operation::from(DefinitionBit::new("ro".to_string(), 4, true));
+    let measurement = Operation::from(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [
+            &definition,
+            &hadamard_0,
+            &cnot_01,
+            &cnot_12,
+            &cnot_23,
+            &paulix_3,
+            &measurement,
+        ] {
+            circuit
+                .call_method1(
+                    "add",
+                    (convert_operation_to_pyobject(operation.clone()).unwrap(),),
+                )
+                .unwrap();
+        }
+
+        for (start, end, expected) in [
+            (
+                0,
+                2,
+                vec![hadamard_0.clone(), cnot_01.clone(), measurement.clone()],
+            ),
+            (
+                2,
+                4,
+                vec![cnot_23.clone(), paulix_3.clone(), measurement.clone()],
+            ),
+            (
+                0,
+                4,
+                vec![
+                    hadamard_0,
+                    cnot_01,
+                    cnot_12,
+                    cnot_23,
+                    paulix_3,
+                    measurement.clone(),
+                ],
+            ),
+            (5, 5, vec![measurement]),
+        ] {
+            let expected: Vec<PyObject> = expected
+                .into_iter()
+                .map(|op| convert_operation_to_pyobject(op).unwrap())
+                .collect();
+            let in_range = circuit
+                .call_method1("operations_on_qubit_range", (start, end))
+                .unwrap();
+            let comparison =
+                bool::extract_bound(&in_range.call_method1("__eq__", (expected,)).unwrap())
+                    .unwrap();
+            assert!(comparison);
+        }
+
+        match circuit.call_method1("operations_on_qubit_range", (3, 1)) {
+            Err(x) => assert!(x.is_instance_of::<pyo3::exceptions::PyValueError>(py)),
+            _ => panic!("Wrong error"),
+        }
+    })
+}
+
 /// Test add function
 #[test_case(Operation::from(RotateX::new(0, CalculatorFloat::from(0))); "RotateX float")]
 #[test_case(Operation::from(RotateZ::new(1, CalculatorFloat::from(1.3))); "RotateZ float")]
@@ -653,6 +2028,39 @@ fn test_circuit_add_function(added_operation: Operation) {
     })
 }
 
+/// Test prepend_circuit function of Circuit
+#[test]
+fn test_prepend_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 2);
+        let other = new_circuit(py);
+        populate_circuit_rotatex(py, &other, 2, 3);
+
+        let doubled = circuit
+            .call_method1("prepend_circuit", (&circuit,))
+            .unwrap();
+        assert_eq!(doubled.len().unwrap(), 4);
+
+        let prepended = circuit.call_method1("prepend_circuit", (&other,)).unwrap();
+        let expected = other.call_method1("__add__", (&circuit,)).unwrap();
+        let comparison =
+            bool::extract_bound(&prepended.call_method1("__eq__", (expected,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let prepended = circuit
+            .call_method1("prepend_circuit", (new_circuit(py),))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&prepended.call_method1("__eq__", (&circuit,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let error = circuit.call_method1("prepend_circuit", (vec!["fails"],));
+        assert!(error.is_err());
+    })
+}
+
 /// Test the __repr__ and __format__ functions
 #[test]
 fn test_format_repr() {
@@ -672,6 +2080,41 @@ fn test_format_repr() {
     })
 }
 
+/// Test the _repr_html_ function
+#[test]
+fn test_repr_html() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 2);
+        let cnot = convert_operation_to_pyobject(Operation::from(CNOT::new(0, 1))).unwrap();
+        circuit.call_method1("add", (cnot,)).unwrap();
+
+        let html: String = circuit
+            .call_method0("_repr_html_")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(html.contains("<table"));
+        assert!(html.ends_with("</table>"));
+        assert_eq!(html.matches("<tr>").count(), 4);
+        assert_eq!(html.matches("</tr>").count(), 4);
+        assert!(html.contains("<tr><td>0</td><td>RotateX</td><td>0</td><td>theta=0.0</td></tr>"));
+        assert!(html.contains("<tr><td>1</td><td>RotateX</td><td>1</td><td>theta=1.0</td></tr>"));
+        assert!(html.contains("<tr><td>2</td><td>CNOT</td><td>0, 1</td><td></td></tr>"));
+
+        let empty_circuit = new_circuit(py);
+        let empty_html: String = empty_circuit
+            .call_method0("_repr_html_")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(empty_html.contains("<table"));
+        assert!(empty_html.contains("<tbody>\n</tbody>"));
+        assert_eq!(empty_html.matches("<tr>").count(), 1);
+    })
+}
+
 /// Test fmt::Debug for OperationIteratorWrapper
 #[test]
 fn test_fmt_circuititerator() {
@@ -851,6 +2294,75 @@ fn test_iter() {
     })
 }
 
+/// Test that Python iteration over a Circuit matches indexed access
+#[test]
+fn test_iter_list() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 3);
+
+        let builtins = py.import_bound("builtins").unwrap();
+        let operations = builtins
+            .getattr("list")
+            .unwrap()
+            .call1((&circuit,))
+            .unwrap();
+        assert_eq!(operations.len().unwrap(), 3);
+        for index in 0..3_usize {
+            let indexed = circuit.call_method1("__getitem__", (index,)).unwrap();
+            let iterated = operations.get_item(index).unwrap();
+            let comparison =
+                bool::extract_bound(&iterated.call_method1("__eq__", (indexed,)).unwrap()).unwrap();
+            assert!(comparison);
+        }
+
+        let iterator = circuit.call_method0("__iter__").unwrap();
+        for _ in 0..3 {
+            builtins
+                .getattr("next")
+                .unwrap()
+                .call1((&iterator,))
+                .unwrap();
+        }
+        let error = builtins
+            .getattr("next")
+            .unwrap()
+            .call1((&iterator,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyStopIteration>(py));
+    })
+}
+
+/// Test the __length_hint__ function of the Circuit iterator
+#[test]
+fn test_iter_length_hint() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        populate_circuit_rotatex(py, &circuit, 0, 5);
+
+        let iterator = circuit.call_method0("__iter__").unwrap();
+        let hint: usize =
+            usize::extract_bound(&iterator.call_method0("__length_hint__").unwrap()).unwrap();
+        assert_eq!(hint, 5);
+
+        iterator.call_method0("__next__").unwrap();
+        iterator.call_method0("__next__").unwrap();
+        let hint: usize =
+            usize::extract_bound(&iterator.call_method0("__length_hint__").unwrap()).unwrap();
+        assert_eq!(hint, 3);
+
+        let operator = py.import_bound("operator").unwrap();
+        let hint: usize = operator
+            .call_method1("length_hint", (&iterator,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(hint, 3);
+    })
+}
+
 /// Test the __len__ function
 #[test]
 fn test_len() {
@@ -268,6 +268,49 @@ fn test_from_circuit() {
     })
 }
 
+/// Test from_circuit called on the CircuitDag class
+#[test]
+fn test_from_circuit_staticmethod() {
+    pyo3::prepare_freethreaded_python();
+    let paulix_0 = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+    let pauliy_1 = convert_operation_to_pyobject(Operation::from(PauliY::new(1))).unwrap();
+    let cnot_01 = convert_operation_to_pyobject(Operation::from(CNOT::new(0, 1))).unwrap();
+    let pauliz_2 = convert_operation_to_pyobject(Operation::from(PauliZ::new(2))).unwrap();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        circuit.call_method1("add", (paulix_0,)).unwrap();
+        circuit.call_method1("add", (pauliy_1,)).unwrap();
+        circuit.call_method1("add", (cnot_01,)).unwrap();
+        circuit.call_method1("add", (pauliz_2,)).unwrap();
+
+        let circuitdag_type = py.get_type_bound::<CircuitDagWrapper>();
+        let binding = circuitdag_type
+            .call_method1("from_circuit", (&circuit,))
+            .unwrap();
+        let dag = binding.downcast::<CircuitDagWrapper>().unwrap();
+
+        let node_count = usize::extract_bound(&dag.call_method0("node_count").unwrap()).unwrap();
+        let circuit_len = usize::extract_bound(&circuit.call_method0("__len__").unwrap()).unwrap();
+        assert_eq!(node_count, circuit_len);
+
+        // Both single-qubit gates block the CNOT, the PauliZ on qubit 2 is independent
+        let successors_0 =
+            Vec::<usize>::extract_bound(&dag.call_method1("successors", (0,)).unwrap()).unwrap();
+        assert_eq!(successors_0, vec![2]);
+        let successors_1 =
+            Vec::<usize>::extract_bound(&dag.call_method1("successors", (1,)).unwrap()).unwrap();
+        assert_eq!(successors_1, vec![2]);
+        let successors_3 =
+            Vec::<usize>::extract_bound(&dag.call_method1("successors", (3,)).unwrap()).unwrap();
+        assert!(successors_3.is_empty());
+        let edge_count = usize::extract_bound(&dag.call_method0("edge_count").unwrap()).unwrap();
+        assert_eq!(edge_count, 2);
+
+        let error = circuitdag_type.call_method1("from_circuit", (vec!["fails"],));
+        assert!(error.is_err());
+    })
+}
+
 #[test]
 fn test_to_circuit() {
     pyo3::prepare_freethreaded_python();
@@ -303,6 +346,15 @@ fn test_to_circuit() {
         let helper3 =
             bool::extract_bound(&comp_op.call_method1("__eq__", (cnot_01,)).unwrap()).unwrap();
         assert!(helper3);
+
+        let pauliz_0 = convert_operation_to_pyobject(Operation::from(PauliZ::new(0))).unwrap();
+        dag.call_method1("add_to_front", (pauliz_0.clone(),))
+            .unwrap();
+        let new_circuit = dag.call_method0("to_circuit").unwrap();
+        let comp_op = new_circuit.call_method1("get", (0,)).unwrap();
+        let helper4 =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (pauliz_0,)).unwrap()).unwrap();
+        assert!(helper4);
     })
 }
 
@@ -477,6 +529,110 @@ fn test_new_front_layer() {
     })
 }
 
+/// Test topological_sort and parallel_operation_blocks
+#[test]
+fn test_topological_sort_parallel_operation_blocks() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let dag = new_circuitdag(py);
+        for operation in [
+            Operation::from(PauliX::new(0)),
+            Operation::from(PauliZ::new(0)),
+            Operation::from(PauliY::new(1)),
+            Operation::from(Hadamard::new(1)),
+            Operation::from(CNOT::new(0, 1)),
+        ] {
+            dag.call_method1(
+                "add_to_back",
+                (convert_operation_to_pyobject(operation).unwrap(),),
+            )
+            .unwrap();
+        }
+        let hqslang = |operation: Bound<PyAny>| -> String {
+            String::extract_bound(&operation.call_method0("hqslang").unwrap()).unwrap()
+        };
+
+        let sorted: Vec<String> = dag
+            .call_method0("topological_sort")
+            .unwrap()
+            .iter()
+            .unwrap()
+            .map(|operation| hqslang(operation.unwrap()))
+            .collect();
+        let position = |name: &str| sorted.iter().position(|x| x == name).unwrap();
+        assert_eq!(sorted.len(), 5);
+        assert!(position("PauliX") < position("PauliZ"));
+        assert!(position("PauliY") < position("Hadamard"));
+        assert_eq!(position("CNOT"), 4);
+
+        let blocks: Vec<Vec<String>> = dag
+            .call_method0("parallel_operation_blocks")
+            .unwrap()
+            .iter()
+            .unwrap()
+            .map(|block| {
+                let mut names: Vec<String> = block
+                    .unwrap()
+                    .iter()
+                    .unwrap()
+                    .map(|operation| hqslang(operation.unwrap()))
+                    .collect();
+                names.sort();
+                names
+            })
+            .collect();
+        assert_eq!(
+            blocks,
+            vec![
+                vec!["PauliX".to_string(), "PauliY".to_string()],
+                vec!["Hadamard".to_string(), "PauliZ".to_string()],
+                vec!["CNOT".to_string()],
+            ]
+        );
+    })
+}
+
+/// Test iterating over the CircuitDag
+#[test]
+fn test_iter() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let dag = new_circuitdag(py);
+        for operation in [
+            Operation::from(PauliX::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(PauliY::new(1)),
+        ] {
+            dag.call_method1(
+                "add_to_back",
+                (convert_operation_to_pyobject(operation).unwrap(),),
+            )
+            .unwrap();
+        }
+        let pauliz_1 = convert_operation_to_pyobject(Operation::from(PauliZ::new(1))).unwrap();
+        dag.call_method1("add_to_front", (pauliz_1,)).unwrap();
+
+        let hqslang = |operation: Bound<PyAny>| -> String {
+            String::extract_bound(&operation.call_method0("hqslang").unwrap()).unwrap()
+        };
+        let iterated: Vec<String> = dag
+            .iter()
+            .unwrap()
+            .map(|operation| hqslang(operation.unwrap()))
+            .collect();
+        let sorted: Vec<String> = dag
+            .call_method0("topological_sort")
+            .unwrap()
+            .iter()
+            .unwrap()
+            .map(|operation| hqslang(operation.unwrap()))
+            .collect();
+        assert_eq!(iterated, sorted);
+        assert_eq!(iterated.len(), 4);
+        assert_eq!(iterated[3], "PauliY");
+    })
+}
+
 #[test]
 fn test_parallel_blocks() {
     pyo3::prepare_freethreaded_python();
@@ -605,6 +761,61 @@ fn test_getters_commuting_operations() {
     })
 }
 
+/// Test node_count and edge_count
+#[test]
+fn test_node_edge_count() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let linear_dag = new_circuitdag(py);
+        let parallel_dag = new_circuitdag(py);
+        assert_eq!(
+            linear_dag
+                .call_method0("node_count")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            0
+        );
+        for qubit in 0..5 {
+            let linear_op = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+            linear_dag
+                .call_method1("add_to_back", (linear_op,))
+                .unwrap();
+            let parallel_op =
+                convert_operation_to_pyobject(Operation::from(PauliX::new(qubit))).unwrap();
+            parallel_dag
+                .call_method1("add_to_back", (parallel_op,))
+                .unwrap();
+        }
+
+        let node_count: usize = linear_dag
+            .call_method0("node_count")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let edge_count: usize = linear_dag
+            .call_method0("edge_count")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(node_count, 5);
+        assert_eq!(edge_count, 4);
+
+        let node_count: usize = parallel_dag
+            .call_method0("node_count")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let edge_count: usize = parallel_dag
+            .call_method0("edge_count")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(node_count, 5);
+        assert_eq!(edge_count, 0);
+    })
+}
+
 #[test]
 fn test_getters_parallel_blocks() {
     pyo3::prepare_freethreaded_python();
@@ -13,10 +13,19 @@
 use ndarray::{array, Array2};
 use numpy::{pyarray_bound, PyArray2};
 use pyo3::prelude::*;
-use qoqo::devices::{AllToAllDeviceWrapper, GenericDeviceWrapper, SquareLatticeDeviceWrapper};
-use roqoqo::devices::{AllToAllDevice, GenericDevice, SquareLatticeDevice};
+use qoqo::devices::{
+    AllToAllDeviceWrapper, GenericDeviceWrapper, HexagonalLatticeDeviceWrapper,
+    LinearChainDeviceWrapper, SquareLatticeDeviceWrapper,
+};
+use qoqo::CircuitWrapper;
+use roqoqo::devices::{
+    AllToAllDevice, GenericDevice, HexagonalLatticeDevice, LinearChainDevice, SquareLatticeDevice,
+};
+use roqoqo::operations::{DefinitionBit, Hadamard, MeasureQubit, RotateX, RotateZ, CNOT};
+use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
+use std::collections::HashMap;
 use test_case::test_case;
 
 fn new_alltoalldevice() -> Py<PyAny> {
@@ -61,6 +70,40 @@ fn new_genericlattice() -> Py<PyAny> {
         device_type.call1(arguments).unwrap().into()
     })
 }
+fn new_linearchain() -> Py<PyAny> {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| -> Py<PyAny> {
+        let number_qubits = 4;
+        let single_qubit_gates = ["RotateX".to_string(), "RotateZ".to_string()];
+        let two_qubit_gates = ["CNOT".to_string()];
+        let arguments: (usize, [String; 2], [String; 1], f64) =
+            (number_qubits, single_qubit_gates, two_qubit_gates, 1.0);
+        let device_type = py.get_type_bound::<LinearChainDeviceWrapper>();
+        device_type.call1(arguments).unwrap().into()
+    })
+}
+
+fn new_hexagonallattice() -> Py<PyAny> {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| -> Py<PyAny> {
+        let number_rows: usize = 2;
+        let number_columns: usize = 2;
+        let single_qubit_gates = ["RotateX".to_string(), "RotateZ".to_string()];
+        let two_qubit_gates = ["CNOT".to_string()];
+        let arguments: (usize, usize, bool, [String; 2], [String; 1], f64) = (
+            number_rows,
+            number_columns,
+            true,
+            single_qubit_gates,
+            two_qubit_gates,
+            1.0,
+        );
+        let device_type = py.get_type_bound::<HexagonalLatticeDeviceWrapper>();
+        device_type.call1(arguments).unwrap().into()
+    })
+}
+
 #[test]
 fn test_number_rows() {
     // test parameters
@@ -119,6 +162,8 @@ fn test_gate_names() {
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericdevice(); "generic")]
 #[test_case(new_genericlattice(); "lattice")]
+#[test_case(new_hexagonallattice(); "hexagonal")]
+#[test_case(new_linearchain(); "linear_chain")]
 fn test_number_qubits(device: Py<PyAny>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -135,6 +180,8 @@ fn test_number_qubits(device: Py<PyAny>) {
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericdevice(); "generic")]
 #[test_case(new_genericlattice(); "lattice")]
+#[test_case(new_hexagonallattice(); "hexagonal")]
+#[test_case(new_linearchain(); "linear_chain")]
 fn test_to_from_json(device: Py<PyAny>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -162,9 +209,78 @@ fn test_to_from_json(device: Py<PyAny>) {
     });
 }
 
+/// Test save_to_json_file and load_from_json_file functions
+#[cfg(feature = "json_schema")]
+#[test_case(new_alltoalldevice(), "all_to_all"; "all_to_all")]
+#[test_case(new_genericlattice(), "lattice"; "lattice")]
+fn test_save_load_json_file(device: Py<PyAny>, name: &str) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        device
+            .call_method1(py, "set_single_qubit_gate_time", ("RotateX", 0, 0.5))
+            .unwrap();
+        device
+            .call_method1(py, "set_two_qubit_gate_time", ("CNOT", 0, 1, 0.25))
+            .unwrap();
+        let rates: &Bound<PyArray2<f64>> =
+            &pyarray_bound![py, [1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]];
+        device
+            .call_method1(py, "set_qubit_decoherence_rates", (0, rates))
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("qoqo_test_device_{}.json", name));
+        let path_str = path.to_str().unwrap();
+        device
+            .call_method1(py, "save_to_json_file", (path_str,))
+            .unwrap();
+        let loaded = device
+            .call_method1(py, "load_from_json_file", (path_str,))
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let comparison: bool = loaded
+            .call_method1(py, "__eq__", (device,))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(comparison);
+        let single_qubit_time: Option<f64> = loaded
+            .call_method1(py, "single_qubit_gate_time", ("RotateX", 0))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(single_qubit_time, Some(0.5));
+        let two_qubit_time: Option<f64> = loaded
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 0, 1))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(two_qubit_time, Some(0.25));
+        let loaded_rates_py = loaded
+            .call_method1(py, "qubit_decoherence_rates", (0_i64,))
+            .unwrap();
+        let loaded_rates = loaded_rates_py
+            .downcast_bound::<PyArray2<f64>>(py)
+            .unwrap()
+            .as_gil_ref()
+            .readonly()
+            .as_array()
+            .to_owned();
+        assert_eq!(
+            loaded_rates,
+            array![[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]
+        );
+
+        let error = loaded.call_method1(py, "load_from_json_file", (path_str,));
+        assert!(error.is_err());
+    });
+}
+
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericdevice(); "generic")]
 #[test_case(new_genericlattice(); "lattice")]
+#[test_case(new_hexagonallattice(); "hexagonal")]
+#[test_case(new_linearchain(); "linear_chain")]
 fn test_to_from_bincode(device: Py<PyAny>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -175,8 +291,11 @@ fn test_to_from_bincode(device: Py<PyAny>) {
             .unwrap();
 
         let vec: Vec<u8> = Vec::new();
-        let deserialised_error = new.call_method1(py, "from_bincode", (vec,));
-        assert!(deserialised_error.is_err());
+        let deserialised_error = new.call_method1(py, "from_bincode", (vec,)).unwrap_err();
+        assert!(deserialised_error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert!(deserialised_error
+            .to_string()
+            .contains("Input cannot be deserialized to selected Device: "));
 
         let deserialised_error = deserialised.call_method0(py, "from_bincode");
         assert!(deserialised_error.is_err());
@@ -318,6 +437,7 @@ fn test_json_schema_generic() {
 // Test qubit_decoherence_rates() for GenericGrid
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericlattice(); "lattice")]
+#[test_case(new_linearchain(); "linear_chain")]
 fn test_decoherence_rates_all(device: Py<PyAny>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -378,6 +498,127 @@ fn test_decoherence_rates_all(device: Py<PyAny>) {
     })
 }
 
+// Test decoherence_rate_matrix() after adding damping to all qubits
+#[test_case(new_alltoalldevice(); "all_to_all")]
+#[test_case(new_genericlattice(); "lattice")]
+fn test_decoherence_rate_matrix(device: Py<PyAny>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = device.call_method1(py, "add_damping_all", (0.01,)).unwrap();
+        let number_qubits: usize = device
+            .call_method0(py, "number_qubits")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        for qubit in 0..number_qubits {
+            let matrix = device
+                .call_method1(py, "decoherence_rate_matrix", (qubit,))
+                .unwrap()
+                .downcast_bound::<PyArray2<f64>>(py)
+                .unwrap()
+                .as_gil_ref()
+                .readonly()
+                .as_array()
+                .to_owned();
+            assert!(matrix[[0, 0]] > 0.0);
+        }
+
+        let generic = new_genericdevice();
+        let matrix = generic
+            .call_method1(py, "decoherence_rate_matrix", (0,))
+            .unwrap();
+        assert!(matrix.is_none(py));
+    })
+}
+
+// Test gate_times_dict() for a square lattice and a sparse generic device
+#[test]
+fn test_gate_times_dict() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<SquareLatticeDeviceWrapper>();
+        let device = device_type
+            .call1((2, 2, vec!["RotateZ"], vec!["CNOT"], 1e-6))
+            .unwrap();
+        let gate_times: HashMap<String, f64> = device
+            .call_method0("gate_times_dict")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let mut expected: HashMap<String, f64> = HashMap::new();
+        for qubit in 0..4 {
+            expected.insert(format!("RotateZ:{}", qubit), 1e-6);
+        }
+        for (control, target) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            expected.insert(format!("CNOT:{}:{}", control, target), 1e-6);
+            expected.insert(format!("CNOT:{}:{}", target, control), 1e-6);
+        }
+        assert_eq!(gate_times, expected);
+
+        let device = new_genericdevice();
+        device
+            .call_method1(py, "set_single_qubit_gate_time", ("RotateX", 1, 0.5))
+            .unwrap();
+        device
+            .call_method1(py, "set_two_qubit_gate_time", ("CNOT", 0, 1, 0.25))
+            .unwrap();
+        let gate_times: HashMap<String, f64> = device
+            .call_method0(py, "gate_times_dict")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        let mut expected: HashMap<String, f64> = HashMap::new();
+        expected.insert("RotateX:1".to_string(), 0.5);
+        expected.insert("CNOT:0:1".to_string(), 0.25);
+        assert_eq!(gate_times, expected);
+    })
+}
+
+/// Test setting the decoherence rates of a single qubit of an AllToAllDevice
+#[test]
+fn test_all_to_all_single_qubit_decoherence_rates() {
+    pyo3::prepare_freethreaded_python();
+    let device = new_alltoalldevice();
+    Python::with_gil(|py| {
+        let rates: &Bound<PyArray2<f64>> =
+            &pyarray_bound![py, [1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]];
+        device
+            .call_method1(py, "set_qubit_decoherence_rates", (1, rates))
+            .unwrap();
+
+        let matrix_0 = device
+            .call_method1(py, "qubit_decoherence_rates", (0,))
+            .unwrap()
+            .downcast_bound::<PyArray2<f64>>(py)
+            .unwrap()
+            .as_gil_ref()
+            .readonly()
+            .as_array()
+            .to_owned();
+        assert_eq!(matrix_0, Array2::<f64>::zeros((3, 3)));
+        let matrix_1 = device
+            .call_method1(py, "qubit_decoherence_rates", (1,))
+            .unwrap()
+            .downcast_bound::<PyArray2<f64>>(py)
+            .unwrap()
+            .as_gil_ref()
+            .readonly()
+            .as_array()
+            .to_owned();
+        assert_eq!(
+            matrix_1,
+            array![[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]
+        );
+
+        // The device has 4 qubits, so qubit 4 is out of range
+        let error = device.call_method1(py, "set_qubit_decoherence_rates", (4, rates));
+        assert!(error.is_err());
+        let invalid: &Bound<PyArray2<f64>> = &pyarray_bound![py, [1.0, 0.0], [0.0, 2.0]];
+        let error = device.call_method1(py, "set_qubit_decoherence_rates", (1, invalid));
+        assert!(error.is_err());
+    })
+}
+
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericdevice(); "generic")]
 #[test_case(new_genericlattice(); "lattice")]
@@ -581,6 +822,7 @@ fn test_gatetimes(device: Py<PyAny>) {
 // Test gate_times for AllToAllDevice
 #[test_case(new_alltoalldevice(); "all_to_all")]
 #[test_case(new_genericlattice(); "lattice")]
+#[test_case(new_linearchain(); "linear_chain")]
 fn test_gatetimes_all(device: Py<PyAny>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -636,6 +878,59 @@ fn test_gatetimes_all(device: Py<PyAny>) {
     })
 }
 
+/// Test coupling_map for all device types
+#[test_case(new_alltoalldevice(), vec![[0,1], [0,2], [0,3], [1,0], [1,2], [1,3], [2,0], [2,1], [2,3], [3,0], [3,1], [3,2]]; "all_to_all")]
+#[test_case(new_genericdevice(), vec![]; "generic")]
+#[test_case(new_genericlattice(), vec![[0,1], [0,2], [1,0], [1,3], [2,0], [2,3], [3,1], [3,2]]; "lattice")]
+#[test_case(new_linearchain(), vec![[0,1], [1,0], [1,2], [2,1], [2,3], [3,2]]; "linear_chain")]
+fn test_coupling_map(device: Py<PyAny>, expected: Vec<[usize; 2]>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let coupling_map: Vec<[usize; 2]> = device
+            .call_method0(py, "coupling_map")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(coupling_map, expected);
+    })
+}
+
+/// Test adding gates to a GenericDevice one by one
+#[test]
+fn test_generic_device_add_gates() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut expected = GenericDevice::new(4);
+        expected
+            .set_single_qubit_gate_time("RotateX", 0, 0.5)
+            .unwrap();
+        expected
+            .set_single_qubit_gate_time("RotateZ", 3, 0.1)
+            .unwrap();
+        expected.set_two_qubit_gate_time("CNOT", 0, 1, 1.0).unwrap();
+
+        let device = new_genericdevice();
+        let added = device
+            .call_method1(py, "add_single_qubit_gate", ("RotateX", 0, 0.5))
+            .unwrap()
+            .call_method1(py, "add_single_qubit_gate", ("RotateZ", 3, 0.1))
+            .unwrap()
+            .call_method1(py, "add_two_qubit_gate", ("CNOT", 0, 1, 1.0))
+            .unwrap();
+        let added_wrapper: GenericDeviceWrapper = added.extract(py).unwrap();
+        assert_eq!(added_wrapper.internal, expected);
+
+        // The original device is not changed
+        let original_wrapper: GenericDeviceWrapper = device.extract(py).unwrap();
+        assert_eq!(original_wrapper.internal, GenericDevice::new(4));
+
+        let error = device.call_method1(py, "add_single_qubit_gate", ("RotateX", 4, 0.5));
+        assert!(error.is_err());
+        let error = device.call_method1(py, "add_two_qubit_gate", ("CNOT", 0, 4, 1.0));
+        assert!(error.is_err());
+    })
+}
+
 #[test]
 fn test_derive_generic_device() {
     let device = GenericDevice::default();
@@ -656,6 +951,52 @@ fn test_derive_all_to_all() {
     assert!(wrapper == wrapper);
 }
 
+/// Test per-qubit decoherence functions of SquareLatticeDevice
+#[test]
+fn test_square_lattice_qubit_decoherence() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = new_genericlattice();
+        let device = device
+            .call_method1(py, "add_damping_to_qubit", (1, 1.0))
+            .unwrap();
+        let device = device
+            .call_method1(py, "add_dephasing_to_qubit", (1, 2.0))
+            .unwrap();
+        let device = device
+            .call_method1(py, "add_depolarising_to_qubit", (1, 4.0))
+            .unwrap();
+
+        let rates = |qubit: usize| -> Array2<f64> {
+            device
+                .call_method1(py, "qubit_decoherence_rates", (qubit,))
+                .unwrap()
+                .downcast_bound::<PyArray2<f64>>(py)
+                .unwrap()
+                .as_gil_ref()
+                .readonly()
+                .as_array()
+                .to_owned()
+        };
+        let expected: Array2<f64> = array![
+            [1.0 + 4.0 / 2.0, 0.0, 0.0],
+            [0.0, 4.0 / 2.0, 0.0],
+            [0.0, 0.0, 2.0 + 4.0 / 4.0]
+        ];
+        assert_eq!(rates(1), expected);
+        for qubit in [0, 2, 3] {
+            assert_eq!(rates(qubit), Array2::<f64>::zeros((3, 3)));
+        }
+
+        let error = device.call_method1(py, "add_damping_to_qubit", (4, 1.0));
+        assert!(error.is_err());
+        let error = device.call_method1(py, "add_dephasing_to_qubit", (4, 1.0));
+        assert!(error.is_err());
+        let error = device.call_method1(py, "add_depolarising_to_qubit", (4, 1.0));
+        assert!(error.is_err());
+    })
+}
+
 #[test]
 fn test_derive_square_lattice() {
     let device = SquareLatticeDevice::default();
@@ -666,9 +1007,134 @@ fn test_derive_square_lattice() {
     assert!(wrapper == wrapper);
 }
 
+#[test]
+fn test_hexagonal_lattice() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = new_hexagonallattice();
+        let number_rows: usize = device
+            .call_method0(py, "number_rows")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(number_rows, 2);
+        let number_columns: usize = device
+            .call_method0(py, "number_columns")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(number_columns, 2);
+        let offset_rows: bool = device
+            .call_method0(py, "offset_rows")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(offset_rows);
+        let coordinates: (usize, usize) = device
+            .call_method1(py, "qubit_to_hex_coordinates", (3,))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(coordinates, (1, 1));
+        let error = device.call_method1(py, "qubit_to_hex_coordinates", (4,));
+        assert!(error.is_err());
+
+        let gate_time: Option<f64> = device
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 3, 1))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(gate_time, Some(1.0));
+        let gate_time: Option<f64> = device
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 0, 2))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(gate_time, None);
+        let error = device.call_method1(py, "set_two_qubit_gate_time", ("CNOT", 0, 2, 0.5));
+        assert!(error.is_err());
+
+        let new_device = device
+            .call_method1(py, "set_all_two_qubit_gate_times", ("CNOT", 0.5))
+            .unwrap();
+        let gate_time: Option<f64> = new_device
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 1, 3))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(gate_time, Some(0.5));
+
+        let new_device = device.call_method1(py, "add_damping_all", (0.1,)).unwrap();
+        let rates: Vec<Vec<f64>> = new_device
+            .call_method1(py, "qubit_decoherence_rates", (0,))
+            .unwrap()
+            .call_method0(py, "tolist")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(rates[0][0], 0.1);
+    })
+}
+
+/// Test that a LinearChainDevice survives a bincode round trip through the generic conversion
+#[test]
+fn test_linear_chain_bincode_roundtrip() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = new_linearchain();
+        let device = device
+            .call_method1(py, "add_dephasing_all", (0.5,))
+            .unwrap();
+        let serialised: Vec<u8> = device
+            .call_method0(py, "to_bincode")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        let internal: LinearChainDevice = bincode::deserialize(&serialised).unwrap();
+        let converted = LinearChainDeviceWrapper::from_pyany(device.bind(py)).unwrap();
+        assert_eq!(internal, converted);
+
+        let device_type = py.get_type_bound::<LinearChainDeviceWrapper>();
+        let deserialised = device_type
+            .call_method1("from_bincode", (serialised,))
+            .unwrap();
+        let comparison: bool = deserialised
+            .call_method1("__eq__", (&device,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(comparison);
+
+        let error = device_type.call_method1("from_bincode", (vec![0u8],));
+        assert!(error.is_err());
+    })
+}
+
+#[test]
+fn test_derive_linear_chain() {
+    let device = LinearChainDevice::default();
+
+    let wrapper = LinearChainDeviceWrapper { internal: device };
+
+    // Test Clone and PartialEq
+    assert!(wrapper == wrapper);
+}
+
+#[test]
+fn test_derive_hexagonal_lattice() {
+    let device = HexagonalLatticeDevice::default();
+
+    let wrapper = HexagonalLatticeDeviceWrapper { internal: device };
+
+    // Test Clone and PartialEq
+    assert!(wrapper == wrapper);
+}
+
 #[test_case(new_alltoalldevice(), vec![(0,1), (0,2), (0,3), (1,2), (1,3), (2,3)]; "all_to_all")]
 #[test_case(new_genericdevice(), vec![]; "generic")]
 #[test_case(new_genericlattice(), vec![(0,1), (2,3) ,(0,2), (1,3)]; "lattice")]
+#[test_case(new_hexagonallattice(), vec![(0,1), (1,3), (2,3)]; "hexagonal")]
+#[test_case(new_linearchain(), vec![(0,1), (1,2), (2,3)]; "linear_chain")]
 fn test_edges(device: Py<PyAny>, test_edges: Vec<(usize, usize)>) {
     Python::with_gil(|py| {
         let edges = device
@@ -912,3 +1378,108 @@ mod test_chain_with_environment {
         assert_eq!(chains_with_environment, comparison);
     }
 }
+
+/// Test validate_circuit for valid and invalid circuits
+#[test_case(new_alltoalldevice(), true; "all_to_all")]
+#[test_case(new_genericlattice(), false; "lattice")]
+#[test_case(new_linearchain(), false; "linear_chain")]
+fn test_validate_circuit(device: Py<PyAny>, cnot_0_3_valid: bool) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = device.bind(py);
+        let mut circuit = Circuit::new();
+        circuit += DefinitionBit::new("ro".to_string(), 2, true);
+        circuit += RotateX::new(0, 1.0.into());
+        circuit += CNOT::new(0, 1);
+        circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+        let circuit_wrapper = Py::new(
+            py,
+            CircuitWrapper {
+                internal: circuit.clone(),
+            },
+        )
+        .unwrap();
+        assert!(bool::extract_bound(
+            &device
+                .call_method1("validate_circuit", (circuit_wrapper,))
+                .unwrap()
+        )
+        .unwrap());
+
+        let mut connected = circuit.clone();
+        connected += CNOT::new(0, 3);
+        let connected_wrapper = Py::new(
+            py,
+            CircuitWrapper {
+                internal: connected,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            device
+                .call_method1("validate_circuit", (connected_wrapper,))
+                .is_ok(),
+            cnot_0_3_valid
+        );
+
+        let mut invalid = circuit;
+        invalid += Hadamard::new(0);
+        invalid += RotateZ::new(5, 1.0.into());
+        let invalid_wrapper = Py::new(py, CircuitWrapper { internal: invalid }).unwrap();
+        let error = device
+            .call_method1("validate_circuit", (invalid_wrapper,))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        let message = error.to_string();
+        assert!(message.contains("Hadamard on qubits [0]: gate is not supported by the device"));
+        assert!(message.contains("RotateZ on qubits [5]: qubit is not in the device"));
+
+        let error = device.call_method1("validate_circuit", (1,));
+        assert!(error.is_err());
+    })
+}
+
+/// Test setting the gate time of a two qubit gate for a single pair of a SquareLatticeDevice
+#[test]
+fn test_square_lattice_two_qubit_gate_time() {
+    pyo3::prepare_freethreaded_python();
+    let device = new_genericlattice();
+    Python::with_gil(|py| {
+        device
+            .call_method1(py, "set_two_qubit_gate_time", ("CNOT", 0, 2, 0.25))
+            .unwrap();
+        let gate_time: Option<f64> = device
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 0, 2))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(gate_time, Some(0.25));
+        let gate_time: Option<f64> = device
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 2, 0))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(gate_time, Some(1.0));
+        let gate_time: Option<f64> = device
+            .call_method1(py, "two_qubit_gate_time", ("CNOT", 0, 3))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(gate_time, None);
+
+        // Qubits 0 and 3 are not connected in a 2x2 lattice
+        let error = device.call_method1(py, "set_two_qubit_gate_time", ("CNOT", 0, 3, 0.25));
+        assert!(error
+            .unwrap_err()
+            .is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        // ControlledPauliZ is not a two qubit gate of the device
+        let error = device.call_method1(
+            py,
+            "set_two_qubit_gate_time",
+            ("ControlledPauliZ", 0, 1, 0.25),
+        );
+        assert!(error
+            .unwrap_err()
+            .is_instance_of::<pyo3::exceptions::PyValueError>(py));
+    })
+}
@@ -13,6 +13,7 @@
 //! Integration test for public API of Basis rotation measurement
 
 use bincode::serialize;
+use numpy::pyarray_bound;
 use pyo3::prelude::*;
 use pyo3::Python;
 use qoqo::measurements::{PauliZProductInputWrapper, PauliZProductWrapper};
@@ -957,3 +958,78 @@ fn test_pyo3_json_schema() {
         assert_eq!(minimum_supported_version_string_input, "1.0.0");
     });
 }
+
+/// Test evaluate_with_readout_error_matrix function
+#[test]
+fn test_pyo3_evaluate_with_readout_error_matrix() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type_bound::<PauliZProductInputWrapper>();
+        let binding = input_type.call1((1, false)).unwrap();
+        let input = binding.downcast::<PauliZProductInputWrapper>().unwrap();
+        let tmp_vec: Vec<usize> = vec![0];
+        let _ = input
+            .call_method1("add_pauliz_product", ("ro", tmp_vec))
+            .unwrap();
+        let mut linear_map: HashMap<usize, f64> = HashMap::new();
+        linear_map.insert(0, 1.0);
+        let _ = input
+            .call_method1("add_linear_exp_val", ("z", linear_map))
+            .unwrap();
+
+        let circs: Vec<CircuitWrapper> = vec![CircuitWrapper::new()];
+        let br_type = py.get_type_bound::<PauliZProductWrapper>();
+        let binding = br_type
+            .call1((Some(CircuitWrapper::new()), circs, input))
+            .unwrap();
+        let br = binding.downcast::<PauliZProductWrapper>().unwrap();
+        let mut register: BitOutputRegister = vec![vec![false]; 99];
+        register.push(vec![true]);
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        bit_registers.insert("ro".to_string(), register);
+        let float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+
+        let raw: HashMap<String, f64> = br
+            .call_method1(
+                "evaluate",
+                (
+                    bit_registers.clone(),
+                    float_registers.clone(),
+                    complex_registers.clone(),
+                ),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!((raw.get("z").unwrap() - 0.98).abs() < 1e-10);
+
+        let matrix = pyarray_bound![py, [0.99, 0.01], [0.01, 0.99]];
+        let corrected: HashMap<String, f64> = br
+            .call_method1(
+                "evaluate_with_readout_error_matrix",
+                (
+                    bit_registers.clone(),
+                    float_registers.clone(),
+                    complex_registers.clone(),
+                    matrix,
+                ),
+            )
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!((corrected.get("z").unwrap() - 1.0).abs() < 1e-10);
+
+        let invalid_matrix = pyarray_bound![py, [0.9, 0.1], [0.2, 0.9]];
+        let error = br.call_method1(
+            "evaluate_with_readout_error_matrix",
+            (
+                bit_registers,
+                float_registers,
+                complex_registers,
+                invalid_matrix,
+            ),
+        );
+        assert!(error.is_err());
+    })
+}
@@ -15,3 +15,4 @@ mod decoherence_on_gate;
 mod decoherence_on_idle;
 mod imperfect_readout;
 mod overrotation;
+mod phase_flip;
@@ -0,0 +1,168 @@
+// Copyright © 2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::prelude::*;
+use qoqo::noise_models::*;
+use qoqo::operations::PragmaDephasingWrapper;
+use roqoqo::operations::PragmaDephasing;
+#[cfg(feature = "json_schema")]
+use roqoqo::{noise_models::PhaseFlipNoiseModel, ROQOQO_VERSION};
+
+/// Test new
+#[test]
+fn test_pyo3_init() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<PhaseFlipNoiseModelWrapper>();
+        let binding = br_type.call1((0.2,)).unwrap();
+        let br = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+        let flip_rate: f64 = br.call_method0("flip_rate").unwrap().extract().unwrap();
+        assert_eq!(flip_rate, 0.2);
+
+        assert!(br_type.call1((-0.1,)).is_err());
+        assert!(br_type.call1((0.7,)).is_err());
+    })
+}
+
+/// Test pragma_for_qubit
+#[test]
+fn test_pragma_for_qubit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<PhaseFlipNoiseModelWrapper>();
+
+        let binding = br_type.call1((0.0,)).unwrap();
+        let pragma = binding
+            .call_method1("pragma_for_qubit", (1, 2.0))
+            .unwrap()
+            .extract::<PragmaDephasingWrapper>()
+            .unwrap();
+        assert_eq!(
+            pragma.internal,
+            PragmaDephasing::new(1, 2.0.into(), 0.0.into())
+        );
+
+        let binding = br_type.call1((0.5,)).unwrap();
+        let pragma = binding
+            .call_method1("pragma_for_qubit", (0, 1.0))
+            .unwrap()
+            .extract::<PragmaDephasingWrapper>()
+            .unwrap();
+        assert_eq!(*pragma.internal.rate().float().unwrap(), f64::INFINITY);
+
+        let error = binding.call_method1("pragma_for_qubit", (0, 0.0));
+        assert!(error.is_err());
+        let error = binding.call_method1("pragma_for_qubit", (0, -1.0));
+        assert!(error.is_err());
+    })
+}
+
+/// Test debug
+#[test]
+fn test_pyo3_debug() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<PhaseFlipNoiseModelWrapper>();
+        let binding = br_type.call1((0.1,)).unwrap();
+        let br = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+        let br_copied = br
+            .call_method0("__copy__")
+            .unwrap()
+            .extract::<PhaseFlipNoiseModelWrapper>()
+            .unwrap();
+
+        let br_wrapper = br.extract::<PhaseFlipNoiseModelWrapper>().unwrap();
+
+        let br_clone = br_wrapper.clone();
+        assert_eq!(format!("{:?}", br_wrapper), format!("{:?}", br_clone));
+        assert_eq!(format!("{:?}", br_copied), format!("{:?}", br_clone));
+    })
+}
+
+/// Test to_json and from_json functions
+#[test]
+fn test_to_from_json() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<PhaseFlipNoiseModelWrapper>();
+        let binding = br_type.call1((0.3,)).unwrap();
+        let br = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+
+        let serialised = br.call_method0("to_json").unwrap();
+        let binding = br.call_method1("from_json", (&serialised,)).unwrap();
+        let deserialised = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+        let comparison =
+            bool::extract_bound(&deserialised.call_method1("__eq__", (br,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let deserialised_error =
+            br.call_method1("from_json", (serde_json::to_string("fails").unwrap(),));
+        assert!(deserialised_error.is_err());
+
+        let other = py
+            .get_type_bound::<ImperfectReadoutModelWrapper>()
+            .call0()
+            .unwrap()
+            .call_method0("to_json")
+            .unwrap();
+        let deserialised_error = br.call_method1("from_json", (other,));
+        assert!(deserialised_error.is_err());
+    })
+}
+
+/// Test to_bincode and from_bincode functions
+#[test]
+fn test_to_from_bincode() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<PhaseFlipNoiseModelWrapper>();
+        let binding = br_type.call1((0.3,)).unwrap();
+        let br = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+
+        let serialised = br.call_method0("to_bincode").unwrap();
+        let binding = br.call_method1("from_bincode", (&serialised,)).unwrap();
+        let deserialised = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+        let comparison =
+            bool::extract_bound(&deserialised.call_method1("__eq__", (br,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let deserialised_error =
+            br.call_method1("from_bincode", (bincode::serialize("fails").unwrap(),));
+        assert!(deserialised_error.is_err());
+    })
+}
+
+/// Test json_schema function of PhaseFlipNoiseModel
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let br_type = py.get_type_bound::<PhaseFlipNoiseModelWrapper>();
+        let binding = br_type.call1((0.1,)).unwrap();
+        let br = binding.downcast::<PhaseFlipNoiseModelWrapper>().unwrap();
+
+        let schema: String =
+            String::extract_bound(&br.call_method0("json_schema").unwrap()).unwrap();
+        let rust_schema =
+            serde_json::to_string_pretty(&schemars::schema_for!(PhaseFlipNoiseModel)).unwrap();
+        assert_eq!(schema, rust_schema);
+
+        let current_version_string =
+            String::extract_bound(&br.call_method0("current_version").unwrap()).unwrap();
+        let minimum_supported_version_string =
+            String::extract_bound(&br.call_method0("min_supported_version").unwrap()).unwrap();
+
+        assert_eq!(current_version_string, ROQOQO_VERSION);
+        assert_eq!(minimum_supported_version_string, "1.14.0");
+    });
+}
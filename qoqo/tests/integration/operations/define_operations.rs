@@ -10,12 +10,11 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[cfg(feature = "unstable_operation_definition")]
 use super::pragma_operations::new_circuit;
 use pyo3::prelude::*;
 use qoqo::operations::*;
+use qoqo::CircuitWrapper;
 use roqoqo::operations::*;
-#[cfg(feature = "unstable_operation_definition")]
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
@@ -209,9 +208,57 @@ fn test_pyo3_new_input_bit() {
     })
 }
 
+/// Test InputBit from_register() and apply_to_circuit() functions
+#[test]
+fn test_pyo3_input_bit_from_register() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let values = vec![true, false, false, true];
+        let operation = py.get_type_bound::<InputBitWrapper>();
+        let input_bits: Vec<InputBitWrapper> = operation
+            .call_method1("from_register", ("ro", values.clone()))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(input_bits.len(), 4);
+        for (index, (input_bit, value)) in input_bits.iter().zip(values.iter()).enumerate() {
+            assert_eq!(
+                input_bit.internal,
+                InputBit::new("ro".to_string(), index, *value)
+            );
+        }
+
+        let mut circuit = Circuit::new();
+        circuit += DefinitionBit::new("ro".to_string(), 4, false);
+        circuit += PauliX::new(0);
+        let apply = wrap_pyfunction!(apply_to_circuit, py).unwrap();
+        let result: CircuitWrapper = apply
+            .call1((
+                CircuitWrapper {
+                    internal: circuit.clone(),
+                },
+                "ro",
+                values.clone(),
+            ))
+            .unwrap()
+            .extract()
+            .unwrap();
+        let mut comparison_circuit: Circuit = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| InputBit::new("ro".to_string(), index, *value))
+            .collect();
+        comparison_circuit += circuit;
+        assert_eq!(result.internal, comparison_circuit);
+        assert_eq!(result.internal.len(), 6);
+
+        let error = apply.call1((vec!["fails"], "ro", values));
+        assert!(error.is_err());
+    })
+}
+
 /// Test GateDefinition new() function
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_pyo3_new_gate_definition() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -393,7 +440,6 @@ fn test_pyo3_input_bit_value() {
     })
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 /// Test inputs for GateDefinition
 #[test]
 fn test_pyo3_gate_definition_inputs() {
@@ -468,7 +514,6 @@ fn test_pyo3_involved_qubits(input_definition: Operation) {
 }
 
 /// Test GateDefinition involved_qubits function
-#[cfg(feature = "unstable_operation_definition")]
 #[test_case(Operation::from(GateDefinition::new(Circuit::new(), String::from("ro"), vec![1], vec!["test".into()])); "GateDefinition")]
 fn test_pyo3_involved_qubits_gate_definition(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
@@ -528,7 +573,6 @@ fn test_pyo3_input_symbolic_format_repr() {
 }
 
 /// Test GateDefinition format and repr functions
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_gate_definition_format_repr() {
     pyo3::prepare_freethreaded_python();
@@ -587,7 +631,6 @@ fn test_pyo3_copy_deepcopy(input_definition: Operation) {
 
 /// Test GateDefinition copy and deepcopy functions
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_pyo3_copy_deepcopy_gate_definition() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -680,7 +723,6 @@ fn test_pyo3_is_parametrized(input_definition: Operation) {
 }
 
 // Test GateDefinitions's tags, hslang and is_parametrized functions
-#[cfg(feature = "unstable_operation_definition")]
 #[test_case(Operation::from(GateDefinition::new(Circuit::new(), String::from("ro"), vec![1], vec!["test".into()])); "GateDefinition")]
 fn test_pyo3_gate_definition(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
@@ -739,7 +781,6 @@ fn test_pyo3_substitute_parameters(input_definition: Operation) {
 }
 
 /// Test GateDefinitions's substitute_parameters functions
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_substitute_parameters_gate_definition() {
     pyo3::prepare_freethreaded_python();
@@ -788,7 +829,6 @@ fn test_pyo3_substitute_parameters_error(input_operation: Operation) {
 }
 
 /// Test GateDefinitions's substitute_parameters() causing an error `not-a-real-number`
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_substitute_parameters_error_gate_definition() {
     pyo3::prepare_freethreaded_python();
@@ -838,7 +878,6 @@ fn test_pyo3_remap_qubits(input_definition: Operation) {
 }
 
 /// Test GateDefinitions's remap_qubits functions
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_remap_qubits_gate_definition() {
     pyo3::prepare_freethreaded_python();
@@ -930,7 +969,6 @@ fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
 
 /// Test the __richcmp__ function for GateDefinitions
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_pyo3_richcmp_gate_definition() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -1032,7 +1070,6 @@ fn test_pyo3_json_schema(operation: Operation) {
 
 /// Test json_schema function for GateDefinitions
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 #[cfg(feature = "json_schema")]
 fn test_pyo3_json_schema_gate_definition() {
     let operation = Operation::from(GateDefinition::new(
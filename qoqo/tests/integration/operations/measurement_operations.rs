@@ -701,6 +701,91 @@ fn test_pyo3_new_get_pauli_product() {
     })
 }
 
+/// Test PragmaGetPauliProducts new() function
+#[test]
+fn test_pyo3_new_get_pauli_products() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaGetPauliProductsWrapper>();
+        let binding = operation
+            .call1((
+                vec![create_qubit_mapping(), qubits_remapped_pauli()],
+                "ro".to_string(),
+                new_circuit(py),
+            ))
+            .unwrap();
+        let new_op = binding.downcast::<PragmaGetPauliProductsWrapper>().unwrap();
+
+        let input_definition = Operation::from(PragmaGetPauliProducts::new(
+            vec![create_qubit_mapping(), qubits_remapped_pauli()],
+            String::from("ro"),
+            Circuit::default(),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let qubit_paulis_list = new_op
+            .call_method0("qubit_paulis_list")
+            .unwrap()
+            .extract::<Vec<HashMap<usize, usize>>>()
+            .unwrap();
+        assert_eq!(
+            qubit_paulis_list,
+            vec![create_qubit_mapping(), qubits_remapped_pauli()]
+        );
+
+        let involved_qubits = new_op
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract::<HashSet<usize>>()
+            .unwrap();
+        let mut qubits: HashSet<usize> = create_qubit_mapping().into_keys().collect();
+        qubits.extend(qubits_remapped_pauli().into_keys());
+        assert_eq!(involved_qubits, qubits);
+    })
+}
+
+/// Test PragmaGetReducedDensityMatrix new() function
+#[test]
+fn test_pyo3_new_get_reduced_density_matrix() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaGetReducedDensityMatrixWrapper>();
+        let binding = operation
+            .call1(("ro".to_string(), vec![2_usize, 0], new_circuit(py)))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaGetReducedDensityMatrixWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaGetReducedDensityMatrix::new(
+            String::from("ro"),
+            vec![2, 0],
+            Some(Circuit::default()),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let qubits = new_op
+            .call_method0("qubits")
+            .unwrap()
+            .extract::<Vec<usize>>()
+            .unwrap();
+        assert_eq!(qubits, vec![2, 0]);
+
+        let involved_qubits = new_op
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract::<HashSet<usize>>()
+            .unwrap();
+        assert_eq!(involved_qubits, HashSet::from([0, 2]));
+    })
+}
+
 /// Test PragmaRepeatedMeasurement new() function
 #[test]
 fn test_pyo3_new_repeated_measurement() {
@@ -13,12 +13,15 @@
 use ndarray::Array2;
 use num_complex::Complex64;
 use numpy::PyArray2;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 use qoqo::operations::convert_operation_to_pyobject;
-#[cfg(feature = "unstable_operation_definition")]
 use qoqo::operations::CallDefinedGateWrapper;
-use qoqo::operations::{MultiQubitMSWrapper, MultiQubitZZWrapper};
+use qoqo::operations::{
+    MultiQubitCNOTWrapper, MultiQubitMSWrapper, MultiQubitXXWrapper, MultiQubitZZWrapper,
+    QFTWrapper,
+};
 use qoqo::CircuitWrapper;
 use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorFloat;
@@ -114,7 +117,186 @@ fn test_new_multi_qubit_zz(input_operation: Operation, arguments: (Vec<u32>, f64
     })
 }
 
-#[cfg(feature = "unstable_operation_definition")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::ZERO)), (vec![0, 1], 0.0,), "__eq__"; "MultiQubitXX_eq")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![2, 3], CalculatorFloat::ZERO)), (vec![0, 1], 0.0,), "__ne__"; "MultiQubitXX_ne")]
+fn test_new_multi_qubit_xx(input_operation: Operation, arguments: (Vec<u32>, f64), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<MultiQubitXXWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<MultiQubitXXWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1(([0, 1], vec!["fails"]));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<MultiQubitXXWrapper>().unwrap();
+        let binding = operation_type.call1((vec![1, 2], 0.0)).unwrap();
+        let new_op_diff = binding.downcast::<MultiQubitXXWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<MultiQubitXXWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "MultiQubitXXWrapper { internal: MultiQubitXX { qubits: [1, 2], theta: Float(0.0) } }"
+        );
+    })
+}
+
+#[test_case(Operation::from(QFT::new(vec![0, 1], false, true)), (vec![0, 1], false, true,), "__eq__"; "QFT_eq")]
+#[test_case(Operation::from(QFT::new(vec![0, 1], true, true)), (vec![0, 1], false, true,), "__ne__"; "QFT_ne")]
+fn test_new_qft(input_operation: Operation, arguments: (Vec<u32>, bool, bool), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<QFTWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<QFTWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1(([0, 1], vec!["fails"], true));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<QFTWrapper>().unwrap();
+        let binding = operation_type.call1((vec![1, 2], true, false)).unwrap();
+        let new_op_diff = binding.downcast::<QFTWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<QFTWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "QFTWrapper { internal: QFT { qubits: [1, 2], inverse: true, with_swaps: false } }"
+        );
+    })
+}
+
+/// Test new() function and validation of MultiQubitCNOT
+#[test]
+fn test_new_multi_qubit_cnot() {
+    let operation =
+        convert_operation_to_pyobject(Operation::from(MultiQubitCNOT::new(vec![0, 1, 2]))).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation_type = py.get_type_bound::<MultiQubitCNOTWrapper>();
+        let binding = operation_type.call1((vec![0, 1, 2],)).unwrap();
+        let operation_py = binding.downcast::<MultiQubitCNOTWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1("__eq__", (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((vec![0],));
+        assert!(result.unwrap_err().is_instance_of::<PyValueError>(py));
+        let result = operation_type.call1((Vec::<usize>::new(),));
+        assert!(result.is_err());
+        let result = operation_type.call1((vec!["fails"],));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<MultiQubitCNOTWrapper>().unwrap();
+        let binding = operation_type.call1((vec![1, 2],)).unwrap();
+        let def_wrapper_diff = binding.extract::<MultiQubitCNOTWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "MultiQubitCNOTWrapper { internal: MultiQubitCNOT { qubits: [1, 2] } }"
+        );
+
+        let circuit = operation_py
+            .call_method0("circuit")
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut comparison_circuit = Circuit::new();
+        comparison_circuit += CNOT::new(0, 1);
+        comparison_circuit += CNOT::new(0, 2);
+        assert_eq!(circuit.internal, comparison_circuit);
+    })
+}
+
+/// Test the unroll function of QFT against its circuit and unitary matrix
+#[test]
+fn test_pyo3_qft_unroll() {
+    let qft = QFT::new(vec![0, 1], false, true);
+    let operation = convert_operation_to_pyobject(Operation::from(qft.clone())).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let unrolled = operation
+            .call_method0(py, "unroll")
+            .unwrap()
+            .bind(py)
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(unrolled.internal, qft.circuit());
+
+        let inverse: bool = operation
+            .call_method0(py, "inverse")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(!inverse);
+        let with_swaps: bool = operation
+            .call_method0(py, "with_swaps")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(with_swaps);
+
+        let py_result = operation.call_method0(py, "unitary_matrix").unwrap();
+        let matrix: Array2<Complex64> = py_result
+            .downcast_bound::<PyArray2<Complex64>>(py)
+            .unwrap()
+            .as_gil_ref()
+            .readonly()
+            .as_array()
+            .to_owned();
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        let test_matrix = ndarray::array![
+            [one, one, one, one],
+            [one, i, -one, -i],
+            [one, -one, one, -one],
+            [one, -i, -one, i],
+        ] * Complex64::new(0.5, 0.0);
+        assert!((matrix - test_matrix).iter().all(|x| x.norm() < 1e-12));
+    })
+}
+
 #[test_case(Operation::from(CallDefinedGate::new("name".to_owned(), vec![0, 1], vec![CalculatorFloat::from(0.0)])), ("name".to_owned(), vec![0, 1], vec![0.0],), "__eq__"; "CallDefinedGate_eq")]
 #[test_case(Operation::from(CallDefinedGate::new("name".to_owned(), vec![2, 3], vec![CalculatorFloat::from(0.0)])), ("name".to_owned(), vec![0, 1], vec![0.0],), "__ne__"; "CallDefinedGate_ne")]
 fn test_new_call_defined_gate(
@@ -167,6 +349,7 @@ fn test_new_call_defined_gate(
 /// Test is_parametrized() function for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitXX")]
 fn test_pyo3_is_parametrized(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -183,6 +366,7 @@ fn test_pyo3_is_parametrized(input_operation: Operation) {
 /// Test is_parametrized = false for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::PI)); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::PI)); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::PI)); "MultiQubitXX")]
 fn test_pyo3_is_not_parametrized(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -200,7 +384,9 @@ fn test_pyo3_is_not_parametrized(input_operation: Operation) {
 #[test_case(CalculatorFloat::from(0), Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitMS float")]
 #[test_case(CalculatorFloat::from("theta"), Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitMS symb")]
 #[test_case(CalculatorFloat::from(0), Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitZZ float")]
+#[test_case(CalculatorFloat::from(0), Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitXX float")]
 #[test_case(CalculatorFloat::from("theta"), Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitZZ symb")]
+#[test_case(CalculatorFloat::from("theta"), Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::from("theta"))); "MultiQubitXX symb")]
 fn test_pyo3_theta(theta: CalculatorFloat, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -221,7 +407,11 @@ fn test_pyo3_theta(theta: CalculatorFloat, input_operation: Operation) {
 #[test_case(vec![0, 1], Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitMS two")]
 #[test_case(vec![0, 1, 2], Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(0))); "MultiQubitMS three")]
 #[test_case(vec![0, 1], Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitZZ two")]
+#[test_case(vec![0, 1], Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitXX two")]
 #[test_case(vec![0, 1, 2], Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0))); "MultiQubitZZ three")]
+#[test_case(vec![0, 1, 2], Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(0))); "MultiQubitXX three")]
+#[test_case(vec![0, 1, 2], Operation::from(QFT::new(vec![0, 1, 2], false, true)); "QFT three")]
+#[test_case(vec![0, 1, 2], Operation::from(MultiQubitCNOT::new(vec![0, 1, 2])); "MultiQubitCNOT three")]
 fn test_pyo3_qubits(qubit: Vec<usize>, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -239,6 +429,9 @@ fn test_pyo3_qubits(qubit: Vec<usize>, input_operation: Operation) {
 /// Test hqslang() function for MultiQubitGate Operations
 #[test_case("MultiQubitMS", Operation::from(MultiQubitMS::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitMS")]
 #[test_case("MultiQubitZZ", Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitZZ")]
+#[test_case("MultiQubitXX", Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::from(0))); "MultiQubitXX")]
+#[test_case("QFT", Operation::from(QFT::new(vec![0, 1], false, true)); "QFT")]
+#[test_case("MultiQubitCNOT", Operation::from(MultiQubitCNOT::new(vec![0, 1])); "MultiQubitCNOT")]
 fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -274,6 +467,34 @@ fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
         "MultiQubitZZ",
         ];
     "MultiQubitZZ")]
+#[test_case(
+    Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(0))),
+    vec![
+        "Operation",
+        "GateOperation",
+        "MultiQubitGateOperation",
+        // "Rotation",
+        "MultiQubitXX",
+        ];
+    "MultiQubitXX")]
+#[test_case(
+    Operation::from(QFT::new(vec![0, 1, 2], false, true)),
+    vec![
+        "Operation",
+        "GateOperation",
+        "MultiQubitGateOperation",
+        "QFT",
+        ];
+    "QFT")]
+#[test_case(
+    Operation::from(MultiQubitCNOT::new(vec![0, 1, 2])),
+    vec![
+        "Operation",
+        "GateOperation",
+        "MultiQubitGateOperation",
+        "MultiQubitCNOT",
+        ];
+    "MultiQubitCNOT")]
 fn test_pyo3_tags(input_operation: Operation, tags: Vec<&str>) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -292,7 +513,6 @@ fn test_pyo3_tags(input_operation: Operation, tags: Vec<&str>) {
 }
 
 // Test CallDefinedGate's tags, hslang and is_parametrized functions
-#[cfg(feature = "unstable_operation_definition")]
 #[test_case(Operation::from(CallDefinedGate::new("name".to_owned(), vec![0, 1], vec![CalculatorFloat::from(0.0)])); "CallDefinedGate")]
 fn test_pyo3_gate_definition(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
@@ -322,7 +542,6 @@ fn test_pyo3_gate_definition(input_definition: Operation) {
 }
 
 /// Test inputs for CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_call_defined_gate_inputs() {
     pyo3::prepare_freethreaded_python();
@@ -369,6 +588,7 @@ fn test_pyo3_call_defined_gate_inputs() {
 /// Test remap_qubits() function for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitXX")]
 fn test_pyo3_remapqubits(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -403,7 +623,6 @@ fn test_pyo3_remapqubits(input_operation: Operation) {
 }
 
 /// Test remap_qubits() function for CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_remapqubits_call_defined_gate() {
     pyo3::prepare_freethreaded_python();
@@ -446,6 +665,7 @@ fn test_pyo3_remapqubits_call_defined_gate() {
 // test remap_qubits() function returning an error.
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitXX")]
 fn test_pyo3_remapqubits_error(input_operation: Operation) {
     // preparation
     pyo3::prepare_freethreaded_python();
@@ -460,7 +680,6 @@ fn test_pyo3_remapqubits_error(input_operation: Operation) {
 }
 
 /// test remap_qubits() function returning an error.
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_remapqubits_error_call_defined_gate() {
     // preparation
@@ -483,6 +702,9 @@ fn test_pyo3_remapqubits_error_call_defined_gate() {
 /// Test unitary_matrix() function for MultiQubitGate Operations
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitXX")]
+#[test_case(Operation::from(QFT::new(vec![0, 1, 2], false, true)); "QFT")]
+#[test_case(Operation::from(MultiQubitCNOT::new(vec![0, 1, 2])); "MultiQubitCNOT")]
 fn test_pyo3_unitarymatrix(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -508,6 +730,7 @@ fn test_pyo3_unitarymatrix(input_operation: Operation) {
 /// Test unitary_matrix() function for MultiQubitGate Operations for the error case
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from("PI"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from("PI"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from("PI"))); "MultiQubitXX")]
 fn test_pyo3_unitarymatrix_error(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -567,9 +790,87 @@ fn test_pyo3_circuit_zz() {
     })
 }
 
+/// Test circuit() function for MultiQubitXX
+#[test]
+fn test_pyo3_circuit_xx() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_operation =
+            Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(1.0)));
+        let operation = convert_operation_to_pyobject(input_operation).unwrap();
+        let py_result = operation.call_method0(py, "circuit").unwrap();
+        let result_circuit: CircuitWrapper = py_result.extract(py).unwrap();
+
+        let mut circuit = Circuit::new();
+        circuit += Hadamard::new(0);
+        circuit += Hadamard::new(1);
+        circuit += Hadamard::new(2);
+        circuit += CNOT::new(0, 1);
+        circuit += CNOT::new(1, 2);
+        circuit += RotateZ::new(2, CalculatorFloat::from(1.0));
+        circuit += CNOT::new(1, 2);
+        circuit += CNOT::new(0, 1);
+        circuit += Hadamard::new(0);
+        circuit += Hadamard::new(1);
+        circuit += Hadamard::new(2);
+
+        assert_eq!(result_circuit.internal, circuit);
+    })
+}
+
+/// Test that unitary_matrix() of the two-qubit MultiQubitZZ and MultiQubitXX matches the explicit matrices
+#[test_case(0.0; "zero")]
+#[test_case(1.3; "float")]
+#[test_case(std::f64::consts::PI; "pi")]
+fn test_pyo3_unitarymatrix_two_qubit(theta: f64) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let c = Complex64::new((theta / 2.0).cos(), 0.0);
+        let s = Complex64::new(0.0, (theta / 2.0).sin());
+        let zero = Complex64::new(0.0, 0.0);
+        // exp(-i * theta/2 * Z_0 * Z_1)
+        let rzz_matrix: Array2<Complex64> = ndarray::array![
+            [c - s, zero, zero, zero],
+            [zero, c + s, zero, zero],
+            [zero, zero, c + s, zero],
+            [zero, zero, zero, c - s],
+        ];
+        // exp(-i * theta/2 * X_0 * X_1)
+        let rxx_matrix: Array2<Complex64> = ndarray::array![
+            [c, zero, zero, -s],
+            [zero, c, -s, zero],
+            [zero, -s, c, zero],
+            [-s, zero, zero, c],
+        ];
+        for (operation, test_matrix) in [
+            (
+                Operation::from(MultiQubitZZ::new(vec![0, 1], CalculatorFloat::from(theta))),
+                rzz_matrix,
+            ),
+            (
+                Operation::from(MultiQubitXX::new(vec![0, 1], CalculatorFloat::from(theta))),
+                rxx_matrix,
+            ),
+        ] {
+            let operation = convert_operation_to_pyobject(operation).unwrap();
+            let py_result = operation.call_method0(py, "unitary_matrix").unwrap();
+            let result_matrix: Array2<Complex64> = py_result
+                .downcast_bound::<PyArray2<Complex64>>(py)
+                .unwrap()
+                .as_gil_ref()
+                .readonly()
+                .as_array()
+                .to_owned();
+            let should_be_zero = result_matrix - test_matrix;
+            assert!(should_be_zero.iter().all(|x| x.norm() < 1e-12));
+        }
+    })
+}
+
 /// Test copy and deepcopy functions
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(1.3))); "MultiQubitXX")]
 fn test_pyo3_copy_deepcopy(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -598,7 +899,6 @@ fn test_pyo3_copy_deepcopy(input_operation: Operation) {
 }
 
 /// Test copy and deepcopy functions for CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_copy_deepcopy_call_defined_gate() {
     pyo3::prepare_freethreaded_python();
@@ -641,6 +941,10 @@ fn test_pyo3_copy_deepcopy_call_defined_gate() {
     "MultiQubitZZ { qubits: [0, 1, 2], theta: Float(0.0) }",
     Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::ZERO));
     "MultiQubitZZ")]
+#[test_case(
+    "MultiQubitXX { qubits: [0, 1, 2], theta: Float(0.0) }",
+    Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::ZERO));
+    "MultiQubitXX")]
 fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -655,7 +959,6 @@ fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
 }
 
 /// Test format and repr functions for CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_format_repr_call_defined_gate() {
     pyo3::prepare_freethreaded_python();
@@ -684,6 +987,7 @@ fn test_pyo3_format_repr_call_defined_gate() {
 /// Test substitute_parameters() function for one parameter
 #[test_case(Operation::from(MultiQubitMS::new(vec![1, 2, 3], CalculatorFloat::from("theta"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![1, 2, 3], CalculatorFloat::from("theta"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![1, 2, 3], CalculatorFloat::from("theta"))); "MultiQubitXX")]
 fn test_pyo3_substitute_params_rotate(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -712,7 +1016,6 @@ fn test_pyo3_substitute_params_rotate(input_operation: Operation) {
     })
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_substitute_call_defined_gate() {
     pyo3::prepare_freethreaded_python();
@@ -754,6 +1057,7 @@ fn test_pyo3_substitute_call_defined_gate() {
 /// Test substitute_parameters() causing an error `None`
 #[test_case(Operation::from(MultiQubitMS::new(vec![1, 2], CalculatorFloat::from("test"))); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![1, 2], CalculatorFloat::from("test"))); "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![1, 2], CalculatorFloat::from("test"))); "MultiQubitXX")]
 fn test_pyo3_substitute_params_error(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -764,7 +1068,6 @@ fn test_pyo3_substitute_params_error(input_operation: Operation) {
     })
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_substitute_params_error_call_defined_gate() {
     pyo3::prepare_freethreaded_python();
@@ -787,6 +1090,9 @@ fn test_pyo3_substitute_params_error_call_defined_gate() {
 #[test_case(
     Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0.005))),
     Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0.005 * 1.5))); "MultiQubitZZ")]
+#[test_case(
+    Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(0.005))),
+    Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(0.005 * 1.5))); "MultiQubitXX")]
 fn test_pyo3_rotate_powercf(first_op: Operation, second_op: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -813,6 +1119,9 @@ fn test_pyo3_rotate_powercf(first_op: Operation, second_op: Operation) {
 #[test_case(
     Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0))),
     Operation::from(MultiQubitZZ::new(vec![1, 2], CalculatorFloat::from(0))); "MultiQubitZZ")]
+#[test_case(
+    Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(0))),
+    Operation::from(MultiQubitXX::new(vec![1, 2], CalculatorFloat::from(0))); "MultiQubitXX")]
 fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -846,7 +1155,6 @@ fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
 }
 
 /// Test the __richcmp__ function for CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_pyo3_richcmp_call_defined_gate() {
     pyo3::prepare_freethreaded_python();
@@ -891,9 +1199,12 @@ fn test_pyo3_richcmp_call_defined_gate() {
 }
 
 #[cfg(feature = "json_schema")]
-#[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(0))); "MultiQubitMS")]
-#[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0))); "MultiQubitZZ")]
-fn test_pyo3_json_schema(operation: Operation) {
+#[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], CalculatorFloat::from(0))), "1.0.0"; "MultiQubitMS")]
+#[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], CalculatorFloat::from(0))), "1.0.0"; "MultiQubitZZ")]
+#[test_case(Operation::from(MultiQubitXX::new(vec![0, 1, 2], CalculatorFloat::from(0))), "1.14.0"; "MultiQubitXX")]
+#[test_case(Operation::from(QFT::new(vec![0, 1, 2], true, false)), "1.14.0"; "QFT")]
+#[test_case(Operation::from(MultiQubitCNOT::new(vec![0, 1, 2])), "1.14.0"; "MultiQubitCNOT")]
+fn test_pyo3_json_schema(operation: Operation, min_version: &str) {
     let rust_schema = match operation {
         Operation::MultiQubitMS(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(MultiQubitMS)).unwrap()
@@ -901,6 +1212,13 @@ fn test_pyo3_json_schema(operation: Operation) {
         Operation::MultiQubitZZ(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(MultiQubitZZ)).unwrap()
         }
+        Operation::MultiQubitXX(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(MultiQubitXX)).unwrap()
+        }
+        Operation::QFT(_) => serde_json::to_string_pretty(&schemars::schema_for!(QFT)).unwrap(),
+        Operation::MultiQubitCNOT(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(MultiQubitCNOT)).unwrap()
+        }
         _ => unreachable!(),
     };
     pyo3::prepare_freethreaded_python();
@@ -920,12 +1238,11 @@ fn test_pyo3_json_schema(operation: Operation) {
                 .unwrap();
 
         assert_eq!(current_version_string, ROQOQO_VERSION);
-        assert_eq!(minimum_supported_version_string, "1.0.0");
+        assert_eq!(minimum_supported_version_string, min_version);
     });
 }
 
 /// Test the json schema for CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_pyo3_json_schema_call_defined_gate() {
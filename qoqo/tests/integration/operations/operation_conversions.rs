@@ -49,6 +49,16 @@ use test_case::test_case;
         )
     ); "RotateAroundSphericalAxis")
 ]
+#[test_case(Operation::from(
+    RotateAxis::new(
+        0,
+        CalculatorFloat::from(PI),
+        CalculatorFloat::from(0),
+        CalculatorFloat::from("y"),
+        CalculatorFloat::from(1),
+).unwrap()
+    ); "RotateAxis")
+]
 #[test_case(Operation::from(RotateXY::new(0, CalculatorFloat::from(0), 1.0.into())); "RotateXY float")]
 #[test_case(Operation::from(PauliX::new(1)); "PauliX")]
 #[test_case(Operation::from(PauliY::new(1)); "PauliY")]
@@ -69,6 +79,10 @@ use test_case::test_case;
 #[test_case(Operation::from(InvSqrtISwap::new(0, 1)); "InvSqrtISwap")]
 #[test_case(Operation::from(XY::new(0, 1, CalculatorFloat::PI)); "XY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::FRAC_PI_4)); "ZXCoupling")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 #[test_case(Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::FRAC_PI_4)); "ControlledPhaseShift")]
 #[test_case(Operation::from(PhaseShiftedControlledZ::new(0, 1, CalculatorFloat::FRAC_PI_4)); "PhaseShiftedControlledZ")]
 #[test_case(Operation::from(PhaseShiftedControlledPhase::new(0, 1, CalculatorFloat::FRAC_PI_4, 0.1.into())); "PhaseShiftedControlledPhase")]
@@ -84,6 +98,8 @@ use test_case::test_case;
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(MultiQubitMS::new(vec![0, 1, 2], 0.1.into())); "MultiQubitMS")]
 #[test_case(Operation::from(MultiQubitZZ::new(vec![0, 1, 2], 0.1.into())); "MultiQubitZZ")]
+#[test_case(Operation::from(QFT::new(vec![0, 1, 2], false, true)); "QFT")]
+#[test_case(Operation::from(MultiQubitCNOT::new(vec![0, 1, 2])); "MultiQubitCNOT")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
@@ -104,15 +120,21 @@ use test_case::test_case;
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(reordering(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
+#[test_case(Operation::from(PragmaGetPauliProducts::new(vec![reordering(), reordering()], String::from("ro"), create_circuit())); "PragmaGetPauliProducts")]
+#[test_case(Operation::from(PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 1], Some(create_circuit()))); "PragmaGetReducedDensityMatrix")]
 #[test_case(Operation::from(PragmaRepeatedMeasurement::new(String::from("ro"), 2, Some(reordering()))); "PragmaRepeatedMeasurement")]
 #[test_case(Operation::from(PragmaSetNumberOfMeasurements::new(1, String::from("ro"))); "PragmaSetNumberOfMeasurements")]
+#[test_case(Operation::from(PragmaSimulationRepetitions::new(100)); "PragmaSimulationRepetitions")]
 #[test_case(Operation::from(PragmaSetStateVector::new(statevector())); "PragmaSetStateVector")]
 #[test_case(Operation::from(PragmaSetDensityMatrix::new(densitymatrix())); "PragmaSetDensityMatrix")]
+#[test_case(Operation::from(PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()])); "PragmaSetStabilizerState")]
+#[test_case(Operation::from(PragmaCustomTwoQubitGate::new(0, 1, Array2::eye(4))); "PragmaCustomTwoQubitGate")]
 #[test_case(Operation::from(PragmaRepeatGate::new(3)); "PragmaRepeatGate")]
 #[test_case(Operation::from(PragmaBoostNoise::new(CalculatorFloat::from(0.003))); "PragmaBoostNoise")]
 #[test_case(Operation::from(PragmaStopParallelBlock::new(vec![0, 1], CalculatorFloat::from(0.0000001))); "PragmaStopParallelBlock")]
 #[test_case(Operation::from(PragmaGlobalPhase::new(CalculatorFloat::from(0.05))); "PragmaGlobalPhase")]
 #[test_case(Operation::from(PragmaSleep::new(vec![0, 1], CalculatorFloat::from(0.0000001))); "PragmaSleep")]
+#[test_case(Operation::from(PragmaQubitSleep::new(HashMap::from([(0, CalculatorFloat::from(0.0000001)), (1, CalculatorFloat::from(0.0000002))]))); "PragmaQubitSleep")]
 #[test_case(Operation::from(PragmaActiveReset::new(0)); "PragmaActiveReset")]
 #[test_case(Operation::from(PragmaOverrotation::new("RotateX".to_string(), vec![0], 0.03, 0.001)); "PragmaOverrotation")]
 #[test_case(Operation::from(PragmaStartDecompositionBlock::new(vec![0, 1], reordering())); "PragmaStartDecompositionBlock")]
@@ -125,6 +147,7 @@ use test_case::test_case;
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from(0.005), create_circuit())); "PragmaLoop")]
 #[test_case(Operation::from(PragmaControlledCircuit::new(0, create_circuit())); "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![0, 1], create_circuit())); "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(Squeezing::new(0, CalculatorFloat::from(0.005), 1.0.into())); "Squeezing")]
 #[test_case(Operation::from(PhaseShift::new(0, CalculatorFloat::from(0.005))); "PhaseShift")]
 #[test_case(Operation::from(PhaseDisplacement::new(0, CalculatorFloat::from(0.005), 1.0.into())); "PhaseDisplacement")]
@@ -187,7 +210,6 @@ fn test_conversion_feature(input: Operation) {
     })
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[test_case(Operation::from(GateDefinition::new(create_circuit(), "name".into(), vec![1, 2], vec!["test".into()])); "GateDefinition")]
 #[test_case(Operation::from(CallDefinedGate::new("name".into(), vec![1, 2], vec![CalculatorFloat::from(0.6)])); "CallDefinedGate")]
 fn test_conversion_operation_definition(input: Operation) {
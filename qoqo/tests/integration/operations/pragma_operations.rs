@@ -14,6 +14,7 @@ use ndarray::{arr2, array, Array1, Array2};
 use num_complex::Complex64;
 use numpy::PyArray2;
 use numpy::PyReadonlyArray1;
+use numpy::{PyReadonlyArray2, ToPyArray};
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 use pyo3::Python;
@@ -163,6 +164,35 @@ fn test_pyo3_inputs_loop() {
     })
 }
 
+/// Test repetitions and circuit of PragmaLoop with a float number of repetitions
+#[test]
+fn test_pyo3_inputs_loop_float_repetitions() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(0));
+    let input_pragma =
+        Operation::from(PragmaLoop::new(CalculatorFloat::from(3.0), circuit.clone()));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_pragma).unwrap();
+
+        let repetitions: CalculatorFloatWrapper = operation
+            .call_method0(py, "repetitions")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        assert_eq!(repetitions.internal, CalculatorFloat::Float(3.0));
+
+        let circuit_op: CircuitWrapper = operation
+            .call_method0(py, "circuit")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        assert_eq!(circuit_op.internal, circuit);
+    })
+}
+
 /// Test inputs of PragmaSetStateVector
 #[test]
 fn test_pyo3_inputs_setstatevector() {
@@ -201,6 +231,47 @@ fn test_pyo3_inputs_setdensitymatrix() {
     })
 }
 
+/// Test PragmaSetDensityMatrix construction from a state vector
+#[test]
+fn test_pyo3_setdensitymatrix_from_statevector() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let pragma_type = py.get_type_bound::<PragmaSetDensityMatrixWrapper>();
+        let density_matrix = |input: Vec<Complex64>| -> Array2<Complex64> {
+            let operation = pragma_type
+                .call_method1("from_statevector", (input,))
+                .unwrap();
+            let matrix = operation.call_method0("density_matrix").unwrap();
+            matrix
+                .downcast::<PyArray2<Complex64>>()
+                .unwrap()
+                .as_gil_ref()
+                .readonly()
+                .as_array()
+                .to_owned()
+        };
+
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        let mut expected: Array2<Complex64> = Array2::zeros((4, 4));
+        expected[[0, 0]] = one;
+        assert_eq!(density_matrix(vec![one, zero, zero, zero]), expected);
+
+        // Non-normalised input is normalised before building |ψ><ψ|
+        let result = density_matrix(vec![Complex64::new(2.0, 0.0), Complex64::new(0.0, 2.0)]);
+        let expected: Array2<Complex64> = array![
+            [Complex64::new(0.5, 0.0), Complex64::new(0.0, -0.5)],
+            [Complex64::new(0.0, 0.5), Complex64::new(0.5, 0.0)],
+        ];
+        for (value, expected_value) in result.iter().zip(expected.iter()) {
+            assert!((value - expected_value).norm() < 1e-10);
+        }
+
+        let error = pragma_type.call_method1("from_statevector", (vec![zero, zero],));
+        assert!(error.is_err());
+    })
+}
+
 /// Test inputs of PragmaRepeatGate
 #[test]
 fn test_pyo3_inputs_repeatgate() {
@@ -263,6 +334,47 @@ fn test_pyo3_inputs_overrotation() {
     })
 }
 
+/// Test that PragmaOverrotation can be reconstructed from its attributes in Python
+#[test]
+fn test_pyo3_overrotation_attribute_roundtrip() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let pragma_type = py.get_type_bound::<PragmaOverrotationWrapper>();
+        let operation = pragma_type
+            .call1(("RotateY".to_string(), vec![1_usize, 2], 0.5, 0.02))
+            .unwrap();
+
+        let gate_hqslang: String = operation
+            .call_method0("gate_hqslang")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let qubits: Vec<usize> = operation.call_method0("qubits").unwrap().extract().unwrap();
+        let amplitude: f64 = operation
+            .call_method0("amplitude")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let variance: f64 = operation
+            .call_method0("variance")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(gate_hqslang, "RotateY");
+        assert_eq!(qubits, vec![1, 2]);
+        assert_eq!(amplitude, 0.5);
+        assert_eq!(variance, 0.02);
+
+        let reconstructed = pragma_type
+            .call1((gate_hqslang, qubits, amplitude, variance))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&reconstructed.call_method1("__eq__", (&operation,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+    })
+}
+
 /// Test inputs of PragmaBoostNoise
 #[test]
 fn test_pyo3_inputs_boostnoise() {
@@ -284,6 +396,45 @@ fn test_pyo3_inputs_boostnoise() {
     })
 }
 
+/// Test scale function of PragmaBoostNoise
+#[test]
+fn test_pyo3_scale_boostnoise() {
+    let input_pragma = Operation::from(PragmaBoostNoise::new(CalculatorFloat::from(0.003)));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_pragma).unwrap();
+
+        let scaled = operation.call_method1(py, "scale", (2.0,)).unwrap();
+        let boost_op: f64 = scaled
+            .call_method0(py, "noise_coefficient")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        assert_eq!(
+            CalculatorFloat::from(boost_op),
+            CalculatorFloat::from(0.006)
+        );
+
+        let scaled = operation.call_method1(py, "scale", ("factor",)).unwrap();
+        let comparison_op = convert_operation_to_pyobject(Operation::from(PragmaBoostNoise::new(
+            CalculatorFloat::from(0.003) * CalculatorFloat::from("factor"),
+        )))
+        .unwrap();
+        let comparison = bool::extract_bound(
+            &scaled
+                .call_method1(py, "__eq__", (comparison_op,))
+                .unwrap()
+                .bind(py),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        let result = operation.call_method1(py, "scale", (vec![1.0],));
+        assert!(result.is_err());
+    })
+}
+
 /// Test inputs of PragmaStopParallelBlock
 #[test]
 fn test_pyo3_inputs_stop() {
@@ -317,6 +468,43 @@ fn test_pyo3_inputs_stop() {
     })
 }
 
+/// Test with_symbolic_execution_time function of PragmaStopParallelBlock
+#[test]
+fn test_pyo3_stop_symbolic_execution_time() {
+    let input_pragma = Operation::from(PragmaStopParallelBlock::new(
+        vec![0, 1],
+        CalculatorFloat::from(0.0000001),
+    ));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_pragma).unwrap();
+        let symbolic = operation
+            .call_method1(py, "with_symbolic_execution_time", ("t_cx",))
+            .unwrap();
+        let symbolic_wrapper = symbolic
+            .bind(py)
+            .extract::<PragmaStopParallelBlockWrapper>()
+            .unwrap();
+        assert_eq!(
+            symbolic_wrapper.internal,
+            PragmaStopParallelBlock::new(vec![0, 1], CalculatorFloat::from("t_cx"))
+        );
+
+        let mut substitution_dict: HashMap<String, f64> = HashMap::new();
+        substitution_dict.insert("t_cx".to_owned(), 50e-9);
+        let substituted = symbolic
+            .call_method1(py, "substitute_parameters", (substitution_dict,))
+            .unwrap();
+        let execution_time: f64 = substituted
+            .call_method0(py, "execution_time")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        assert_eq!(execution_time, 50e-9);
+    })
+}
+
 /// Test inputs of PragmaGlobalPhase
 #[test]
 fn test_pyo3_inputs_globalphase() {
@@ -368,6 +556,80 @@ fn test_pyo3_inputs_sleep() {
     })
 }
 
+/// Test inputs, remap_qubits and substitute_parameters of PragmaQubitSleep
+#[test]
+fn test_pyo3_qubit_sleep() {
+    let mut sleep_times: HashMap<usize, CalculatorFloat> = HashMap::new();
+    sleep_times.insert(0, CalculatorFloat::from("t_0"));
+    sleep_times.insert(1, CalculatorFloat::from(0.0000001));
+    sleep_times.insert(2, CalculatorFloat::from("2 * t_0"));
+    let input_pragma = Operation::from(PragmaQubitSleep::new(sleep_times));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_pragma.clone()).unwrap();
+        let operation = operation.bind(py);
+
+        let times_op: HashMap<usize, CalculatorFloatWrapper> = operation
+            .call_method0("sleep_times")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(times_op.len(), 3);
+        assert_eq!(times_op[&1].internal, CalculatorFloat::from(0.0000001));
+        assert_eq!(times_op[&2].internal, CalculatorFloat::from("2 * t_0"));
+
+        let qubits: HashSet<usize> = operation
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(qubits, HashSet::from([0, 1, 2]));
+
+        let mut mapping: HashMap<usize, usize> = HashMap::new();
+        mapping.insert(0, 2);
+        mapping.insert(2, 0);
+        let remapped = operation.call_method1("remap_qubits", (mapping,)).unwrap();
+        let remapped_times: HashMap<usize, CalculatorFloatWrapper> = remapped
+            .call_method0("sleep_times")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            remapped_times[&0].internal,
+            CalculatorFloat::from("2 * t_0")
+        );
+        assert_eq!(remapped_times[&2].internal, CalculatorFloat::from("t_0"));
+
+        let mut substitution_dict: HashMap<String, f64> = HashMap::new();
+        substitution_dict.insert("t_0".to_owned(), 0.5);
+        let substituted = operation
+            .call_method1("substitute_parameters", (substitution_dict,))
+            .unwrap();
+        assert!(
+            !bool::extract_bound(&substituted.call_method0("is_parametrized").unwrap()).unwrap()
+        );
+        let substituted_times: HashMap<usize, CalculatorFloatWrapper> = substituted
+            .call_method0("sleep_times")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(substituted_times[&0].internal, CalculatorFloat::from(0.5));
+        assert_eq!(
+            substituted_times[&1].internal,
+            CalculatorFloat::from(0.0000001)
+        );
+        assert_eq!(substituted_times[&2].internal, CalculatorFloat::from(1.0));
+
+        let new_op = operation
+            .get_type()
+            .call1((HashMap::from([(0_usize, 0.1_f64)]),))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&operation.call_method1("__eq__", (new_op,)).unwrap()).unwrap();
+        assert!(!comparison);
+    })
+}
+
 /// Test inputs of PragmaActiveReset
 #[test]
 fn test_pyo3_inputs_activereset() {
@@ -530,6 +792,31 @@ fn test_pyo3_inputs_randomnoise() {
     })
 }
 
+/// Test validate_rates_matrix function of PragmaGeneralNoise
+#[test_case(Array2::zeros((3, 3)), true; "zero")]
+#[test_case(Array2::eye(3), true; "identity")]
+#[test_case(arr2(&[[0.5, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.1]]), true; "positive diagonal")]
+#[test_case(arr2(&[[1.0, 0.5, 0.0], [0.5, 1.0, 0.0], [0.0, 0.0, 0.0]]), true; "positive off-diagonal")]
+#[test_case(arr2(&[[1.0, 2.0, 0.0], [2.0, 1.0, 0.0], [0.0, 0.0, 1.0]]), false; "negative eigenvalue")]
+#[test_case(arr2(&[[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]]), false; "negative diagonal")]
+#[test_case(arr2(&[[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]), false; "not symmetric")]
+#[test_case(Array2::eye(2), false; "wrong shape")]
+fn test_pyo3_validate_rates_matrix(rates: Array2<f64>, valid: bool) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let pragma_type = py.get_type_bound::<PragmaGeneralNoiseWrapper>();
+        let result =
+            pragma_type.call_method1("validate_rates_matrix", (rates.to_pyarray_bound(py),));
+        match result {
+            Ok(_) => assert!(valid),
+            Err(err) => {
+                assert!(!valid);
+                assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+            }
+        }
+    })
+}
+
 /// Test inputs of PragmaGeneralNoise
 #[test]
 fn test_pyo3_inputs_generalnoise() {
@@ -611,8 +898,29 @@ fn test_pyo3_inputs_conditional() {
         let paulix = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
         circuit.call_method1("add", (paulix,)).unwrap();
         let comparison_circuit =
-            bool::extract_bound(&circuit_op.call_method1("__eq__", (circuit,)).unwrap()).unwrap();
+            bool::extract_bound(&circuit_op.call_method1("__eq__", (&circuit,)).unwrap()).unwrap();
+        assert!(comparison_circuit);
+
+        // The returned circuit is a copy, modifying it does not change the PragmaConditional
+        let pauliz = convert_operation_to_pyobject(Operation::from(PauliZ::new(1))).unwrap();
+        circuit_op.call_method1("add", (pauliz,)).unwrap();
+        let circuit_after = operation.call_method0(py, "circuit").unwrap();
+        let comparison_circuit = bool::extract_bound(
+            &circuit_after
+                .bind(py)
+                .call_method1("__eq__", (&circuit,))
+                .unwrap(),
+        )
+        .unwrap();
         assert!(comparison_circuit);
+        let comparison_modified = bool::extract_bound(
+            &circuit_after
+                .bind(py)
+                .call_method1("__eq__", (circuit_op,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!comparison_modified);
     })
 }
 
@@ -643,6 +951,36 @@ fn test_pyo3_inputs_controlled_circuit() {
     })
 }
 
+/// Test inputs of PragmaMultiControlledCircuit
+#[test]
+fn test_pyo3_inputs_multi_controlled_circuit() {
+    let input_pragma = Operation::from(PragmaMultiControlledCircuit::new(
+        vec![1, 2],
+        create_circuit(),
+    ));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_pragma).unwrap();
+
+        let controlling_qubits_op: Vec<usize> = operation
+            .call_method0(py, "controlling_qubits")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        assert_eq!(controlling_qubits_op, vec![1, 2]);
+
+        let to_circuit = operation.call_method0(py, "circuit").unwrap();
+        let circuit_op = to_circuit.bind(py);
+        let circuit = new_circuit(py);
+        let paulix = convert_operation_to_pyobject(Operation::from(PauliX::new(0))).unwrap();
+        circuit.call_method1("add", (paulix,)).unwrap();
+        let comparison_circuit =
+            bool::extract_bound(&circuit_op.call_method1("__eq__", (circuit,)).unwrap()).unwrap();
+        assert!(comparison_circuit);
+    })
+}
+
 /// Test inputs of PragmaAnnotatedOp
 #[test]
 fn test_pyo3_inputs_annotated_op() {
@@ -676,6 +1014,61 @@ fn test_pyo3_inputs_annotated_op() {
     })
 }
 
+/// Test replace_annotation function of PragmaAnnotatedOp
+#[test]
+fn test_pyo3_replace_annotation() {
+    let input_op = Operation::from(PauliX::new(0));
+    let input_pragma = Operation::from(PragmaAnnotatedOp::new(
+        input_op.clone(),
+        "scheduled".to_string(),
+    ));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_pragma).unwrap();
+        let replaced = operation
+            .call_method1(py, "replace_annotation", ("completed",))
+            .unwrap();
+
+        let original_op = operation.call_method0(py, "operation").unwrap();
+        let replaced_op = replaced.call_method0(py, "operation").unwrap();
+        let comparison_op = bool::extract_bound(
+            &original_op
+                .bind(py)
+                .call_method1("__eq__", (replaced_op,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison_op);
+
+        let original_annotation: String = operation
+            .call_method0(py, "annotation")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        let replaced_annotation: String = replaced
+            .call_method0(py, "annotation")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(original_annotation, "scheduled".to_string());
+        assert_eq!(replaced_annotation, "completed".to_string());
+
+        let expected = convert_operation_to_pyobject(Operation::from(PragmaAnnotatedOp::new(
+            input_op,
+            "completed".to_string(),
+        )))
+        .unwrap();
+        let comparison = bool::extract_bound(
+            &replaced
+                .bind(py)
+                .call_method1("__eq__", (expected,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+    })
+}
+
 /// Test involved_qubits function for Pragmas with None
 #[test_case(Operation::from(PragmaSetNumberOfMeasurements::new(1, String::from("ro"))); "PragmaSetNumberOfMeasurements")]
 #[test_case(Operation::from(PragmaBoostNoise::new(CalculatorFloat::from(0.003))); "PragmaBoostNoise")]
@@ -723,6 +1116,7 @@ fn test_pyo3_involved_qubits_all(input_definition: Operation) {
 #[test_case(Operation::from(PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005), operators())); "PragmaGeneralNoise")]
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new(0, create_circuit())); "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![0], create_circuit())); "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
 fn test_pyo3_involved_qubits_qubit(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
@@ -786,6 +1180,8 @@ fn test_pyo3_involved_qubits_qubit_overrotation(input_definition: Operation) {
             "PragmaConditional { condition_register: \"ro\", condition_index: 1, circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"; "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, Circuit::default())),
             "PragmaControlledCircuit { controlling_qubit: 1, circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"; "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], Circuit::default())),
+            "PragmaMultiControlledCircuit { controlling_qubits: [1], circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"; "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), Circuit::default())),
             "PragmaLoop { repetitions: Str(\"number_t\"), circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"; "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())),
@@ -837,6 +1233,7 @@ fn test_pyo3_format_repr_overrotation(input_measurement: Operation, format_repr:
 #[test_case(Operation::from(PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005),  operators())); "PragmaGeneralNoise")]
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())); "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())); "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), Circuit::default())); "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
 fn test_pyo3_copy_deepcopy(input_measurement: Operation) {
@@ -989,6 +1386,7 @@ fn test_pyo3_tags_single(input_measurement: Operation, tag_name: &str) {
 /// Test tags function for Pragmas that are also SingleQubitGates
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())), "PragmaConditional"; "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())), "PragmaControlledCircuit"; "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())), "PragmaMultiControlledCircuit"; "PragmaMultiControlledCircuit")]
 fn test_pyo3_tags_conditional(input_measurement: Operation, tag_name: &str) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -1061,6 +1459,7 @@ fn test_pyo3_tags_noise(input_measurement: Operation, tag_name: &str) {
 #[test_case(Operation::from(PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005),  operators())), "PragmaGeneralNoise"; "PragmaGeneralNoise")]
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())), "PragmaConditional"; "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())), "PragmaControlledCircuit"; "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())), "PragmaMultiControlledCircuit"; "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), Circuit::default())), "PragmaLoop"; "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())), "PragmaAnnotatedOp"; "PragmaAnnotatedOp")]
 fn test_pyo3_hqslang(input_measurement: Operation, hqslang_param: &str) {
@@ -1111,6 +1510,7 @@ fn test_pyo3_hqslang_overrotation(input_measurement: Operation, hqslang_param: &
 #[test_case(Operation::from(PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005),  operators())); "PragmaGeneralNoise")]
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())); "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())); "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from(1.0), Circuit::default())); "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
 fn test_pyo3_is_parametrized_false(input_pragma: Operation) {
@@ -1210,6 +1610,9 @@ fn test_pyo3_is_parametrized_overrotation(input_measurement: Operation) {
 #[test_case(Operation::from(PragmaControlledCircuit::new(1, create_circuit())),
             Operation::from(PragmaControlledCircuit::new(1, create_circuit()));
             "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())),
+            Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit()));
+            "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("test"), Circuit::default())),
             Operation::from(PragmaLoop::new(CalculatorFloat::from(1.0), Circuit::default()));
             "PragmaLoop")]
@@ -1312,6 +1715,7 @@ fn test_pyo3_substitute_params_error(input_operation: Operation) {
 #[test_case(Operation::from(PragmaSetNumberOfMeasurements::new(1, String::from("ro"))); "PragmaSetNumberOfMeasurements")]
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())); "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())); "PragmaMultiControlledCircuit")]
 #[test_case(
     Operation::from(PragmaAnnotatedOp::new(Operation::from(RotateX::new(
         0,
@@ -1385,6 +1789,9 @@ fn test_pyo3_substituteparameters_error(input_operation: Operation) {
 #[test_case(Operation::from(PragmaControlledCircuit::new(20, create_circuit())),
             Operation::from(PragmaControlledCircuit::new(20, circuit_remapped()));
             "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![20], create_circuit())),
+            Operation::from(PragmaMultiControlledCircuit::new(vec![20], circuit_remapped()));
+            "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), create_circuit())),
             Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), circuit_remapped()));
             "PragmaLoop")]
@@ -1589,6 +1996,52 @@ fn test_pyo3_noise_superoperator_randomnoise() {
     })
 }
 
+/// Test superoperator of PragmaDepolarising and that powercf(0.5) is the square root of the channel
+#[test]
+fn test_pyo3_noise_superoperator_depolarising_powercf() {
+    let noise_pragma = Operation::from(PragmaDepolarising::new(
+        0,
+        CalculatorFloat::from(1e-3),
+        CalculatorFloat::from(0.1),
+    ));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(noise_pragma).unwrap();
+        let superoperator = |op: &PyObject| -> Array2<f64> {
+            op.call_method0(py, "superoperator")
+                .unwrap()
+                .downcast_bound::<PyArray2<f64>>(py)
+                .unwrap()
+                .as_gil_ref()
+                .readonly()
+                .as_array()
+                .to_owned()
+        };
+
+        let superop_prob: f64 = 0.75 * (1.0 - (-1e-3 * 0.1_f64).exp());
+        let superop_proba1: f64 = 1.0 - (2.0 / 3.0) * superop_prob;
+        let superop_proba2: f64 = 1.0 - (4.0 / 3.0) * superop_prob;
+        let superop_proba3: f64 = (2.0 / 3.0) * superop_prob;
+        let superop_param: Array2<f64> = arr2(&[
+            [superop_proba1, 0.0, 0.0, superop_proba3],
+            [0.0, superop_proba2, 0.0, 0.0],
+            [0.0, 0.0, superop_proba2, 0.0],
+            [superop_proba3, 0.0, 0.0, superop_proba1],
+        ]);
+        let superop_op = superoperator(&operation);
+        assert_eq!(superop_op, superop_param);
+
+        // Applying the square root of the channel twice gives the original channel
+        let power = convert_cf_to_pyobject(py, CalculatorFloat::from(0.5));
+        let sqrt_operation = operation.call_method1(py, "powercf", (power,)).unwrap();
+        let sqrt_superop = superoperator(&sqrt_operation);
+        let squared = sqrt_superop.dot(&sqrt_superop);
+        for (value, expected) in squared.iter().zip(superop_op.iter()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    })
+}
+
 /// Test probability function of Noise Pragmas
 #[test_case(Operation::from(PragmaDamping::new(0, CalculatorFloat::from(0.005), CalculatorFloat::from(0.02))), 0.00009999500016666385; "PragmaDamping")]
 #[test_case(Operation::from(PragmaDepolarising::new(0, CalculatorFloat::from(0.005), CalculatorFloat::from(0.02))), 0.00007499625012499789; "PragmaDepolarising")]
@@ -1699,6 +2152,9 @@ fn test_pyo3_noise_powercf(first_op: Operation, second_op: Operation) {
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())),
             Operation::from(PragmaControlledCircuit::new( 1, circuit_remapped()));
             "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())),
+            Operation::from(PragmaMultiControlledCircuit::new(vec![1], circuit_remapped()));
+            "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), create_circuit())),
             Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), circuit_remapped()));
             "PragmaLoop")]
@@ -1813,6 +2269,52 @@ fn test_pyo3_new_set_number_of_measurements() {
     })
 }
 
+/// Test PragmaSimulationRepetitions new() function
+#[test]
+fn test_pyo3_new_simulation_repetitions() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaSimulationRepetitionsWrapper>();
+        let binding = operation.call1((100,)).unwrap();
+        let new_op = binding
+            .downcast::<PragmaSimulationRepetitionsWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaSimulationRepetitions::new(100));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let repetitions: usize = new_op
+            .call_method0("repetitions")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(repetitions, 100);
+
+        let pragma_wrapper = new_op
+            .extract::<PragmaSimulationRepetitionsWrapper>()
+            .unwrap();
+        let binding = operation.call1((10,)).unwrap();
+        let new_op_diff = binding
+            .downcast::<PragmaSimulationRepetitionsWrapper>()
+            .unwrap();
+        let pragma_wrapper_diff = new_op_diff
+            .extract::<PragmaSimulationRepetitionsWrapper>()
+            .unwrap();
+        let helper_ne: bool = pragma_wrapper_diff != pragma_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = pragma_wrapper == pragma_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaSimulationRepetitionsWrapper { internal: PragmaSimulationRepetitions { repetitions: 100 } }"
+        );
+    })
+}
+
 /// Test PragmaSetStateVector new() function
 #[test]
 fn test_pyo3_new_set_statevector() {
@@ -1915,6 +2417,133 @@ fn test_pyo3_new_set_densitymatrix() {
     })
 }
 
+/// Test PragmaSetStabilizerState new() function
+#[test]
+fn test_pyo3_new_set_stabilizer_state() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaSetStabilizerStateWrapper>();
+        let binding = operation
+            .call1((vec!["XX".to_string(), "ZZ".to_string()],))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaSetStabilizerStateWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaSetStabilizerState::new(vec![
+            "XX".to_string(),
+            "ZZ".to_string(),
+        ]));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let generators: Vec<String> = new_op
+            .call_method0("stabilizer_generators")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(generators, vec!["XX".to_string(), "ZZ".to_string()]);
+
+        let pragma_wrapper = new_op.extract::<PragmaSetStabilizerStateWrapper>().unwrap();
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaSetStabilizerStateWrapper { internal: PragmaSetStabilizerState { stabilizer_generators: [\"XX\", \"ZZ\"] } }"
+        );
+    })
+}
+
+/// Test PragmaSetStabilizerState new() function with invalid stabilizer generators
+#[test_case(vec!["XX", "ZX"]; "non-commuting")]
+#[test_case(vec!["XX"]; "too few generators")]
+#[test_case(vec!["XX", "ZZ", "YY"]; "too many generators")]
+#[test_case(vec!["XA", "ZZ"]; "invalid Pauli")]
+#[test_case(vec![]; "no generators")]
+#[test_case(vec!["ZZ", "ZZ"]; "duplicate generators")]
+#[test_case(vec!["II", "ZZ"]; "identity generator")]
+#[test_case(vec!["XXI", "ZZI", "YYI"]; "dependent generators")]
+fn test_pyo3_new_set_stabilizer_state_error(generators: Vec<&str>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaSetStabilizerStateWrapper>();
+        let generators: Vec<String> = generators.iter().map(|s| s.to_string()).collect();
+        let result = operation.call1((generators,));
+        assert!(result.is_err());
+    })
+}
+
+/// Test PragmaCustomTwoQubitGate new() function
+#[test]
+fn test_pyo3_new_custom_two_qubit_gate() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaCustomTwoQubitGateWrapper>();
+        let identity: Array2<Complex64> = Array2::eye(4);
+        let binding = operation
+            .call1((0, 1, identity.to_pyarray_bound(py)))
+            .unwrap();
+        let new_op = binding
+            .downcast::<PragmaCustomTwoQubitGateWrapper>()
+            .unwrap();
+
+        let input_definition =
+            Operation::from(PragmaCustomTwoQubitGate::new(0, 1, identity.clone()));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let matrix_op = new_op.call_method0("to_matrix").unwrap();
+        let matrix: PyReadonlyArray2<Complex64> = matrix_op.extract().unwrap();
+        assert_eq!(matrix.as_array().to_owned(), identity);
+
+        let non_unitary: Array2<f64> = array![
+            [1.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ];
+        let result = operation.call1((0, 1, non_unitary.to_pyarray_bound(py)));
+        assert!(result.is_err());
+
+        let wrong_shape: Array2<Complex64> = Array2::eye(2);
+        let result = operation.call1((0, 1, wrong_shape.to_pyarray_bound(py)));
+        assert!(result.is_err());
+    })
+}
+
+/// Test PragmaCustomTwoQubitGate remap_qubits() function
+#[test]
+fn test_pyo3_remap_qubits_custom_two_qubit_gate() {
+    let identity: Array2<Complex64> = Array2::eye(4);
+    let input_definition = Operation::from(PragmaCustomTwoQubitGate::new(0, 1, identity));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = convert_operation_to_pyobject(input_definition).unwrap();
+        let mut mapping: HashMap<usize, usize> = HashMap::new();
+        mapping.insert(0, 1);
+        mapping.insert(1, 0);
+        let remapped = operation
+            .call_method1(py, "remap_qubits", (mapping,))
+            .unwrap();
+        let qubit_0: usize = remapped
+            .call_method0(py, "qubit_0")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        let qubit_1: usize = remapped
+            .call_method0(py, "qubit_1")
+            .unwrap()
+            .bind(py)
+            .extract()
+            .unwrap();
+        assert_eq!(qubit_0, 1);
+        assert_eq!(qubit_1, 0);
+    })
+}
+
 /// Test PragmaRepeatGate new() function
 #[test]
 fn test_pyo3_new_repeated_gate() {
@@ -2571,6 +3200,55 @@ fn test_pyo3_new_controlled_circuit() {
     })
 }
 
+/// Test PragmaMultiControlledCircuit new() function
+#[test]
+fn test_pyo3_new_multi_controlled_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaMultiControlledCircuitWrapper>();
+        let binding = operation.call1((vec![0, 1], new_circuit(py))).unwrap();
+        let new_op = binding
+            .downcast::<PragmaMultiControlledCircuitWrapper>()
+            .unwrap();
+
+        let input_definition = Operation::from(PragmaMultiControlledCircuit::new(
+            vec![0, 1],
+            Circuit::new(),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition).unwrap();
+        let comparison_copy =
+            bool::extract_bound(&new_op.call_method1("__eq__", (copy_param,)).unwrap()).unwrap();
+        assert!(comparison_copy);
+
+        let controlling_qubits: Vec<usize> = new_op
+            .call_method0("controlling_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(controlling_qubits, vec![0, 1]);
+
+        let pragma_wrapper = new_op
+            .extract::<PragmaMultiControlledCircuitWrapper>()
+            .unwrap();
+        let binding = operation.call1((vec![2], new_circuit(py))).unwrap();
+        let new_op_diff = binding
+            .downcast::<PragmaMultiControlledCircuitWrapper>()
+            .unwrap();
+        let pragma_wrapper_diff = new_op_diff
+            .extract::<PragmaMultiControlledCircuitWrapper>()
+            .unwrap();
+        let helper_ne: bool = pragma_wrapper_diff != pragma_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = pragma_wrapper == pragma_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", pragma_wrapper),
+            "PragmaMultiControlledCircuitWrapper { internal: PragmaMultiControlledCircuit { controlling_qubits: [0, 1], circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } } }"
+        );
+    })
+}
+
 /// Test PragmaLoop new() function
 #[test]
 fn test_pyo3_new_loop() {
@@ -2664,6 +3342,7 @@ fn test_pyo3_new_annotated_op() {
 #[test_case(Operation::from(PragmaRandomNoise::new(0, CalculatorFloat::from(0.005), CalculatorFloat::from(0.02), CalculatorFloat::from(0.01))); "PragmaRandomNoise")]
 #[test_case(Operation::from(PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005), operators())); "PragmaGeneralNoise")]
 #[test_case(Operation::from(PragmaControlledCircuit::new(0, roqoqo::Circuit::new())); "PragmaControlledCircuit")]
+#[test_case(Operation::from(PragmaMultiControlledCircuit::new(vec![0], roqoqo::Circuit::new())); "PragmaMultiControlledCircuit")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
 fn test_pyo3_remapqubits_error(input_operation: Operation) {
     // preparation
@@ -2697,11 +3376,17 @@ fn test_pyo3_remapqubits_error(input_operation: Operation) {
 #[test_case(PragmaOperation::from(PragmaGeneralNoise::new(0, CalculatorFloat::from(0.005),  operators())); "PragmaGeneralNoise")]
 #[test_case(PragmaOperation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(PragmaOperation::from(PragmaControlledCircuit::new( 1, create_circuit())); "PragmaControlledCircuit")]
+#[test_case(PragmaOperation::from(PragmaMultiControlledCircuit::new(vec![1], create_circuit())); "PragmaMultiControlledCircuit")]
 #[test_case(PragmaOperation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), Circuit::default())); "PragmaLoop")]
 #[test_case(PragmaOperation::from(PragmaSetNumberOfMeasurements::new(1, String::from("ro"))); "PragmaSetNumberOfMeasurements")]
+#[test_case(PragmaOperation::from(PragmaSimulationRepetitions::new(100)); "PragmaSimulationRepetitions")]
 #[test_case(PragmaOperation::from(PragmaOverrotation::new("RotateX".to_string(), vec![0], 0.03, 0.001)); "PragmaOverrotation")]
 fn test_pyo3_json_schema(operation: PragmaOperation) {
     let rust_schema = match operation {
+        PragmaOperation::PragmaSimulationRepetitions(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(PragmaSimulationRepetitions))
+                .unwrap()
+        }
         PragmaOperation::PragmaSetNumberOfMeasurements(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(PragmaSetNumberOfMeasurements))
                 .unwrap()
@@ -2765,6 +3450,10 @@ fn test_pyo3_json_schema(operation: PragmaOperation) {
         PragmaOperation::PragmaControlledCircuit(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(PragmaControlledCircuit)).unwrap()
         }
+        PragmaOperation::PragmaMultiControlledCircuit(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(PragmaMultiControlledCircuit))
+                .unwrap()
+        }
         _ => unreachable!(),
     };
     pyo3::prepare_freethreaded_python();
@@ -2772,6 +3461,8 @@ fn test_pyo3_json_schema(operation: PragmaOperation) {
         let minimum_version: String = match operation {
             PragmaOperation::PragmaLoop(_) => "1.1.0".to_string(),
             PragmaOperation::PragmaControlledCircuit(_) => "1.5.0".to_string(),
+            PragmaOperation::PragmaMultiControlledCircuit(_) => "1.14.0".to_string(),
+            PragmaOperation::PragmaSimulationRepetitions(_) => "1.14.0".to_string(),
             _ => "1.0.0".to_string(),
         };
         let converted_op = Operation::from(operation);
@@ -19,8 +19,9 @@ use qoqo::operations::convert_operation_to_pyobject;
 use qoqo::operations::{
     GPi2Wrapper, GPiWrapper, HadamardWrapper, IdentityWrapper, InvSqrtPauliXWrapper, PauliXWrapper,
     PauliYWrapper, PauliZWrapper, PhaseShiftState0Wrapper, PhaseShiftState1Wrapper,
-    RotateAroundSphericalAxisWrapper, RotateXWrapper, RotateXYWrapper, RotateYWrapper,
-    RotateZWrapper, SGateWrapper, SingleQubitGateWrapper, SqrtPauliXWrapper, TGateWrapper,
+    RotateAroundSphericalAxisWrapper, RotateAxisWrapper, RotateXWrapper, RotateXYWrapper,
+    RotateYWrapper, RotateZWrapper, SGateWrapper, SingleQubitGateWrapper, SqrtPauliXWrapper,
+    TGateWrapper,
 };
 use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorFloat;
@@ -674,6 +675,88 @@ fn test_new_rotate(input_operation: Operation, arguments: (u32, f64, f64, f64),
     })
 }
 
+#[test_case(Operation::from(
+    RotateAxis::new(
+        1,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::from(1.0),
+).unwrap()
+    ), (1, 0.0, 0.0, 0.0, 1.0,), "__eq__"; "rotate_axis_eq")]
+#[test_case(Operation::from(
+    RotateAxis::new(
+        1,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+        CalculatorFloat::from(1.0),
+).unwrap()
+    ), (0, 0.0, 0.0, 0.0, 1.0,), "__ne__"; "rotate_axis_ne")]
+fn test_new_rotate_axis(
+    input_operation: Operation,
+    arguments: (u32, f64, f64, f64, f64),
+    method: &str,
+) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<RotateAxisWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<RotateAxisWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, vec!["fails"], 0.0, 0.0, 1.0));
+        assert!(result.is_err());
+        let result = operation_type.call1((0, 0.0, vec!["fails"], 0.0, 1.0));
+        assert!(result.is_err());
+        let result = operation_type.call1((0, 0.0, 0.0, 0.0, vec!["fails"]));
+        assert!(result.is_err());
+        let error = operation_type.call1((0, 0.0, 0.0, 0.0, 0.0)).unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+
+        // The rotation axis is normalized on construction
+        let binding = operation_type.call1((0, 0.0, 3.0, 0.0, 4.0)).unwrap();
+        let normalized = binding.downcast::<RotateAxisWrapper>().unwrap();
+        let axis_x: CalculatorFloatWrapper = normalized
+            .call_method0("axis_x")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let axis_z: CalculatorFloatWrapper = normalized
+            .call_method0("axis_z")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(axis_x.internal, CalculatorFloat::from(0.6));
+        assert_eq!(axis_z.internal, CalculatorFloat::from(0.8));
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<RotateAxisWrapper>().unwrap();
+        let binding = operation_type.call1((2, 0.0, 0.0, 0.0, 1.0)).unwrap();
+        let new_op_diff = binding.downcast::<RotateAxisWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<RotateAxisWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "RotateAxisWrapper { internal: RotateAxis { qubit: 2, theta: Float(0.0), axis_x: Float(0.0), axis_y: Float(0.0), axis_z: Float(1.0) } }"
+        );
+    })
+}
+
 #[test_case(Operation::from(
     RotateXY::new(
         1,
@@ -2586,6 +2669,16 @@ fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
 #[test_case(SingleQubitGateOperation::from(GPi::new(0, CalculatorFloat::from(0.0))); "GPi")]
 #[test_case(SingleQubitGateOperation::from(GPi2::new(0, CalculatorFloat::from(0.0))); "GPi2")]
 #[test_case(SingleQubitGateOperation::from(Identity::new(1)); "Identity")]
+#[test_case(SingleQubitGateOperation::from(
+    RotateAxis::new(
+        0,
+        CalculatorFloat::from(PI),
+        CalculatorFloat::from(0),
+        CalculatorFloat::from(0),
+        CalculatorFloat::from(1),
+).unwrap()
+    ); "RotateAxis")
+]
 fn test_pyo3_json_schema(operation: SingleQubitGateOperation) {
     let rust_schema = match operation {
         SingleQubitGateOperation::SingleQubitGate(_) => {
@@ -2645,6 +2738,9 @@ fn test_pyo3_json_schema(operation: SingleQubitGateOperation) {
         SingleQubitGateOperation::Identity(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(Identity)).unwrap()
         }
+        SingleQubitGateOperation::RotateAxis(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(RotateAxis)).unwrap()
+        }
         _ => unreachable!(),
     };
     pyo3::prepare_freethreaded_python();
@@ -2653,6 +2749,7 @@ fn test_pyo3_json_schema(operation: SingleQubitGateOperation) {
             SingleQubitGateOperation::GPi(_) => "1.4.0".to_string(),
             SingleQubitGateOperation::GPi2(_) => "1.4.0".to_string(),
             SingleQubitGateOperation::Identity(_) => "1.7.0".to_string(),
+            SingleQubitGateOperation::RotateAxis(_) => "1.14.0".to_string(),
             _ => "1.0.0".to_string(),
         };
         let converted_op = Operation::from(operation);
@@ -18,13 +18,14 @@ use pyo3::prelude::*;
 use pyo3::Python;
 use qoqo::operations::convert_operation_to_pyobject;
 use qoqo::operations::{
-    BogoliubovWrapper, CNOTWrapper, ComplexPMInteractionWrapper, ControlledPauliYWrapper,
-    ControlledPauliZWrapper, ControlledPhaseShiftWrapper, ControlledRotateXWrapper,
-    ControlledRotateXYWrapper, EchoCrossResonanceWrapper, FSwapWrapper, FsimWrapper,
-    GivensRotationLittleEndianWrapper, GivensRotationWrapper, ISwapWrapper, InvSqrtISwapWrapper,
-    MolmerSorensenXXWrapper, PMInteractionWrapper, PhaseShiftedControlledPhaseWrapper,
-    PhaseShiftedControlledZWrapper, QsimWrapper, SWAPWrapper, SpinInteractionWrapper,
-    SqrtISwapWrapper, VariableMSXXWrapper, XYWrapper,
+    BogoliubovWrapper, CNOTWrapper, ComplexPMInteractionWrapper, ControlledHadamardWrapper,
+    ControlledPauliYWrapper, ControlledPauliZWrapper, ControlledPhaseShiftWrapper,
+    ControlledRotateXWrapper, ControlledRotateXYWrapper, EchoCrossResonanceWrapper, FSwapWrapper,
+    FsimWrapper, GivensRotationLittleEndianWrapper, GivensRotationWrapper, ISwapWrapper,
+    InvSqrtCNOTWrapper, InvSqrtISwapWrapper, MolmerSorensenXXWrapper, PMInteractionWrapper,
+    PhaseShiftedControlledPhaseWrapper, PhaseShiftedControlledZWrapper, QsimWrapper, RXXWrapper,
+    RYYWrapper, RZZWrapper, SWAPWrapper, SpinInteractionWrapper, SqrtCNOTWrapper, SqrtISwapWrapper,
+    VariableMSXXWrapper, XYWrapper, ZXCouplingWrapper,
 };
 
 use qoqo_calculator::CalculatorFloat;
@@ -50,6 +51,10 @@ use test_case::test_case;
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -63,6 +68,9 @@ use test_case::test_case;
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_is_not_parametrized(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -176,6 +184,42 @@ fn test_pyo3_is_not_parametrized(input_operation: Operation) {
         "VariableMSXX",
         ],
     Operation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "RXX",
+        ],
+    Operation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "RYY",
+        ],
+    Operation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "RZZ",
+        ],
+    Operation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "ZXCoupling",
+        ],
+    Operation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     vec![
         "Operation",
@@ -284,6 +328,30 @@ fn test_pyo3_is_not_parametrized(input_operation: Operation) {
         "EchoCrossResonance",
         ],
     Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "ControlledHadamard",
+        ],
+    Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "SqrtCNOT",
+        ],
+    Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "InvSqrtCNOT",
+        ],
+    Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_tags(tags: Vec<&str>, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -317,6 +385,10 @@ fn test_pyo3_tags(tags: Vec<&str>, input_operation: Operation) {
 #[test_case("ControlledPauliZ", Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case("MolmerSorensenXX", Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case("VariableMSXX", Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case("RXX", Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case("RYY", Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case("RZZ", Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case("ZXCoupling", Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case("GivensRotation", Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case("GivensRotationLittleEndian", Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case("Qsim", Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -330,6 +402,9 @@ fn test_pyo3_tags(tags: Vec<&str>, input_operation: Operation) {
 #[test_case("ControlledRotateX", Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case("ControlledRotateXY", Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case("EchoCrossResonance", Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case("ControlledHadamard", Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case("SqrtCNOT", Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case("InvSqrtCNOT", Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -357,6 +432,10 @@ fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -370,6 +449,9 @@ fn test_pyo3_hqslang(name: &'static str, input_operation: Operation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_remapqubits(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -436,6 +518,10 @@ fn test_pyo3_remapqubits(input_operation: Operation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -449,6 +535,9 @@ fn test_pyo3_remapqubits(input_operation: Operation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_remapqubits_error(input_operation: Operation) {
     // preparation
     pyo3::prepare_freethreaded_python();
@@ -466,6 +555,10 @@ fn test_pyo3_remapqubits_error(input_operation: Operation) {
 #[test_case(Operation::from(XY::new(0, 1, CalculatorFloat::from("test"))); "XY")]
 #[test_case(Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::from("test"))); "ControlledPhaseShift")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("test"))); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("test"))); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("test"))); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("test"))); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("test"))); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -500,6 +593,10 @@ fn test_pyo3_unitarymatrix_error(input_operation: Operation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -513,6 +610,9 @@ fn test_pyo3_unitarymatrix_error(input_operation: Operation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_unitarymatrix(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -573,6 +673,18 @@ fn test_pyo3_unitarymatrix(input_operation: Operation) {
 #[test_case(
     "VariableMSXX { control: 1, target: 0, theta: Float(3.141592653589793) }",
     Operation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    "RXX { control: 1, target: 0, theta: Float(3.141592653589793) }",
+    Operation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    "RYY { control: 1, target: 0, theta: Float(3.141592653589793) }",
+    Operation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    "RZZ { control: 1, target: 0, theta: Float(3.141592653589793) }",
+    Operation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    "ZXCoupling { control: 1, target: 0, theta: Float(3.141592653589793) }",
+    Operation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     "GivensRotation { control: 1, target: 0, theta: Float(0.0), phi: Float(0.0) }",
     Operation::from(GivensRotation::new(1, 0, CalculatorFloat::ZERO, CalculatorFloat::ZERO)); "GivensRotation")]
@@ -612,6 +724,15 @@ fn test_pyo3_unitarymatrix(input_operation: Operation) {
 #[test_case(
     "EchoCrossResonance { control: 0, target: 1 }",
     Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(
+    "ControlledHadamard { control: 0, target: 1 }",
+    Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(
+    "SqrtCNOT { control: 0, target: 1 }",
+    Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(
+    "InvSqrtCNOT { control: 0, target: 1 }",
+    Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -638,6 +759,10 @@ fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -651,6 +776,9 @@ fn test_pyo3_format_repr(format_repr: &str, input_operation: Operation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_copy_deepcopy(input_operation: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -715,6 +843,18 @@ fn test_pyo3_copy_deepcopy(input_operation: Operation) {
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("test"))),
             Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from(1.0)));
             "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("test"))),
+            Operation::from(RXX::new(0, 1, CalculatorFloat::from(1.0)));
+            "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("test"))),
+            Operation::from(RYY::new(0, 1, CalculatorFloat::from(1.0)));
+            "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("test"))),
+            Operation::from(RZZ::new(0, 1, CalculatorFloat::from(1.0)));
+            "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("test"))),
+            Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from(1.0)));
+            "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::from(0.0))),
             Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(0.0)));
             "GivensRotation")]
@@ -754,6 +894,15 @@ fn test_pyo3_copy_deepcopy(input_operation: Operation) {
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)),
             Operation::from(EchoCrossResonance::new(0, 1));
             "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)),
+            Operation::from(ControlledHadamard::new(0, 1));
+            "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)),
+            Operation::from(SqrtCNOT::new(0, 1));
+            "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)),
+            Operation::from(InvSqrtCNOT::new(0, 1));
+            "InvSqrtCNOT")]
 fn test_pyo3_substitute_parameters(first_op: Operation, second_op: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -782,6 +931,14 @@ fn test_pyo3_substitute_parameters(first_op: Operation, second_op: Operation) {
             "ControlledPhaseShift")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("test")));
             "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("test")));
+            "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("test")));
+            "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("test")));
+            "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("test")));
+            "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::from(0.0)));
             "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from("test"), CalculatorFloat::from(0.0)));
@@ -824,6 +981,18 @@ fn test_pyo3_substitute_params_error(input_operation: Operation) {
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from(0.005))),
             Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from(0.005 * 1.5)));
             "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from(0.005))),
+            Operation::from(RXX::new(0, 1, CalculatorFloat::from(0.005 * 1.5)));
+            "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from(0.005))),
+            Operation::from(RYY::new(0, 1, CalculatorFloat::from(0.005 * 1.5)));
+            "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from(0.005))),
+            Operation::from(RZZ::new(0, 1, CalculatorFloat::from(0.005 * 1.5)));
+            "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from(0.005))),
+            Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from(0.005 * 1.5)));
+            "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from(0.005), CalculatorFloat::from(0.02))),
             Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from(0.005 * 1.5), CalculatorFloat::from(0.02)));
             "GivensRotation")]
@@ -1310,6 +1479,170 @@ fn test_new_variablemsxx(input_operation: Operation, arguments: (u32, u32, f64),
     })
 }
 
+/// Test new() function for RXX
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__eq__"; "RXX_eq")]
+#[test_case(Operation::from(RXX::new(2, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__ne__"; "RXX_ne")]
+fn test_new_rxx(input_operation: Operation, arguments: (u32, u32, f64), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<RXXWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<RXXWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, vec!["fails"]));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<RXXWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2, 0.0)).unwrap();
+        let new_op_diff = binding.downcast::<RXXWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<RXXWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "RXXWrapper { internal: RXX { control: 1, target: 2, theta: Float(0.0) } }"
+        );
+    })
+}
+
+/// Test new() function for RYY
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__eq__"; "RYY_eq")]
+#[test_case(Operation::from(RYY::new(2, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__ne__"; "RYY_ne")]
+fn test_new_ryy(input_operation: Operation, arguments: (u32, u32, f64), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<RYYWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<RYYWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, vec!["fails"]));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<RYYWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2, 0.0)).unwrap();
+        let new_op_diff = binding.downcast::<RYYWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<RYYWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "RYYWrapper { internal: RYY { control: 1, target: 2, theta: Float(0.0) } }"
+        );
+    })
+}
+
+/// Test new() function for RZZ
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__eq__"; "RZZ_eq")]
+#[test_case(Operation::from(RZZ::new(2, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__ne__"; "RZZ_ne")]
+fn test_new_rzz(input_operation: Operation, arguments: (u32, u32, f64), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<RZZWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<RZZWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, vec!["fails"]));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<RZZWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2, 0.0)).unwrap();
+        let new_op_diff = binding.downcast::<RZZWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<RZZWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "RZZWrapper { internal: RZZ { control: 1, target: 2, theta: Float(0.0) } }"
+        );
+    })
+}
+
+/// Test new() function for ZXCoupling
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__eq__"; "ZXCoupling_eq")]
+#[test_case(Operation::from(ZXCoupling::new(2, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__ne__"; "ZXCoupling_ne")]
+fn test_new_zxcoupling(input_operation: Operation, arguments: (u32, u32, f64), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialisation, no errors
+        let operation_type = py.get_type_bound::<ZXCouplingWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<ZXCouplingWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, vec!["fails"]));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<ZXCouplingWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2, 0.0)).unwrap();
+        let new_op_diff = binding.downcast::<ZXCouplingWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<ZXCouplingWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "ZXCouplingWrapper { internal: ZXCoupling { control: 1, target: 2, theta: Float(0.0) } }"
+        );
+    })
+}
+
 /// Test new() function for PMInteraction
 #[test_case(Operation::from(PMInteraction::new(0, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__eq__"; "PMInteraction_eq")]
 #[test_case(Operation::from(PMInteraction::new(2, 1, CalculatorFloat::from(0.0))), (0, 1, 0.0), "__ne__"; "PMInteraction_ne")]
@@ -1935,6 +2268,129 @@ fn test_new_echocrossresonance(input_operation: Operation, arguments: (u32, u32)
     })
 }
 
+/// Test new() function for ControlledHadamard
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)), (0, 1), "__eq__"; "ControlledHadamard_eq")]
+#[test_case(Operation::from(ControlledHadamard::new(2, 1)), (0, 1), "__ne__"; "ControlledHadamard_ne")]
+fn test_new_controlledhadamard(input_operation: Operation, arguments: (u32, u32), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialization, no errors
+        let operation_type = py.get_type_bound::<ControlledHadamardWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<ControlledHadamardWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, 0.0));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<ControlledHadamardWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2)).unwrap();
+        let new_op_diff = binding.downcast::<ControlledHadamardWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<ControlledHadamardWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "ControlledHadamardWrapper { internal: ControlledHadamard { control: 1, target: 2 } }"
+        );
+    })
+}
+
+/// Test new() function for SqrtCNOT
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)), (0, 1), "__eq__"; "SqrtCNOT_eq")]
+#[test_case(Operation::from(SqrtCNOT::new(2, 1)), (0, 1), "__ne__"; "SqrtCNOT_ne")]
+fn test_new_sqrtcnot(input_operation: Operation, arguments: (u32, u32), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialization, no errors
+        let operation_type = py.get_type_bound::<SqrtCNOTWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<SqrtCNOTWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, 0.0));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<SqrtCNOTWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2)).unwrap();
+        let new_op_diff = binding.downcast::<SqrtCNOTWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<SqrtCNOTWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "SqrtCNOTWrapper { internal: SqrtCNOT { control: 1, target: 2 } }"
+        );
+    })
+}
+
+/// Test new() function for InvSqrtCNOT
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)), (0, 1), "__eq__"; "InvSqrtCNOT_eq")]
+#[test_case(Operation::from(InvSqrtCNOT::new(2, 1)), (0, 1), "__ne__"; "InvSqrtCNOT_ne")]
+fn test_new_invsqrtcnot(input_operation: Operation, arguments: (u32, u32), method: &str) {
+    let operation = convert_operation_to_pyobject(input_operation).unwrap();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Basic initialization, no errors
+        let operation_type = py.get_type_bound::<InvSqrtCNOTWrapper>();
+        let binding = operation_type.call1(arguments).unwrap();
+        let operation_py = binding.downcast::<InvSqrtCNOTWrapper>().unwrap();
+        let comparison = bool::extract_bound(
+            &operation
+                .bind(py)
+                .call_method1(method, (operation_py,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Error initialisation
+        let result = operation_type.call1((0, 1, 0.0));
+        assert!(result.is_err());
+
+        // Testing PartialEq, Clone and Debug
+        let def_wrapper = operation_py.extract::<InvSqrtCNOTWrapper>().unwrap();
+        let binding = operation_type.call1((1, 2)).unwrap();
+        let new_op_diff = binding.downcast::<InvSqrtCNOTWrapper>().unwrap();
+        let def_wrapper_diff = new_op_diff.extract::<InvSqrtCNOTWrapper>().unwrap();
+        let helper_ne: bool = def_wrapper_diff != def_wrapper;
+        assert!(helper_ne);
+        let helper_eq: bool = def_wrapper == def_wrapper.clone();
+        assert!(helper_eq);
+
+        assert_eq!(
+            format!("{:?}", def_wrapper_diff),
+            "InvSqrtCNOTWrapper { internal: InvSqrtCNOT { control: 1, target: 2 } }"
+        );
+    })
+}
+
 /// Test the __richcmp__ function
 #[test_case(
     Operation::from(CNOT::new(0, 1)),
@@ -1972,6 +2428,18 @@ fn test_new_echocrossresonance(input_operation: Operation, arguments: (u32, u32)
 #[test_case(
     Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)),
     Operation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    Operation::from(RXX::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    Operation::from(RYY::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)),
     Operation::from(GivensRotation::new(1, 0, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
@@ -2011,6 +2479,15 @@ fn test_new_echocrossresonance(input_operation: Operation, arguments: (u32, u32)
 #[test_case(
     Operation::from(EchoCrossResonance::new(0, 1)),
     Operation::from(EchoCrossResonance::new(1, 0)); "EchoCrossResonance")]
+#[test_case(
+    Operation::from(ControlledHadamard::new(0, 1)),
+    Operation::from(ControlledHadamard::new(1, 0)); "ControlledHadamard")]
+#[test_case(
+    Operation::from(SqrtCNOT::new(0, 1)),
+    Operation::from(SqrtCNOT::new(1, 0)); "SqrtCNOT")]
+#[test_case(
+    Operation::from(InvSqrtCNOT::new(0, 1)),
+    Operation::from(InvSqrtCNOT::new(1, 0)); "InvSqrtCNOT")]
 fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -2057,6 +2534,10 @@ fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
 #[test_case(TwoQubitGateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(TwoQubitGateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(TwoQubitGateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(TwoQubitGateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(TwoQubitGateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(TwoQubitGateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(TwoQubitGateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(TwoQubitGateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(TwoQubitGateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(TwoQubitGateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -2070,6 +2551,9 @@ fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
 #[test_case(TwoQubitGateOperation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(TwoQubitGateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(TwoQubitGateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(TwoQubitGateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(TwoQubitGateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_pyo3_json_schema(operation: TwoQubitGateOperation) {
     let rust_schema = match operation {
         TwoQubitGateOperation::CNOT(_) => {
@@ -2149,6 +2633,27 @@ fn test_pyo3_json_schema(operation: TwoQubitGateOperation) {
         TwoQubitGateOperation::EchoCrossResonance(_) => {
             serde_json::to_string_pretty(&schemars::schema_for!(EchoCrossResonance)).unwrap()
         }
+        TwoQubitGateOperation::RXX(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(RXX)).unwrap()
+        }
+        TwoQubitGateOperation::RYY(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(RYY)).unwrap()
+        }
+        TwoQubitGateOperation::RZZ(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(RZZ)).unwrap()
+        }
+        TwoQubitGateOperation::ZXCoupling(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(ZXCoupling)).unwrap()
+        }
+        TwoQubitGateOperation::ControlledHadamard(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(ControlledHadamard)).unwrap()
+        }
+        TwoQubitGateOperation::SqrtCNOT(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(SqrtCNOT)).unwrap()
+        }
+        TwoQubitGateOperation::InvSqrtCNOT(_) => {
+            serde_json::to_string_pretty(&schemars::schema_for!(InvSqrtCNOT)).unwrap()
+        }
         _ => unreachable!(),
     };
     pyo3::prepare_freethreaded_python();
@@ -2158,6 +2663,13 @@ fn test_pyo3_json_schema(operation: TwoQubitGateOperation) {
             TwoQubitGateOperation::ControlledRotateX(_) => "1.3.0".to_string(),
             TwoQubitGateOperation::ControlledRotateXY(_) => "1.3.0".to_string(),
             TwoQubitGateOperation::EchoCrossResonance(_) => "1.8.0".to_string(),
+            TwoQubitGateOperation::RXX(_) => "1.14.0".to_string(),
+            TwoQubitGateOperation::RYY(_) => "1.14.0".to_string(),
+            TwoQubitGateOperation::RZZ(_) => "1.14.0".to_string(),
+            TwoQubitGateOperation::ZXCoupling(_) => "1.14.0".to_string(),
+            TwoQubitGateOperation::ControlledHadamard(_) => "1.14.0".to_string(),
+            TwoQubitGateOperation::SqrtCNOT(_) => "1.14.0".to_string(),
+            TwoQubitGateOperation::InvSqrtCNOT(_) => "1.14.0".to_string(),
             _ => "1.0.0".to_string(),
         };
         let converted_op = Operation::from(operation);
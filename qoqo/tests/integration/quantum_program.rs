@@ -20,6 +20,7 @@ use qoqo::operations::convert_operation_to_pyobject;
 use qoqo::{
     convert_into_quantum_program, CircuitWrapper, QoqoError, QuantumProgramWrapper, QOQO_VERSION,
 };
+use qoqo_calculator::Calculator;
 use roqoqo::measurements::{
     Cheated, CheatedInput, CheatedPauliZProduct, CheatedPauliZProductInput, ClassicalRegister,
     PauliZProduct, PauliZProductInput,
@@ -27,6 +28,7 @@ use roqoqo::measurements::{
 use roqoqo::operations::Operation;
 use roqoqo::operations::*;
 use roqoqo::{Circuit, QuantumProgram, ROQOQO_VERSION};
+use std::collections::HashMap;
 
 #[pyclass(name = "TestBackend", module = "qoqo")]
 #[derive(Debug, Clone, Copy)]
@@ -326,6 +328,570 @@ fn test_new_error_1() {
     })
 }
 
+/// Test with_input_parameter_names and with_measurement functions of QuantumProgram
+#[test]
+fn test_with_input_parameter_names_with_measurement() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input = create_measurement(py);
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let binding = program_type
+            .call1((&input, vec!["test".to_string()]))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+
+        let circs: Vec<CircuitWrapper> = vec![CircuitWrapper::new()];
+        let cr_type = py.get_type_bound::<ClassicalRegisterWrapper>();
+        let new_measurement = cr_type.call1((Some(CircuitWrapper::new()), circs)).unwrap();
+
+        let new_program = program
+            .call_method1("with_input_parameter_names", (vec!["theta".to_string()],))
+            .unwrap()
+            .call_method1("with_measurement", (&new_measurement,))
+            .unwrap()
+            .extract::<QuantumProgramWrapper>()
+            .unwrap();
+        assert_eq!(
+            new_program,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::ClassicalRegister {
+                    measurement: ClassicalRegister {
+                        constant_circuit: Some(Circuit::new()),
+                        circuits: vec![Circuit::new()],
+                    },
+                    input_parameter_names: vec!["theta".to_string()]
+                }
+            }
+        );
+
+        // The original program is unchanged
+        let input_parameter_names: Vec<String> = program
+            .call_method0("input_parameter_names")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(input_parameter_names, vec!["test".to_string()]);
+        let measurement = program.call_method0("measurement").unwrap();
+        assert!(measurement
+            .downcast::<CheatedPauliZProductWrapper>()
+            .is_ok());
+
+        let error = program.call_method1("with_measurement", (vec!["test".to_string()],));
+        assert!(error.is_err());
+    })
+}
+
+/// Test with_input_parameter_names with an empty list of names
+#[test]
+fn test_with_empty_input_parameter_names() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input = create_measurement(py);
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let binding = program_type
+            .call1((&input, vec!["test".to_string()]))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+
+        let new_program = program
+            .call_method1("with_input_parameter_names", (Vec::<String>::new(),))
+            .unwrap();
+        let input_parameter_names: Vec<String> = new_program
+            .call_method0("input_parameter_names")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(input_parameter_names.is_empty());
+        let has_parameters: bool = new_program
+            .call_method0("has_parameters")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(!has_parameters);
+
+        // The original program is unchanged
+        let has_parameters: bool = program
+            .call_method0("has_parameters")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(has_parameters);
+    })
+}
+
+/// Test with_constant_circuit function of QuantumProgram
+#[test]
+fn test_with_constant_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::ClassicalRegister {
+                    measurement: ClassicalRegister {
+                        constant_circuit: None,
+                        circuits: vec![Circuit::new()],
+                    },
+                    input_parameter_names: vec![],
+                },
+            },
+        )
+        .unwrap();
+
+        // Setting a constant circuit
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += Hadamard::new(0);
+        let with_constant = program
+            .call_method1(
+                py,
+                "with_constant_circuit",
+                (CircuitWrapper {
+                    internal: constant_circuit.clone(),
+                },),
+            )
+            .unwrap();
+        let returned: Option<CircuitWrapper> = with_constant
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(returned.unwrap().internal, constant_circuit);
+        let original: Option<CircuitWrapper> = program
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(original.is_none());
+
+        // Replacing the constant circuit
+        let mut new_constant_circuit = Circuit::new();
+        new_constant_circuit += PauliX::new(1);
+        let replaced = with_constant
+            .call_method1(
+                py,
+                "with_constant_circuit",
+                (CircuitWrapper {
+                    internal: new_constant_circuit.clone(),
+                },),
+            )
+            .unwrap();
+        let returned: Option<CircuitWrapper> = replaced
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(returned.unwrap().internal, new_constant_circuit);
+
+        // Removing the constant circuit
+        let removed = replaced
+            .call_method1(py, "with_constant_circuit", (py.None(),))
+            .unwrap();
+        let returned: Option<CircuitWrapper> = removed
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(returned.is_none());
+
+        let error = program.call_method1(py, "with_constant_circuit", (vec![0],));
+        assert!(error.is_err());
+    })
+}
+
+/// Test input_parameter_count and has_parameters functions of QuantumProgram
+#[test]
+fn test_input_parameter_count() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        for (names, has_parameters) in [
+            (vec!["theta".to_string(), "phi".to_string()], true),
+            (vec![], false),
+        ] {
+            let program = Py::new(
+                py,
+                QuantumProgramWrapper {
+                    internal: QuantumProgram::ClassicalRegister {
+                        measurement: ClassicalRegister {
+                            constant_circuit: None,
+                            circuits: vec![Circuit::new()],
+                        },
+                        input_parameter_names: names.clone(),
+                    },
+                },
+            )
+            .unwrap();
+            let count: usize = program
+                .call_method0(py, "input_parameter_count")
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            assert_eq!(count, names.len());
+            let returned: bool = program
+                .call_method0(py, "has_parameters")
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            assert_eq!(returned, has_parameters);
+        }
+    })
+}
+
+/// Test circuits and constant_circuit functions of QuantumProgram
+#[test]
+fn test_circuits_constant_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += Hadamard::new(0);
+        let circuits: Vec<Circuit> = (0..3)
+            .map(|i| {
+                let mut circuit = Circuit::new();
+                circuit += RotateX::new(i, "theta".into());
+                circuit
+            })
+            .collect();
+        let mut bri = PauliZProductInput::new(3, false);
+        let _ = bri.add_pauliz_product("ro".to_string(), vec![0]);
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::PauliZProduct {
+                    measurement: PauliZProduct {
+                        constant_circuit: Some(constant_circuit.clone()),
+                        circuits: circuits.clone(),
+                        input: bri,
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+            },
+        )
+        .unwrap();
+
+        let returned: Vec<CircuitWrapper> = program
+            .call_method0(py, "circuits")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(returned.len(), 3);
+        for (returned_circuit, circuit) in returned.iter().zip(circuits.iter()) {
+            assert_eq!(&returned_circuit.internal, circuit);
+        }
+        let returned_constant: Option<CircuitWrapper> = program
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(returned_constant.unwrap().internal, constant_circuit);
+
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::ClassicalRegister {
+                    measurement: ClassicalRegister {
+                        constant_circuit: None,
+                        circuits: vec![Circuit::new()],
+                    },
+                    input_parameter_names: vec![],
+                },
+            },
+        )
+        .unwrap();
+        let returned: Vec<CircuitWrapper> = program
+            .call_method0(py, "circuits")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(returned, vec![CircuitWrapper::new()]);
+        let returned_constant: Option<CircuitWrapper> = program
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(returned_constant.is_none());
+    })
+}
+
+/// Test circuit_count function of QuantumProgram
+#[test]
+fn test_circuit_count() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += Hadamard::new(0);
+        let circuits: Vec<Circuit> = (0..3)
+            .map(|i| {
+                let mut circuit = Circuit::new();
+                circuit += RotateX::new(i, "theta".into());
+                circuit
+            })
+            .collect();
+        let mut bri = PauliZProductInput::new(3, false);
+        let _ = bri.add_pauliz_product("ro".to_string(), vec![0]);
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::PauliZProduct {
+                    measurement: PauliZProduct {
+                        constant_circuit: Some(constant_circuit.clone()),
+                        circuits,
+                        input: bri.clone(),
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+            },
+        )
+        .unwrap();
+        let circuit_count: usize = program
+            .call_method0(py, "circuit_count")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(circuit_count, 3);
+
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::PauliZProduct {
+                    measurement: PauliZProduct {
+                        constant_circuit: Some(constant_circuit),
+                        circuits: vec![],
+                        input: bri,
+                    },
+                    input_parameter_names: vec![],
+                },
+            },
+        )
+        .unwrap();
+        let circuit_count: usize = program
+            .call_method0(py, "circuit_count")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert_eq!(circuit_count, 0);
+    })
+}
+
+/// Test get_circuit function of QuantumProgram
+#[test]
+fn test_get_circuit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuits: Vec<Circuit> = (0..3)
+            .map(|i| {
+                let mut circuit = Circuit::new();
+                circuit += RotateX::new(i, "theta".into());
+                circuit
+            })
+            .collect();
+        let mut bri = PauliZProductInput::new(3, false);
+        let _ = bri.add_pauliz_product("ro".to_string(), vec![0]);
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::PauliZProduct {
+                    measurement: PauliZProduct {
+                        constant_circuit: None,
+                        circuits: circuits.clone(),
+                        input: bri,
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+            },
+        )
+        .unwrap();
+
+        for (index, circuit) in circuits.iter().enumerate() {
+            let returned: CircuitWrapper = program
+                .call_method1(py, "get_circuit", (index,))
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            assert_eq!(&returned.internal, circuit);
+        }
+        let error = program.call_method1(py, "get_circuit", (3,)).unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyIndexError>(py));
+
+        let returned_constant: Option<CircuitWrapper> = program
+            .call_method0(py, "constant_circuit")
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!(returned_constant.is_none());
+    })
+}
+
+/// Test assert_no_symbolic_parameters function of QuantumProgram
+#[test]
+fn test_assert_no_symbolic_parameters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let create_program = |constant_circuit: Circuit, circuit: Circuit| {
+            Py::new(
+                py,
+                QuantumProgramWrapper {
+                    internal: QuantumProgram::ClassicalRegister {
+                        measurement: ClassicalRegister {
+                            constant_circuit: Some(constant_circuit),
+                            circuits: vec![circuit],
+                        },
+                        input_parameter_names: vec![],
+                    },
+                },
+            )
+            .unwrap()
+        };
+
+        let mut concrete_circuit = Circuit::new();
+        concrete_circuit += RotateZ::new(0, 0.5.into());
+        let program = create_program(Circuit::new(), concrete_circuit.clone());
+        assert!(program
+            .call_method0(py, "assert_no_symbolic_parameters")
+            .is_ok());
+
+        let mut symbolic_circuit = Circuit::new();
+        symbolic_circuit += RotateZ::new(0, "theta".into());
+        let program = create_program(Circuit::new(), symbolic_circuit);
+        let error = program
+            .call_method0(py, "assert_no_symbolic_parameters")
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyRuntimeError>(py));
+        assert!(error.to_string().contains("theta"));
+
+        // Substituting only theta leaves phi in the second circuit unresolved
+        let mut constant_circuit = Circuit::new();
+        constant_circuit += RotateX::new(0, "theta".into());
+        let mut calculator = Calculator::new();
+        calculator.set_variable("theta", 0.5);
+        let constant_circuit = constant_circuit.substitute_parameters(&calculator).unwrap();
+        let mut partial_circuit = Circuit::new();
+        partial_circuit += RotateY::new(0, "phi".into());
+        let program = create_program(constant_circuit, partial_circuit);
+        let error = program
+            .call_method0(py, "assert_no_symbolic_parameters")
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyRuntimeError>(py));
+        assert!(error.to_string().contains("phi"));
+        assert!(!error.to_string().contains("theta"));
+
+        // Words in annotations are not symbolic parameters
+        let mut annotated_circuit = Circuit::new();
+        annotated_circuit += PragmaAnnotatedOp::new(
+            RotateZ::new(0, 0.5.into()).into(),
+            "needs calibration".to_string(),
+        );
+        let program = create_program(Circuit::new(), annotated_circuit);
+        assert!(program
+            .call_method0(py, "assert_no_symbolic_parameters")
+            .is_ok());
+
+        let mut annotated_circuit = Circuit::new();
+        annotated_circuit += PragmaAnnotatedOp::new(
+            RotateZ::new(0, "theta".into()).into(),
+            "needs calibration".to_string(),
+        );
+        let program = create_program(Circuit::new(), annotated_circuit);
+        let error = program
+            .call_method0(py, "assert_no_symbolic_parameters")
+            .unwrap_err();
+        assert!(error.to_string().contains("[\"theta\"]"));
+    })
+}
+
+/// Test to_pennylane_qnode function of QuantumProgram
+#[test]
+fn test_to_pennylane_qnode() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = Circuit::new();
+        circuit += RotateX::new(0, "theta".into());
+        circuit += CNOT::new(0, 1);
+        circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+        let mut input = PauliZProductInput::new(2, false);
+        let index = input.add_pauliz_product("ro".to_string(), vec![1]).unwrap();
+        input
+            .add_linear_exp_val("exp_val".to_string(), HashMap::from([(index, 2.0)]))
+            .unwrap();
+        let program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::PauliZProduct {
+                    measurement: PauliZProduct {
+                        constant_circuit: None,
+                        circuits: vec![circuit.clone()],
+                        input,
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+            },
+        )
+        .unwrap();
+
+        // Only PauliZProduct measurements are supported
+        let classical_program = Py::new(
+            py,
+            QuantumProgramWrapper {
+                internal: QuantumProgram::ClassicalRegister {
+                    measurement: ClassicalRegister {
+                        constant_circuit: None,
+                        circuits: vec![circuit],
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+            },
+        )
+        .unwrap();
+        let error = classical_program
+            .call_method1(py, "to_pennylane_qnode", (py.None(),))
+            .unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+
+        // The conversion itself can only be tested when pennylane is installed
+        let qml = match py.import_bound("pennylane") {
+            Ok(qml) => qml,
+            Err(_) => return,
+        };
+        let device = qml.call_method1("device", ("default.qubit", 2)).unwrap();
+        let qnode = program
+            .call_method1(py, "to_pennylane_qnode", (device,))
+            .unwrap();
+
+        let result: f64 = qnode.call1(py, (0.0,)).unwrap().extract(py).unwrap();
+        assert!((result - 2.0).abs() < 1e-10);
+        let result: f64 = qnode
+            .call1(py, (std::f64::consts::PI,))
+            .unwrap()
+            .extract(py)
+            .unwrap();
+        assert!((result + 2.0).abs() < 1e-10);
+
+        // Gates with a Pennylane equivalent are not converted to QubitUnitary
+        let script = qml
+            .getattr("tape")
+            .unwrap()
+            .call_method1("make_qscript", (qnode.getattr(py, "func").unwrap(),))
+            .unwrap()
+            .call1((0.5,))
+            .unwrap();
+        let names: Vec<String> = script
+            .getattr("operations")
+            .unwrap()
+            .iter()
+            .unwrap()
+            .map(|operation| {
+                operation
+                    .unwrap()
+                    .getattr("name")
+                    .unwrap()
+                    .extract()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(names, vec!["RX".to_string(), "CNOT".to_string()]);
+
+        assert!(qnode.call1(py, (0.0, 1.0)).is_err());
+    })
+}
+
 /// Test copy and deepcopy functions of QuantumProgram
 #[test]
 fn test_copy_deepcopy() {
@@ -417,9 +983,13 @@ fn test_to_from_bincode() {
         .unwrap();
         assert!(comparison);
 
-        let deserialised_error =
-            new.call_method1("from_bincode", (bincode::serialize("fails").unwrap(),));
-        assert!(deserialised_error.is_err());
+        let deserialised_error = new
+            .call_method1("from_bincode", (bincode::serialize("fails").unwrap(),))
+            .unwrap_err();
+        assert!(deserialised_error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert!(deserialised_error
+            .to_string()
+            .contains("Input cannot be deserialized to QuantumProgram: "));
 
         let deserialised_error =
             new.call_method1("from_bincode", (bincode::serialize(&vec![0]).unwrap(),));
@@ -493,9 +1063,13 @@ fn test_to_from_json() {
             bool::extract_bound(&deserialised.call_method1("__eq__", (program,)).unwrap()).unwrap();
         assert!(comparison);
 
-        let deserialised_error =
-            new.call_method1("from_json", (serde_json::to_string("fails").unwrap(),));
-        assert!(deserialised_error.is_err());
+        let deserialised_error = new
+            .call_method1("from_json", (serde_json::to_string("fails").unwrap(),))
+            .unwrap_err();
+        assert!(deserialised_error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        assert!(deserialised_error
+            .to_string()
+            .contains("Input cannot be deserialized to QuantumProgram: "));
 
         let deserialised_error =
             new.call_method1("from_json", (serde_json::to_string(&vec![0]).unwrap(),));
@@ -519,6 +1093,73 @@ fn test_to_from_json() {
     })
 }
 
+/// Test that to_ and from_json round-trip the different measurement types of QuantumProgram
+#[test]
+fn test_to_from_json_measurement_types() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circuit = Circuit::new();
+        circuit += RotateX::new(0, "theta".into());
+        circuit += DefinitionBit::new("ro".to_string(), 1, true);
+        circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+        let mut pauliz_input = PauliZProductInput::new(1, false);
+        let _ = pauliz_input.add_pauliz_product("ro".to_string(), vec![0]);
+
+        let programs = [
+            (
+                QuantumProgram::PauliZProduct {
+                    measurement: PauliZProduct {
+                        constant_circuit: Some(Circuit::new()),
+                        circuits: vec![circuit.clone()],
+                        input: pauliz_input,
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+                "PauliZProduct",
+            ),
+            (
+                QuantumProgram::ClassicalRegister {
+                    measurement: ClassicalRegister {
+                        constant_circuit: Some(Circuit::new()),
+                        circuits: vec![circuit.clone()],
+                    },
+                    input_parameter_names: vec!["theta".to_string()],
+                },
+                "ClassicalRegister",
+            ),
+            (
+                QuantumProgram::ClassicalRegister {
+                    measurement: ClassicalRegister {
+                        constant_circuit: None,
+                        circuits: vec![circuit],
+                    },
+                    input_parameter_names: vec![],
+                },
+                "ClassicalRegister",
+            ),
+        ];
+        for (program, tag) in programs {
+            let wrapper = Bound::new(
+                py,
+                QuantumProgramWrapper {
+                    internal: program.clone(),
+                },
+            )
+            .unwrap();
+            let serialised: String = wrapper.call_method0("to_json").unwrap().extract().unwrap();
+            // The json representation names the measurement type of the program
+            assert!(serialised.contains(tag));
+            let deserialised: QuantumProgramWrapper = py
+                .get_type_bound::<QuantumProgramWrapper>()
+                .call_method1("from_json", (serialised,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(deserialised.internal, program);
+        }
+    })
+}
+
 /// Test json_schema function of QuantumProgram
 #[cfg(feature = "json_schema")]
 #[test]
@@ -766,6 +1407,39 @@ fn test_return_measurement_classicalreg() {
     })
 }
 
+/// Test measurement_type()
+#[test]
+fn test_measurement_type() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let measurement = create_measurement(py);
+        let program_type = py.get_type_bound::<QuantumProgramWrapper>();
+        let program = program_type
+            .call1((measurement, vec!["test".to_string()]))
+            .unwrap();
+        let measurement_type: String = program
+            .call_method0("measurement_type")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(measurement_type, "CheatedPauliZProduct");
+
+        let br_type = py.get_type_bound::<ClassicalRegisterWrapper>();
+        let measurement = br_type
+            .call1((Some(CircuitWrapper::new()), vec![CircuitWrapper::new()]))
+            .unwrap();
+        let program = program_type
+            .call1((measurement, Vec::<String>::new()))
+            .unwrap();
+        let measurement_type: String = program
+            .call_method0("measurement_type")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(measurement_type, "ClassicalRegister");
+    })
+}
+
 /// Test input_parameter_names()
 #[test]
 fn test_input_parameter_names() {
@@ -14,7 +14,9 @@ use crate::{extract_fields_with_types, extract_variants_with_types, RESERVED_FIE
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::collections::HashSet;
-use syn::{Data, DataEnum, DataStruct, DeriveInput, Ident, Type, TypePath};
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, GenericArgument, Ident, PathArguments, Type, TypePath,
+};
 
 /// Dispatch to derive Operate for enums and structs
 pub fn dispatch_struct_enum(input: DeriveInput) -> TokenStream {
@@ -146,6 +148,16 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                 !self.#id.is_float()
             }
         });
+    let calculator_float_map_fields = fields_with_type
+        .clone()
+        .filter(|(_, type_string, ty)| {
+            type_string == &Some("HashMap".to_string()) && has_calculator_float_values(ty)
+        })
+        .map(|(id, _, _)| {
+            quote! {
+                self.#id.values().any(|x| !x.is_float())
+            }
+        });
     let circuit_fields = fields_with_type
         .clone()
         .filter(|(_, type_string, _)| type_string == &Some("Circuit".to_string()))
@@ -177,6 +189,7 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
         });
 
     let is_parametrized_fields = if calculator_float_fields.clone().last().is_none()
+        && calculator_float_map_fields.clone().last().is_none()
         && circuit_fields.clone().last().is_none()
         && circuit_fields2.clone().last().is_none()
         && struqture_fields.clone().last().is_none()
@@ -184,6 +197,7 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
         vec![quote!(false)]
     } else {
         calculator_float_fields
+            .chain(calculator_float_map_fields)
             .chain(circuit_fields)
             .chain(circuit_fields2)
             .chain(struqture_fields)
@@ -244,6 +258,25 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
     }
 }
 
+/// Returns true when the type is a map with CalculatorFloat values (e.g. HashMap<usize, CalculatorFloat>)
+fn has_calculator_float_values(ty: &Type) -> bool {
+    let arguments = match ty {
+        Type::Path(TypePath { path: p, .. }) => p.segments.last().map(|segment| &segment.arguments),
+        _ => None,
+    };
+    match arguments {
+        Some(PathArguments::AngleBracketed(angle_arguments)) => match angle_arguments.args.last() {
+            Some(GenericArgument::Type(Type::Path(TypePath { path: inner, .. }))) => inner
+                .segments
+                .last()
+                .map(|segment| segment.ident == "CalculatorFloat")
+                .unwrap_or(false),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 /// Dispatch to derive Operation TryFrom implementations for enums
 pub fn dispatch_try_from_enum(input: DeriveInput) -> TokenStream {
     let ident = input.ident;
@@ -19,9 +19,9 @@ use std::str::FromStr;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::visit::{self, Visit};
-use syn::{AttrStyle, File, Ident, ItemImpl, ItemStruct, LitStr, Path, Token, Type, TypePath};
+use syn::{AttrStyle, File, Ident, ItemImpl, ItemStruct, Path, Token, Type, TypePath};
 
-const NUMBER_OF_MINOR_VERSIONS: usize = 12;
+const NUMBER_OF_MINOR_VERSIONS: usize = 15;
 
 /// Visitor scanning rust source code for struct belonging to enums
 struct Visitor {
@@ -115,18 +115,6 @@ impl Visitor {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct CfgFeatureMacroArgument(String);
-
-impl Parse for CfgFeatureMacroArgument {
-    fn parse(input: ParseStream) -> syn::parse::Result<Self> {
-        input.parse::<Ident>()?;
-        input.parse::<Token![=]>()?;
-        let feature_name: LitStr = input.parse()?;
-        Ok(Self(feature_name.value()))
-    }
-}
-
 /// Struct for parsed derive macro arguments. Used to identify structs belonging to enums
 #[derive(Debug)]
 struct DeriveMacroArguments(HashSet<String>);
@@ -165,17 +153,6 @@ impl<'ast> Visit<'ast> for Visitor {
         // Check attributes
         for att in i.attrs.clone() {
             let path = att.path().get_ident().map(|id| id.to_string());
-            // TOFIX: REMOVE WHEN STABILISED
-            if matches!(att.style, AttrStyle::Outer)
-                && path == Some("cfg".to_string())
-                && !cfg!(feature = "unstable_operation_definition")
-            {
-                let cfg_feature_name: CfgFeatureMacroArgument =
-                    att.parse_args().expect("parsing failed 1");
-                if cfg_feature_name.0.contains("unstable_operation_definition") {
-                    return;
-                }
-            }
             // only consider the derive attribute, if no derive attribute is present don't add anything
             // to the internal storage of the visitor
             if matches!(att.style, AttrStyle::Outer) && path == Some("derive".to_string()) {
@@ -359,6 +336,12 @@ impl<'ast> Visit<'ast> for Visitor {
                 if trait_name.as_str() == "ImplementedIn1point11" {
                     self.roqoqo_version_register.insert(id.clone(), 11);
                 }
+                if trait_name.as_str() == "ImplementedIn1point13" {
+                    self.roqoqo_version_register.insert(id.clone(), 13);
+                }
+                if trait_name.as_str() == "ImplementedIn1point14" {
+                    self.roqoqo_version_register.insert(id.clone(), 14);
+                }
                 if trait_name.as_str() == "OperateSingleQubitGate" {
                     self.single_qubit_gate_operations.push(id.clone());
                 }
@@ -11,17 +11,21 @@
 // limitations under the License.
 
 use crate::operations::{
-    Define, InvolveQubits, InvolvedQubits, Operate, Operation, Substitute, SupportedVersion,
+    Define, GPi2, GateOperation, GivensRotation, GivensRotationLittleEndian, InvSqrtCNOT,
+    InvSqrtISwap, InvSqrtPauliX, InvolveQubits, InvolvedQubits, Operate, OperateGate,
+    OperateSingleQubit, OperateSingleQubitGate, OperateTwoQubit, Operation, PhaseShiftState1,
+    PhaseShiftedControlledPhase, PragmaGlobalPhase, Rotate, Rotation, SingleQubitGate, SqrtCNOT,
+    SqrtISwap, SqrtPauliX, Substitute, SupportedVersion,
 };
-#[cfg(feature = "overrotate")]
-use crate::operations::{Rotate, Rotation};
 use crate::RoqoqoError;
 use crate::RoqoqoVersion;
 #[cfg(feature = "serialize")]
 use crate::RoqoqoVersionSerializable;
-use qoqo_calculator::Calculator;
-#[cfg(feature = "overrotate")]
+use ndarray::Array2;
+use num_complex::Complex64;
+use qoqo_calculator::{Calculator, CalculatorFloat};
 use std::convert::TryFrom;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use std::ops;
 use std::{
     collections::{HashMap, HashSet},
@@ -176,7 +180,6 @@ impl Circuit {
             Operation::InputSymbolic(_) => {
                 self.definitions.push(input);
             }
-            #[cfg(feature = "unstable_operation_definition")]
             Operation::GateDefinition(_) => {
                 self.definitions.push(input);
             }
@@ -283,6 +286,138 @@ impl Circuit {
         }
     }
 
+    /// Returns the depth of the Circuit.
+    ///
+    /// The depth is the length of the longest chain of operations where each operation
+    /// acts on at least one qubit of the previous operation in the chain.
+    /// Operations acting on all qubits are treated as acting on every qubit seen so far in the Circuit,
+    /// operations not acting on any qubit are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The depth of the Circuit.
+    pub fn depth(&self) -> usize {
+        let mut qubit_depths: HashMap<usize, usize> = HashMap::new();
+        let mut depth: usize = 0;
+        for op in self.operations.iter() {
+            match op.involved_qubits() {
+                InvolvedQubits::None => (),
+                InvolvedQubits::All => {
+                    depth += 1;
+                    for qubit_depth in qubit_depths.values_mut() {
+                        *qubit_depth = depth;
+                    }
+                }
+                InvolvedQubits::Set(qubits) => {
+                    let layer = qubits
+                        .iter()
+                        .map(|qubit| qubit_depths.get(qubit).copied().unwrap_or_default())
+                        .max()
+                        .unwrap_or_default()
+                        + 1;
+                    for qubit in qubits {
+                        qubit_depths.insert(qubit, layer);
+                    }
+                    depth = depth.max(layer);
+                }
+            }
+        }
+        depth
+    }
+
+    /// Returns the number of operations acting on each qubit of the Circuit.
+    ///
+    /// Operations are treated the same way as in [Circuit::depth]: operations acting on all qubits
+    /// count for every qubit seen so far in the Circuit, operations not acting on any qubit are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<usize, usize>` - The number of operations for each qubit.
+    pub fn gate_count_by_qubit(&self) -> HashMap<usize, usize> {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for op in self.operations.iter() {
+            match op.involved_qubits() {
+                InvolvedQubits::None => (),
+                InvolvedQubits::All => {
+                    for count in counts.values_mut() {
+                        *count += 1;
+                    }
+                }
+                InvolvedQubits::Set(qubits) => {
+                    for qubit in qubits {
+                        *counts.entry(qubit).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+        counts
+    }
+
+    /// Returns the gate volume of the Circuit.
+    ///
+    /// The gate volume is the depth of the Circuit multiplied by its width,
+    /// the number of distinct qubits the operations in the Circuit act on.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The gate volume of the Circuit.
+    pub fn gate_volume(&self) -> usize {
+        let mut qubits: HashSet<usize> = HashSet::new();
+        for op in self.operations.iter() {
+            if let InvolvedQubits::Set(involved) = op.involved_qubits() {
+                qubits.extend(involved);
+            }
+        }
+        self.depth() * qubits.len()
+    }
+
+    /// Returns the operations on the critical path of the Circuit.
+    ///
+    /// The critical path is the longest chain of operations where each operation
+    /// acts on at least one qubit of the previous operation in the chain, its length is the depth of the Circuit.
+    /// Operations are treated the same way as in [Circuit::depth].
+    /// When several chains have the same length, the chain ending first in the Circuit is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Operation>` - The operations on the critical path in execution order.
+    pub fn critical_path_gates(&self) -> Vec<Operation> {
+        // For each qubit the length of the longest chain ending on it and the index of its last operation
+        let mut qubit_chains: HashMap<usize, (usize, usize)> = HashMap::new();
+        let mut predecessors: Vec<Option<usize>> = vec![None; self.operations.len()];
+        let mut end: Option<(usize, usize)> = None;
+        for (index, op) in self.operations.iter().enumerate() {
+            let qubits: Vec<usize> = match op.involved_qubits() {
+                InvolvedQubits::None => continue,
+                InvolvedQubits::All => qubit_chains.keys().copied().collect(),
+                InvolvedQubits::Set(qubits) => qubits.into_iter().collect(),
+            };
+            let previous = qubits
+                .iter()
+                .filter_map(|qubit| qubit_chains.get(qubit).copied())
+                .max_by_key(|(length, _)| *length);
+            let length = previous.map(|(length, _)| length).unwrap_or_default() + 1;
+            predecessors[index] = previous.map(|(_, previous_index)| previous_index);
+            for qubit in qubits {
+                qubit_chains.insert(qubit, (length, index));
+            }
+            if end
+                .map(|(max_length, _)| length > max_length)
+                .unwrap_or(true)
+            {
+                end = Some((length, index));
+            }
+        }
+        let mut path: Vec<Operation> = Vec::new();
+        let mut current = end.map(|(_, index)| index);
+        while let Some(index) = current {
+            path.push(self.operations[index].clone());
+            current = predecessors[index];
+        }
+        path.reverse();
+        path
+    }
+
     /// Returns reference to the vector of definitions in Circuit.
     ///
     /// Definitions need to be unique.
@@ -483,6 +618,233 @@ impl Circuit {
         }
         Ok(return_circuit)
     }
+
+    /// Returns the inverse of the Circuit.
+    ///
+    /// The order of the operations is reversed and every operation is replaced with its inverse:
+    /// * Rotations are replaced with the rotation by the negative angle.
+    /// * PhaseShiftedControlledPhase is replaced with the gate with negative θ and φ.
+    /// * GivensRotation and GivensRotationLittleEndian are replaced with the Givens rotation by the negative angle
+    ///   and a PhaseShiftState1 by -φ on the qubit the phase φ acts on.
+    /// * GPi is self-inverse, GPi2 with angle θ is replaced with GPi2 with angle θ + π.
+    /// * Self-inverse gates (e.g. PauliX, Hadamard, CNOT) are unchanged.
+    /// * Gates with a known inverse gate (e.g. SqrtPauliX) are replaced with the inverse gate.
+    /// * SGate and TGate are replaced with PhaseShiftState1 by -π/2 and -π/4, as roqoqo has no inverse S or T gate.
+    ///   Inverting the Circuit twice therefore returns PhaseShiftState1 by π/2 and π/4 in their place,
+    ///   which have the same unitary matrix as SGate and TGate.
+    /// * PragmaGlobalPhase is replaced with the negative global phase.
+    ///
+    /// Gates with symbolic parameters are only inverted when the inverse is known for any value of
+    /// the parameters, the self-inverse check of other gates needs the unitary matrix.
+    ///
+    /// Definitions are not changed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The inverse Circuit.
+    /// * `Err(RoqoqoError::GenericError)` - The Circuit contains an operation without a known inverse.
+    pub fn inverse(&self) -> Result<Self, RoqoqoError> {
+        let mut inverse_operations: Vec<Operation> = Vec::with_capacity(self.operations.len());
+        for op in self.operations.iter().rev() {
+            inverse_operations.extend(inverse_operation(op)?);
+        }
+        Ok(Self {
+            definitions: self.definitions.clone(),
+            operations: inverse_operations,
+            _roqoqo_version: RoqoqoVersion,
+        })
+    }
+
+    /// Returns clone of the circuit with constant operations folded.
+    ///
+    /// Constant folding is only possible for circuits without free symbolic parameters and runs the following steps:
+    /// * Constant symbolic expressions (e.g. "2 * 0.5") are evaluated.
+    /// * Gate operations whose unitary matrix is the identity (e.g. zero-angle rotations) are removed.
+    /// * Pairs of identical self-inverse gate operations (e.g. PauliX followed by PauliX)
+    ///   that are not separated by an operation acting on the same qubits cancel.
+    ///
+    /// Definitions are not changed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The Circuit with constant operations folded.
+    /// * `Err(RoqoqoError::CalculatorError)` - The Circuit contains free symbolic parameters.
+    pub fn constant_folding(&self) -> Result<Self, RoqoqoError> {
+        let calculator = Calculator::new();
+        let mut folded_operations: Vec<Operation> = Vec::new();
+        for op in self.operations.iter() {
+            let op = op.substitute_parameters(&calculator)?;
+            let unitary: Option<Array2<Complex64>> = GateOperation::try_from(op.clone())
+                .ok()
+                .and_then(|gate| gate.unitary_matrix().ok());
+            if let Some(unitary) = unitary {
+                if is_identity_matrix(&unitary) {
+                    continue;
+                }
+                // Search the last operation acting on one of the qubits of the current operation
+                let involved_qubits = op.involved_qubits();
+                let previous_index = folded_operations.iter().rposition(|previous| {
+                    qubits_overlap(&previous.involved_qubits(), &involved_qubits)
+                });
+                if let Some(previous_index) = previous_index {
+                    if folded_operations[previous_index] == op
+                        && is_identity_matrix(&unitary.dot(&unitary))
+                    {
+                        folded_operations.remove(previous_index);
+                        continue;
+                    }
+                }
+            }
+            folded_operations.push(op);
+        }
+        Ok(Self {
+            definitions: self.definitions.clone(),
+            operations: folded_operations,
+            _roqoqo_version: RoqoqoVersion,
+        })
+    }
+
+    /// Returns clone of the circuit with all PragmaLoop operations replaced by their repeated circuits.
+    ///
+    /// Every PragmaLoop is replaced with the operations of its circuit repeated floor(repetitions) times.
+    /// Nested PragmaLoop operations are flattened recursively.
+    /// The definitions of a looped circuit are only added once.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The Circuit without PragmaLoop operations.
+    /// * `Err(RoqoqoError::GenericError)` - A PragmaLoop has a symbolic number of repetitions.
+    pub fn flatten_pragma_loops(&self) -> Result<Self, RoqoqoError> {
+        let mut flattened = Circuit::new();
+        for op in self.iter() {
+            match op {
+                Operation::PragmaLoop(pragma_loop) => {
+                    let repetitions = pragma_loop.repetitions().float().map_err(|_| {
+                        RoqoqoError::GenericError {
+                            msg: format!(
+                                "Cannot flatten PragmaLoop with symbolic repetitions {}",
+                                pragma_loop.repetitions()
+                            ),
+                        }
+                    })?;
+                    let inner_circuit = pragma_loop.circuit().flatten_pragma_loops()?;
+                    for definition in inner_circuit.definitions() {
+                        flattened.add_operation(definition.clone());
+                    }
+                    for _ in 0..(repetitions.floor().max(0.0) as usize) {
+                        for inner_op in inner_circuit.operations() {
+                            flattened.add_operation(inner_op.clone());
+                        }
+                    }
+                }
+                _ => flattened.add_operation(op.clone()),
+            }
+        }
+        Ok(flattened)
+    }
+}
+
+// Helper function returning the operations implementing the inverse of a single operation.
+//
+// The operations are returned in the order in which they are applied.
+fn inverse_operation(operation: &Operation) -> Result<Vec<Operation>, RoqoqoError> {
+    let inverse: Operation = match operation {
+        Operation::PragmaGlobalPhase(op) => {
+            PragmaGlobalPhase::new(op.phase().clone() * (-1.0)).into()
+        }
+        Operation::SGate(op) => {
+            PhaseShiftState1::new(*op.qubit(), CalculatorFloat::from(-FRAC_PI_2)).into()
+        }
+        Operation::TGate(op) => {
+            PhaseShiftState1::new(*op.qubit(), CalculatorFloat::from(-FRAC_PI_4)).into()
+        }
+        Operation::SqrtPauliX(op) => InvSqrtPauliX::new(*op.qubit()).into(),
+        Operation::InvSqrtPauliX(op) => SqrtPauliX::new(*op.qubit()).into(),
+        Operation::SqrtISwap(op) => InvSqrtISwap::new(*op.control(), *op.target()).into(),
+        Operation::InvSqrtISwap(op) => SqrtISwap::new(*op.control(), *op.target()).into(),
+        Operation::SqrtCNOT(op) => InvSqrtCNOT::new(*op.control(), *op.target()).into(),
+        Operation::InvSqrtCNOT(op) => SqrtCNOT::new(*op.control(), *op.target()).into(),
+        Operation::SingleQubitGate(op) => SingleQubitGate::new(
+            *op.qubit(),
+            op.alpha_r(),
+            op.alpha_i() * (-1.0),
+            op.beta_r() * (-1.0),
+            op.beta_i() * (-1.0),
+            op.global_phase() * (-1.0),
+        )
+        .into(),
+        // GPi is self-inverse for any angle
+        Operation::GPi(_) => operation.clone(),
+        Operation::GPi2(op) => GPi2::new(*op.qubit(), op.theta().clone() + PI).into(),
+        Operation::PhaseShiftedControlledPhase(op) => PhaseShiftedControlledPhase::new(
+            *op.control(),
+            *op.target(),
+            op.theta().clone() * (-1.0),
+            op.phi().clone() * (-1.0),
+        )
+        .into(),
+        // The phase φ is applied to the target qubit before the rotation
+        Operation::GivensRotation(op) => {
+            return Ok(vec![
+                GivensRotation::new(
+                    *op.control(),
+                    *op.target(),
+                    op.theta().clone() * (-1.0),
+                    CalculatorFloat::ZERO,
+                )
+                .into(),
+                PhaseShiftState1::new(*op.target(), op.phi().clone() * (-1.0)).into(),
+            ])
+        }
+        // The phase φ is applied to the control qubit after the rotation
+        Operation::GivensRotationLittleEndian(op) => {
+            return Ok(vec![
+                PhaseShiftState1::new(*op.control(), op.phi().clone() * (-1.0)).into(),
+                GivensRotationLittleEndian::new(
+                    *op.control(),
+                    *op.target(),
+                    op.theta().clone() * (-1.0),
+                    CalculatorFloat::ZERO,
+                )
+                .into(),
+            ])
+        }
+        _ => {
+            if let Ok(rotation) = Rotation::try_from(operation.clone()) {
+                rotation.powercf(CalculatorFloat::from(-1.0)).into()
+            } else {
+                let is_self_inverse = GateOperation::try_from(operation.clone())
+                    .ok()
+                    .and_then(|gate| gate.unitary_matrix().ok())
+                    .map(|unitary| is_identity_matrix(&unitary.dot(&unitary)))
+                    .unwrap_or(false);
+                if !is_self_inverse {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!("No known inverse for operation {}", operation.hqslang()),
+                    });
+                }
+                operation.clone()
+            }
+        }
+    };
+    Ok(vec![inverse])
+}
+
+// Helper function checking if a matrix is the identity matrix up to numerical accuracy.
+fn is_identity_matrix(matrix: &Array2<Complex64>) -> bool {
+    matrix.indexed_iter().all(|((row, column), value)| {
+        let expected = if row == column { 1.0 } else { 0.0 };
+        (*value - expected).norm() < 1e-12
+    })
+}
+
+// Helper function checking if two sets of involved qubits share at least one qubit.
+fn qubits_overlap(first: &InvolvedQubits, second: &InvolvedQubits) -> bool {
+    match (first, second) {
+        (InvolvedQubits::None, _) | (_, InvolvedQubits::None) => false,
+        (InvolvedQubits::All, _) | (_, InvolvedQubits::All) => true,
+        (InvolvedQubits::Set(x), InvolvedQubits::Set(y)) => !x.is_disjoint(y),
+    }
 }
 
 /// Implements Index Access for Circuit.
@@ -857,8 +1219,16 @@ impl Iterator for OperationIterator {
             None => self.operation_iter.next(),
         }
     }
+
+    /// Returns the exact number of remaining Operations in the Iterator.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.definition_iter.len() + self.operation_iter.len();
+        (remaining, Some(remaining))
+    }
 }
 
+impl ExactSizeIterator for OperationIterator {}
+
 impl SupportedVersion for Circuit {
     fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
         let mut current_minimum_version = (1, 0, 0);
@@ -797,6 +797,34 @@ impl CircuitDag {
     pub fn get(&self, node: NodeIndex<usize>) -> Option<&Operation> {
         self.graph.node_weight(node.into())
     }
+
+    /// Returns the number of nodes (Operations) in the CircuitDag.
+    ///
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Returns the number of edges (dependencies between Operations) in the CircuitDag.
+    ///
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Returns the Operations in the CircuitDag in topological order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<&Operation>)` - The Operations, each placed after all Operations it depends on.
+    /// * `Err(RoqoqoError::GenericError)` - The CircuitDag contains a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<&Operation>, RoqoqoError> {
+        let order = toposort(&self.graph, None).map_err(|_| RoqoqoError::GenericError {
+            msg: "CircuitDag contains a cycle".to_string(),
+        })?;
+        Ok(order
+            .into_iter()
+            .map(|node| self.graph.node_weight(node).expect("Node index from graph"))
+            .collect())
+    }
 }
 
 /// Creates a new CircuitDag from a given Circuit.
@@ -835,10 +863,10 @@ impl From<CircuitDag> for Circuit {
     fn from(dag: CircuitDag) -> Circuit {
         let mut circuit: Circuit = Circuit::new();
 
-        match toposort(&dag.graph, None) {
-            Ok(order) => {
-                for i in order {
-                    circuit.add_operation(dag.graph.node_weight(i).unwrap().clone());
+        match dag.topological_sort() {
+            Ok(operations) => {
+                for operation in operations {
+                    circuit.add_operation(operation.clone());
                 }
             }
             Err(_) => {
@@ -371,7 +371,7 @@ impl GenericDevice {
         // Check if input matrix has the dimension (3x3)
         let shape = rates.shape();
         if shape == [3, 3] {
-            if qubit > self.number_qubits {
+            if qubit >= self.number_qubits {
                 return Err(RoqoqoError::GenericError {
                     msg: format!(
                         "Qubit {} out of range for device of size {}",
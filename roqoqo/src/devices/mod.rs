@@ -46,6 +46,10 @@ mod all_to_all;
 pub use all_to_all::AllToAllDevice;
 mod square_lattice;
 pub use square_lattice::SquareLatticeDevice;
+mod hexagonal_lattice;
+pub use hexagonal_lattice::HexagonalLatticeDevice;
+mod linear_chain;
+pub use linear_chain::LinearChainDevice;
 // use crate::RoqoqoError;
 // use std::collections::HashMap;
 
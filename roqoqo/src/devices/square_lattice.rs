@@ -213,7 +213,8 @@ impl SquareLatticeDevice {
     ///
     /// # Returns
     ///
-    /// A SquareLatticeDevice with updated gate times.
+    /// * `Ok(())` - The gate time was set for the pair of qubits.
+    /// * `Err(RoqoqoError)` - The gate is not a two-qubit-gate of the device or the qubits are not connected.
     ///
     pub fn set_two_qubit_gate_time(
         &mut self,
@@ -222,6 +223,14 @@ impl SquareLatticeDevice {
         target: usize,
         gate_time: f64,
     ) -> Result<(), RoqoqoError> {
+        if !self.generic_device.two_qubit_gates.contains_key(gate) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Two qubit gate {} is not supported by the SquareLattice",
+                    gate
+                ),
+            });
+        }
         let row_control: i64 = (control / self.number_columns)
             .try_into()
             .expect("Qubit number too large");
@@ -108,6 +108,50 @@ impl MeasureExpectationValues for PauliZProduct {
         bit_registers: HashMap<String, BitOutputRegister>,
         float_registers: HashMap<String, FloatOutputRegister>,
         complex_registers: HashMap<String, ComplexOutputRegister>,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        self.evaluate_with_readout_correction(bit_registers, None)
+    }
+}
+
+impl PauliZProduct {
+    // TODO add optional device later for use with flipped measurement
+    #[allow(unused_variables)]
+    /// Executes the PauliZ product measurement mitigating readout errors.
+    ///
+    /// The inverse of the readout error matrix is applied to the raw measurement results
+    /// of every measured qubit before the Pauli products are evaluated.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The classical bit registers as a HashMap with the register name as key.
+    /// * `float_registers` - The classical float registers as a HashMap with the register name as key.
+    /// * `complex_registers` - The classical complex registers as a HashMap with the register name as key.
+    /// * `readout_error_mitigation` - The readout error matrix of the measured qubits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(HashMap<String, f64>))` - The measurement has been evaluated successfully. The HashMap contains the measured expectation values.
+    /// * `Ok(None)` - The measurement did not fail but is incomplete. A new round of measurements is needed
+    /// * `Err([RoqoqoError::PauliZProductMeasurementError])` - An error occured in PauliZ product measurement.
+    ///
+    pub fn evaluate_with_readout_error_mitigation(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        float_registers: HashMap<String, FloatOutputRegister>,
+        complex_registers: HashMap<String, ComplexOutputRegister>,
+        readout_error_mitigation: &ReadoutErrorMitigationInput,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        self.evaluate_with_readout_correction(
+            bit_registers,
+            Some(readout_error_mitigation.corrected_outcomes()),
+        )
+    }
+
+    // Evaluates the measurement, applying the corrected single-qubit outcomes when given.
+    fn evaluate_with_readout_correction(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        readout_correction: Option<[f64; 2]>,
     ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
         // todo replace with actual input
         let measurement_fidelities = vec![1.0; self.input.number_qubits];
@@ -155,6 +199,15 @@ impl MeasureExpectationValues for PauliZProduct {
                         let mut column = single_shot_pauli_products.column_mut(*index);
                         // Iterate over all single shot readouts for all qubits and construct Pauli Product
                         for (row_index, values) in register.iter().enumerate() {
+                            if let Some(correction) = readout_correction {
+                                // Apply the inverse readout error matrix to every measured qubit.
+                                // For flipped readout the measured state is the inverted logical state.
+                                let sign = if *flip_measurement { -1.0 } else { 1.0 };
+                                column[row_index] = mask_val.iter().fold(1.0, |product, i| {
+                                    product * sign * correction[usize::from(values[*i])]
+                                });
+                                continue;
+                            }
                             // Determine the value of the pauli product with the parity of the number of 0 and 1 measurements of the qubits
                             // false is even parity and true is odd parity
                             let mut parity = false;
@@ -11,9 +11,10 @@
 // limitations under the License.
 
 use crate::CalculatorFloat;
-#[cfg(feature = "json_schema")]
-use crate::Complex64Def;
 use crate::RoqoqoError;
+#[cfg(feature = "json_schema")]
+use crate::{Array2f64Def, Complex64Def};
+use ndarray::Array2;
 use num_complex::Complex64;
 use std::collections::HashMap;
 
@@ -421,8 +422,103 @@ impl CheatedInput {
     }
 }
 
+/// Provides the single-qubit readout error matrix used to mitigate readout errors of a [crate::measurements::PauliZProduct] measurement.
+///
+/// The readout error matrix M is a 2x2 column-stochastic matrix applied to every measured qubit,
+/// where M[i][j] is the probability of obtaining the outcome i when the qubit is in state j.
+/// The inverse of the matrix is applied to the raw measurement results
+/// before the Pauli products are evaluated.
+///
+/// The readout error matrix is kept separate from [PauliZProductInput]
+/// so that the serialized form of PauliZProductInput stays compatible with older versions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadoutErrorMitigationInput {
+    /// The single-qubit readout error matrix.
+    #[cfg_attr(feature = "json_schema", schemars(with = "Array2f64Def"))]
+    readout_error_matrix: Array2<f64>,
+}
+
+impl ReadoutErrorMitigationInput {
+    /// Creates new ReadoutErrorMitigationInput.
+    ///
+    /// # Arguments
+    ///
+    /// * `readout_error_matrix` - The single-qubit readout error matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new ReadoutErrorMitigationInput.
+    /// * `Err([RoqoqoError::PauliZProductMeasurementError])` - The matrix is not an invertible 2x2 column-stochastic matrix.
+    pub fn new(readout_error_matrix: Array2<f64>) -> Result<Self, RoqoqoError> {
+        if readout_error_matrix.shape() != [2, 2] {
+            return Err(RoqoqoError::PauliZProductMeasurementError {
+                msg: format!(
+                    "Readout error matrix must be of shape (2, 2), got {:?}",
+                    readout_error_matrix.shape()
+                ),
+            });
+        }
+        if readout_error_matrix
+            .iter()
+            .any(|p| !(0.0..=1.0).contains(p))
+        {
+            return Err(RoqoqoError::PauliZProductMeasurementError {
+                msg: "Entries of readout error matrix must be probabilities between 0 and 1"
+                    .to_string(),
+            });
+        }
+        for (column_index, column) in readout_error_matrix.columns().into_iter().enumerate() {
+            if (column.sum() - 1.0).abs() > 1e-10 {
+                return Err(RoqoqoError::PauliZProductMeasurementError {
+                    msg: format!(
+                        "Readout error matrix is not column-stochastic, column {} sums to {}",
+                        column_index,
+                        column.sum()
+                    ),
+                });
+            }
+        }
+        let determinant = readout_error_matrix[[0, 0]] * readout_error_matrix[[1, 1]]
+            - readout_error_matrix[[0, 1]] * readout_error_matrix[[1, 0]];
+        if determinant.abs() < 1e-10 {
+            return Err(RoqoqoError::PauliZProductMeasurementError {
+                msg: "Readout error matrix is not invertible".to_string(),
+            });
+        }
+        Ok(Self {
+            readout_error_matrix,
+        })
+    }
+
+    /// Returns the single-qubit readout error matrix.
+    pub fn readout_error_matrix(&self) -> &Array2<f64> {
+        &self.readout_error_matrix
+    }
+
+    /// Returns the corrected contribution of a measured single-qubit outcome to a Pauli product.
+    ///
+    /// For the measured outcome o the corrected contribution is sum_t (M^-1)[t][o] z(t)
+    /// with z(0) = 1 and z(1) = -1.
+    pub(crate) fn corrected_outcomes(&self) -> [f64; 2] {
+        let matrix = &self.readout_error_matrix;
+        let determinant = matrix[[0, 0]] * matrix[[1, 1]] - matrix[[0, 1]] * matrix[[1, 0]];
+        [
+            (matrix[[1, 1]] + matrix[[1, 0]]) / determinant,
+            (-matrix[[0, 1]] - matrix[[0, 0]]) / determinant,
+        ]
+    }
+}
+
 impl crate::operations::SupportedVersion for CheatedInput {}
 
 impl crate::operations::SupportedVersion for CheatedPauliZProductInput {}
 
 impl crate::operations::SupportedVersion for PauliZProductInput {}
+
+impl crate::operations::SupportedVersion for ReadoutErrorMitigationInput {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
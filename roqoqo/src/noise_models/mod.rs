@@ -26,6 +26,8 @@ mod overrotation;
 pub use overrotation::{SingleQubitOverrotationDescription, SingleQubitOverrotationOnGate};
 mod decoherence_on_idle;
 pub use decoherence_on_idle::DecoherenceOnIdleModel;
+mod phase_flip;
+pub use phase_flip::PhaseFlipNoiseModel;
 
 /// Collection of all available noise models in this version of qoqo/roqoqo
 ///
@@ -45,6 +47,8 @@ pub enum NoiseModel {
     SingleQubitOverrotationOnGate(SingleQubitOverrotationOnGate),
     /// Dechoherence on idle qubits model
     DecoherenceOnIdleModel(DecoherenceOnIdleModel),
+    /// Phase flip channel with a single flip probability
+    PhaseFlipNoiseModel(PhaseFlipNoiseModel),
 }
 
 impl From<ContinuousDecoherenceModel> for NoiseModel {
@@ -77,6 +81,12 @@ impl From<DecoherenceOnIdleModel> for NoiseModel {
     }
 }
 
+impl From<PhaseFlipNoiseModel> for NoiseModel {
+    fn from(value: PhaseFlipNoiseModel) -> Self {
+        Self::PhaseFlipNoiseModel(value)
+    }
+}
+
 impl SupportedVersion for NoiseModel {
     fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
         match self {
@@ -95,6 +105,9 @@ impl SupportedVersion for NoiseModel {
             NoiseModel::DecoherenceOnIdleModel(internal) => {
                 internal.minimum_supported_roqoqo_version()
             }
+            NoiseModel::PhaseFlipNoiseModel(internal) => {
+                internal.minimum_supported_roqoqo_version()
+            }
         }
     }
 }
@@ -132,4 +145,10 @@ mod tests {
         let noise_model: NoiseModel = noise.into();
         assert_eq!(noise_model.minimum_supported_roqoqo_version(), (1, 11, 0));
     }
+    #[test]
+    fn minimum_supported_roqoqo_version_phase_flip() {
+        let noise = PhaseFlipNoiseModel::new(0.1).unwrap();
+        let noise_model: NoiseModel = noise.into();
+        assert_eq!(noise_model.minimum_supported_roqoqo_version(), (1, 14, 0));
+    }
 }
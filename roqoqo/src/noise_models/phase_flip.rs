@@ -0,0 +1,165 @@
+// Copyright © 2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::SupportedVersion;
+use crate::operations::PragmaDephasing;
+use crate::RoqoqoError;
+
+/// Noise model representing a single-parameter phase flip channel.
+///
+/// Each qubit undergoes the channel ρ → (1-p) ρ + p Z ρ Z, where p is the flip rate.
+/// The channel is equivalent to a dephasing process, so it can be applied with a [PragmaDephasing]
+/// whose rate is chosen such that the dephasing probability of the PRAGMA equals the flip rate.
+///
+/// # Example
+///
+/// ```rust
+/// use roqoqo::noise_models::PhaseFlipNoiseModel;
+/// use roqoqo::operations::OperatePragmaNoise;
+///
+/// let model = PhaseFlipNoiseModel::new(0.1).unwrap();
+/// let pragma = model.pragma_for_qubit(0, 1.0).unwrap();
+/// let probability: f64 = *pragma.probability().float().unwrap();
+/// assert!((probability - 0.1).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PhaseFlipNoiseModel {
+    /// Probability of a phase flip for each qubit.
+    flip_rate: f64,
+}
+
+impl PhaseFlipNoiseModel {
+    /// Create a new PhaseFlipNoiseModel.
+    ///
+    /// # Arguments
+    ///
+    /// * `flip_rate` - The probability p of a phase flip, between 0 and 0.5.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PhaseFlipNoiseModel)` - The new model
+    /// * `Err(RoqoqoError)` - The flip rate is not in the range 0 to 0.5
+    pub fn new(flip_rate: f64) -> Result<Self, RoqoqoError> {
+        if !(0.0..=0.5).contains(&flip_rate) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Flip rate needs to be between 0 and 0.5, {} is not in range",
+                    flip_rate
+                ),
+            });
+        }
+        Ok(Self { flip_rate })
+    }
+
+    /// Return the probability of a phase flip.
+    ///
+    /// # Returns
+    ///
+    /// `f64` - The flip rate of the model
+    pub fn flip_rate(&self) -> f64 {
+        self.flip_rate
+    }
+
+    /// Return the dephasing rate that produces the phase flip probability in the given time.
+    ///
+    /// The dephasing probability (1 - exp(-2 * gate_time * rate)) / 2 is equal to the flip rate,
+    /// so a flip rate of 0.5 corresponds to an infinite dephasing rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate_time` - The time over which the phase flip channel acts, needs to be positive.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The dephasing rate (in 1/second)
+    /// * `Err(RoqoqoError)` - The gate time is not positive
+    pub fn dephasing_rate(&self, gate_time: f64) -> Result<f64, RoqoqoError> {
+        if gate_time.is_nan() || gate_time <= 0.0 {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Gate time needs to be positive, {} is not", gate_time),
+            });
+        }
+        if self.flip_rate == 0.0 {
+            Ok(0.0)
+        } else {
+            Ok(-(1.0 - 2.0 * self.flip_rate).ln() / (2.0 * gate_time))
+        }
+    }
+
+    /// Return the PragmaDephasing applying the phase flip channel to a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the channel acts on.
+    /// * `gate_time` - The time over which the phase flip channel acts, needs to be positive.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PragmaDephasing)` - The PRAGMA with the dephasing rate matching the flip rate
+    /// * `Err(RoqoqoError)` - The gate time is not positive
+    pub fn pragma_for_qubit(
+        &self,
+        qubit: usize,
+        gate_time: f64,
+    ) -> Result<PragmaDephasing, RoqoqoError> {
+        Ok(PragmaDephasing::new(
+            qubit,
+            gate_time.into(),
+            self.dephasing_rate(gate_time)?.into(),
+        ))
+    }
+}
+
+impl SupportedVersion for PhaseFlipNoiseModel {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::OperatePragmaNoise;
+
+    #[test]
+    fn test_phase_flip_model_new() {
+        let model = PhaseFlipNoiseModel::new(0.2).unwrap();
+        assert_eq!(model.flip_rate(), 0.2);
+        assert!(PhaseFlipNoiseModel::new(-0.1).is_err());
+        assert!(PhaseFlipNoiseModel::new(0.6).is_err());
+    }
+
+    #[test]
+    fn test_phase_flip_model_pragma() {
+        let model = PhaseFlipNoiseModel::new(0.0).unwrap();
+        let pragma = model.pragma_for_qubit(1, 2.0).unwrap();
+        assert_eq!(pragma, PragmaDephasing::new(1, 2.0.into(), 0.0.into()));
+
+        let model = PhaseFlipNoiseModel::new(0.3).unwrap();
+        let probability = *model
+            .pragma_for_qubit(0, 2.0)
+            .unwrap()
+            .probability()
+            .float()
+            .unwrap();
+        assert!((probability - 0.3).abs() < 1e-10);
+
+        let model = PhaseFlipNoiseModel::new(0.5).unwrap();
+        assert_eq!(model.dephasing_rate(1.0).unwrap(), f64::INFINITY);
+        assert!(model.dephasing_rate(0.0).is_err());
+        assert!(model.dephasing_rate(-1.0).is_err());
+        assert!(model.pragma_for_qubit(0, 0.0).is_err());
+        assert!(model.dephasing_rate(f64::NAN).is_err());
+    }
+}
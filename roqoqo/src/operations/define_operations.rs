@@ -32,7 +32,6 @@
 //!
 
 use crate::operations::{Define, InvolveQubits, InvolvedQubits, Operate, RoqoqoError, Substitute};
-#[cfg(feature = "unstable_operation_definition")]
 use crate::{operations::OperateMultiQubit, Circuit};
 use std::collections::HashSet;
 
@@ -276,7 +275,6 @@ impl InvolveQubits for InputBit {
 
 /// GateDefinition is the Definition of a new custom gate defined by a circuit that can be used with the CallDefinedGate Operation.
 ///
-#[cfg(feature = "unstable_operation_definition")]
 #[derive(
     Debug,
     Clone,
@@ -299,21 +297,17 @@ pub struct GateDefinition {
     free_parameters: Vec<String>,
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 impl super::ImplementedIn1point13 for GateDefinition {}
 
-#[cfg(feature = "unstable_operation_definition")]
 impl SupportedVersion for GateDefinition {
     fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
         (1, 13, 0)
     }
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[allow(non_upper_case_globals)]
 const TAGS_GateDefinition: &[&str; 3] = &["Operation", "Definition", "GateDefinition"];
 
-#[cfg(feature = "unstable_operation_definition")]
 // Implementing the InvolveQubits trait for GateDefinition.
 impl InvolveQubits for GateDefinition {
     /// Lists all involved Qubits (here, none).
@@ -352,6 +352,187 @@ impl InvolveQubits for PragmaGetPauliProduct {
     }
 }
 
+/// This PRAGMA measurement operation returns the expectation values of several Pauli products.
+///
+/// The batched version of [PragmaGetPauliProduct]: the expectation value of the i-th Pauli product in
+/// `qubit_paulis_list` is written to the i-th entry of the readout register. All Pauli products are evaluated
+/// on a clone of the quantum register after applying the same measurement preparation circuit.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGetPauliProducts {
+    /// The list of HashMaps of the pauli matrix to apply to each qubit in the form {qubit: pauli}. Allowed values to be provided for 'pauli' are: `0` = identity, `1` = PauliX, `2` = PauliY, `3` = PauliZ.
+    qubit_paulis_list: Vec<HashMap<usize, usize>>,
+    /// The name of the classical readout register.
+    readout: String,
+    /// The measurement preparation Circuit, applied on a copy of the register before measurement.
+    circuit: Circuit,
+}
+
+impl SupportedVersion for PragmaGetPauliProducts {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for PragmaGetPauliProducts {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGetPauliProducts: &[&str; 4] = &[
+    "Operation",
+    "Measurement",
+    "PragmaOperation",
+    "PragmaGetPauliProducts",
+];
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaGetPauliProducts {
+    /// Remaps qubits in operations in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+
+        let new_qubit_paulis_list: Vec<HashMap<usize, usize>> = self
+            .qubit_paulis_list
+            .iter()
+            .map(|qubit_paulis| {
+                qubit_paulis
+                    .iter()
+                    .map(|(key, val)| (*mapping.get(key).unwrap_or(key), *val))
+                    .collect()
+            })
+            .collect();
+
+        let new_circuit = self.circuit.remap_qubits(mapping)?;
+        Ok(PragmaGetPauliProducts::new(
+            new_qubit_paulis_list,
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
+        Ok(PragmaGetPauliProducts::new(
+            self.qubit_paulis_list.clone(),
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for PragmaGetPauliProducts.
+impl InvolveQubits for PragmaGetPauliProducts {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut new_hash_set: HashSet<usize> = HashSet::new();
+        for qubit_paulis in self.qubit_paulis_list.iter() {
+            for qubit in qubit_paulis.keys() {
+                new_hash_set.insert(*qubit);
+            }
+        }
+        if let InvolvedQubits::Set(tmp_set) = &self.circuit.involved_qubits() {
+            for qubit in tmp_set {
+                new_hash_set.insert(*qubit);
+            }
+        }
+        InvolvedQubits::Set(new_hash_set)
+    }
+
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::All(self.readout.clone())
+    }
+}
+
+/// This PRAGMA measurement operation returns the reduced density matrix of a subset of qubits.
+///
+/// The qubits not in `qubits` are traced out. The order of `qubits` determines the order
+/// of the qubits in the reduced density matrix.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGetReducedDensityMatrix {
+    /// The name of the classical readout register.
+    readout: String,
+    /// The qubits of the reduced density matrix.
+    qubits: Vec<usize>,
+    /// The measurement preparation Circuit, applied on a copy of the register before measurement (None if not defined, Some(Circuit) otherwise).
+    circuit: Option<Circuit>,
+}
+
+impl SupportedVersion for PragmaGetReducedDensityMatrix {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for PragmaGetReducedDensityMatrix {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGetReducedDensityMatrix: &[&str; 4] = &[
+    "Operation",
+    "Measurement",
+    "PragmaOperation",
+    "PragmaGetReducedDensityMatrix",
+];
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaGetReducedDensityMatrix {
+    /// Remaps qubits in operations in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_qubits: Vec<usize> = self
+            .qubits
+            .iter()
+            .map(|qubit| *mapping.get(qubit).unwrap_or(qubit))
+            .collect();
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.remap_qubits(mapping)?),
+            _ => None,
+        };
+        Ok(PragmaGetReducedDensityMatrix::new(
+            self.readout.clone(),
+            new_qubits,
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.substitute_parameters(calculator)?),
+            _ => None,
+        };
+        Ok(PragmaGetReducedDensityMatrix::new(
+            self.readout.clone(),
+            self.qubits.clone(),
+            new_circuit,
+        ))
+    }
+}
+
+// Implements the InvolveQubits trait for PragmaGetReducedDensityMatrix.
+impl InvolveQubits for PragmaGetReducedDensityMatrix {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut new_hash_set: HashSet<usize> = self.qubits.iter().cloned().collect();
+        if let Some(circuit) = self.circuit.as_ref() {
+            match circuit.involved_qubits() {
+                InvolvedQubits::Set(tmp_set) => new_hash_set.extend(tmp_set),
+                InvolvedQubits::All => return InvolvedQubits::All,
+                InvolvedQubits::None => (),
+            }
+        }
+        InvolvedQubits::Set(new_hash_set)
+    }
+
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::All(self.readout.clone())
+    }
+}
+
 /// This PRAGMA measurement operation returns a measurement record for N repeated measurements.
 ///
 #[derive(
@@ -847,6 +847,9 @@ pub trait ImplementedIn1point11: Operate {}
 
 /// Marker trait to show that some operation has been implemented in roqoqo 1.13.0
 pub trait ImplementedIn1point13: Operate {}
+
+/// Marker trait to show that some operation has been implemented in roqoqo 1.14.0
+pub trait ImplementedIn1point14: Operate {}
 #[cfg(feature = "dynamic")]
 /// A wrapper for Operate trait objects.
 ///
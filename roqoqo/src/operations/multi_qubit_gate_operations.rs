@@ -161,10 +161,278 @@ impl OperateMultiQubitGate for MultiQubitZZ {
     }
 }
 
+/// The multi qubit Pauli-X-Product gate.
+///
+/// The gate applies the rotation under the product of Pauli X operators on multiple qubits.
+/// In mathematical terms the gate applies exp(-i * θ/2 * X_i0 * X_i1 * ... * X_in).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateMultiQubit,
+    roqoqo_derive::Rotate,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct MultiQubitXX {
+    /// The qubits involved in the multi qubit Pauli-X-Product gate.
+    qubits: Vec<usize>,
+    /// The angle of the multi qubit Pauli-X-Product gate.
+    theta: CalculatorFloat,
+}
+
+impl super::ImplementedIn1point14 for MultiQubitXX {}
+
+impl SupportedVersion for MultiQubitXX {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_MultiQubitXX: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "MultiQubitGateOperation",
+    "MultiQubitXX",
+];
+
+impl OperateGate for MultiQubitXX {
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let dim = 2_usize.pow(self.qubits.len() as u32);
+        let mut array: Array2<Complex64> = Array2::zeros((dim, dim));
+        let cos: Complex64 = Complex64::new((self.theta.float()? / 2.0).cos(), 0.0);
+        let sin: Complex64 = Complex64::new(0.0, -(self.theta.float()? / 2.0).sin());
+        // X_i0 * X_i1 * ... * X_in flips all qubits and maps basis state i to dim - i - 1
+        for i in 0..dim {
+            array[(i, i)] = cos;
+            array[(i, dim - i - 1)] = sin;
+        }
+        Ok(array)
+    }
+}
+
+impl OperateMultiQubitGate for MultiQubitXX {
+    fn circuit(&self) -> Circuit {
+        let mut circuit = Circuit::new();
+        for q in self.qubits.iter() {
+            circuit += operations::Hadamard::new(*q);
+        }
+        for pair in self.qubits.windows(2) {
+            circuit += operations::CNOT::new(pair[0], pair[1]);
+        }
+        if let Some(last) = self.qubits.last() {
+            circuit += operations::RotateZ::new(*last, self.theta.clone());
+        }
+        for pair in self.qubits.windows(2).rev() {
+            circuit += operations::CNOT::new(pair[0], pair[1]);
+        }
+        for q in self.qubits.iter() {
+            circuit += operations::Hadamard::new(*q);
+        }
+        circuit
+    }
+}
+
+/// The quantum Fourier transform (QFT) on multiple qubits.
+///
+/// The gate applies the discrete Fourier transform to the amplitudes of the qubits,
+/// with the first qubit in `qubits` being the most significant one.
+/// When `inverse` is set the inverse transform is applied.
+/// When `with_swaps` is not set the final SWAP network reversing the order of the qubits is omitted.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateMultiQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct QFT {
+    /// The qubits involved in the QFT.
+    qubits: Vec<usize>,
+    /// Whether the inverse QFT is applied.
+    inverse: bool,
+    /// Whether the SWAP network reversing the order of the qubits is included.
+    with_swaps: bool,
+}
+
+impl super::ImplementedIn1point14 for QFT {}
+
+impl SupportedVersion for QFT {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_QFT: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "MultiQubitGateOperation",
+    "QFT",
+];
+
+impl OperateGate for QFT {
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let number_qubits = self.qubits.len();
+        let dim = 2_usize.pow(number_qubits as u32);
+        let norm = 1.0 / (dim as f64).sqrt();
+        let reverse = |index: usize| -> usize {
+            (0..number_qubits).fold(0, |reversed, bit| {
+                reversed | (((index >> bit) & 1) << (number_qubits - 1 - bit))
+            })
+        };
+        let mut array: Array2<Complex64> = Array2::zeros((dim, dim));
+        for row in 0..dim {
+            for column in 0..dim {
+                // Without the SWAP network the output (QFT) or input (inverse QFT) is bit-reversed
+                let (j, k) = match (self.inverse, self.with_swaps) {
+                    (false, false) => (reverse(row), column),
+                    (true, false) => (row, reverse(column)),
+                    _ => (row, column),
+                };
+                let sign = if self.inverse { -1.0 } else { 1.0 };
+                let phase = sign * 2.0 * std::f64::consts::PI * ((j * k) % dim) as f64 / dim as f64;
+                array[(row, column)] = Complex64::from_polar(norm, phase);
+            }
+        }
+        Ok(array)
+    }
+}
+
+impl OperateMultiQubitGate for QFT {
+    fn circuit(&self) -> Circuit {
+        let number_qubits = self.qubits.len();
+        let mut circuit = Circuit::new();
+        let mut swaps = Circuit::new();
+        if self.with_swaps {
+            for (first, last) in self
+                .qubits
+                .iter()
+                .zip(self.qubits.iter().rev())
+                .take(number_qubits / 2)
+            {
+                swaps += operations::SWAP::new(*first, *last);
+            }
+        }
+        if self.inverse {
+            circuit += swaps;
+            for target in (0..number_qubits).rev() {
+                for control in (target + 1..number_qubits).rev() {
+                    circuit += operations::ControlledPhaseShift::new(
+                        self.qubits[control],
+                        self.qubits[target],
+                        CalculatorFloat::from(
+                            -std::f64::consts::PI / 2_f64.powi((control - target) as i32),
+                        ),
+                    );
+                }
+                circuit += operations::Hadamard::new(self.qubits[target]);
+            }
+        } else {
+            for target in 0..number_qubits {
+                circuit += operations::Hadamard::new(self.qubits[target]);
+                for control in target + 1..number_qubits {
+                    circuit += operations::ControlledPhaseShift::new(
+                        self.qubits[control],
+                        self.qubits[target],
+                        CalculatorFloat::from(
+                            std::f64::consts::PI / 2_f64.powi((control - target) as i32),
+                        ),
+                    );
+                }
+            }
+            circuit += swaps;
+        }
+        circuit
+    }
+}
+
+/// The multi qubit CNOT gate (fan-out CNOT).
+///
+/// The first qubit in `qubits` is the control qubit, all other qubits are target qubits.
+/// When the control qubit is in state 1 a PauliX gate is applied to every target qubit.
+/// The gate needs at least two qubits.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateMultiQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct MultiQubitCNOT {
+    /// The qubits involved in the multi qubit CNOT gate, the first qubit is the control qubit.
+    qubits: Vec<usize>,
+}
+
+impl super::ImplementedIn1point14 for MultiQubitCNOT {}
+
+impl SupportedVersion for MultiQubitCNOT {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_MultiQubitCNOT: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "MultiQubitGateOperation",
+    "MultiQubitCNOT",
+];
+
+impl OperateGate for MultiQubitCNOT {
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        if self.qubits.len() < 2 {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "MultiQubitCNOT needs at least two qubits, got {}",
+                    self.qubits.len()
+                ),
+            });
+        }
+        let dim = 2_usize.pow(self.qubits.len() as u32);
+        // The control qubit is the most significant qubit, flipping all targets
+        // corresponds to flipping all lower bits of the basis state index
+        let target_mask = dim / 2 - 1;
+        let mut array: Array2<Complex64> = Array2::zeros((dim, dim));
+        for i in 0..dim {
+            let j = if i >= dim / 2 { i ^ target_mask } else { i };
+            array[(j, i)] = Complex64::new(1.0, 0.0);
+        }
+        Ok(array)
+    }
+}
+
+impl OperateMultiQubitGate for MultiQubitCNOT {
+    fn circuit(&self) -> Circuit {
+        let mut circuit = Circuit::new();
+        if let Some((control, targets)) = self.qubits.split_first() {
+            for target in targets {
+                circuit += operations::CNOT::new(*control, *target);
+            }
+        }
+        circuit
+    }
+}
+
 /// The gate to be replaced by a gate defined with GateDefinition gate.
 ///
 /// The gate applies a gate previously defined by GateDefinition with the name gate_name.
-#[cfg(feature = "unstable_operation_definition")]
 #[allow(clippy::upper_case_acronyms)]
 #[derive(
     Debug,
@@ -187,7 +455,6 @@ pub struct CallDefinedGate {
     free_parameters: Vec<CalculatorFloat>,
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 impl Substitute for CallDefinedGate {
     fn substitute_parameters(
         &self,
@@ -231,17 +498,14 @@ impl Substitute for CallDefinedGate {
     }
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 impl super::ImplementedIn1point13 for CallDefinedGate {}
 
-#[cfg(feature = "unstable_operation_definition")]
 impl SupportedVersion for CallDefinedGate {
     fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
         (1, 13, 0)
     }
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[allow(non_upper_case_globals)]
 const TAGS_CallDefinedGate: &[&str; 3] =
     &["Operation", "MultiQubitGateOperation", "CallDefinedGate"];
@@ -73,6 +73,50 @@ impl InvolveQubits for PragmaSetNumberOfMeasurements {
     }
 }
 
+/// This PRAGMA Operation sets the number of repetitions of stochastic simulations of the circuit.
+///
+/// This is used for simulator backends that sample stochastic processes, for example when
+/// simulating noise with quantum trajectories, and need to know how often the simulation is repeated.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperatePragma,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSimulationRepetitions {
+    /// The number of repetitions of the simulation.
+    repetitions: usize,
+}
+
+impl SupportedVersion for PragmaSimulationRepetitions {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for PragmaSimulationRepetitions {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSimulationRepetitions: &[&str; 3] = &[
+    "Operation",
+    "PragmaOperation",
+    "PragmaSimulationRepetitions",
+];
+
+// Implementing the InvolveQubits trait for PragmaSimulationRepetitions.
+impl InvolveQubits for PragmaSimulationRepetitions {
+    /// Lists all involved qubits (here, none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+}
+
 /// This PRAGMA Operation sets the statevector of a quantum register.
 ///
 /// The Circuit() module automatically initializes the qubits in the |0> state, so this PRAGMA
@@ -410,6 +454,65 @@ const TAGS_PragmaSleep: &[&str; 4] = &[
     "PragmaSleep",
 ];
 
+/// This PRAGMA Operation makes the quantum hardware wait a qubit-specific amount of time.
+///
+/// In contrast to [PragmaSleep] every qubit can be assigned its own sleep time,
+/// for example to account for the different decoherence times of the qubits.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaQubitSleep {
+    /// The time each qubit sleeps in seconds.
+    sleep_times: HashMap<usize, CalculatorFloat>,
+}
+
+impl SupportedVersion for PragmaQubitSleep {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for PragmaQubitSleep {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaQubitSleep: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaQubitSleep"];
+
+// Implementing the InvolveQubits trait for PragmaQubitSleep.
+impl InvolveQubits for PragmaQubitSleep {
+    /// Lists all involved qubits (here, the qubits with a sleep time).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::Set(self.sleep_times.keys().cloned().collect())
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaQubitSleep {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let sleep_times: HashMap<usize, CalculatorFloat> = self
+            .sleep_times
+            .iter()
+            .map(|(qubit, time)| (*mapping.get(qubit).unwrap_or(qubit), time.clone()))
+            .collect();
+        Ok(PragmaQubitSleep::new(sleep_times))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let mut sleep_times: HashMap<usize, CalculatorFloat> =
+            HashMap::with_capacity(self.sleep_times.len());
+        for (qubit, time) in self.sleep_times.iter() {
+            sleep_times.insert(
+                *qubit,
+                CalculatorFloat::from(calculator.parse_get(time.clone())?),
+            );
+        }
+        Ok(PragmaQubitSleep::new(sleep_times))
+    }
+}
+
 /// This PRAGMA Operation resets the chosen qubit to the zero state.
 ///
 #[derive(
@@ -1177,6 +1280,90 @@ impl Substitute for PragmaControlledCircuit {
     }
 }
 
+/// A circuit controlled by multiple qubits.
+///
+/// The circuit is applied when all controlling qubits are in state 1.
+/// Note that this is a unitary operation (for example a Toffoli gate
+/// is equivalent to a PragmaMultiControlledCircuit([0, 1], [PauliX(2)])) but it cannot be represented
+/// by a unitary operation in qoqo for arbitrary circuits.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaMultiControlledCircuit {
+    /// The qubits controlling if the circuit is applied. Circuit is applied if all qubits are in state 1.
+    controlling_qubits: Vec<usize>,
+    /// The circuit executed if the condition is met.
+    circuit: Circuit,
+}
+
+impl SupportedVersion for PragmaMultiControlledCircuit {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        if self.circuit.minimum_supported_roqoqo_version() > (1, 14, 0) {
+            return self.circuit.minimum_supported_roqoqo_version();
+        }
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for PragmaMultiControlledCircuit {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaMultiControlledCircuit: &[&str; 3] = &[
+    "Operation",
+    "PragmaOperation",
+    "PragmaMultiControlledCircuit",
+];
+
+// Implementing the InvolveQubits trait for PragmaMultiControlledCircuit.
+impl InvolveQubits for PragmaMultiControlledCircuit {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        match self.circuit.involved_qubits() {
+            InvolvedQubits::All => InvolvedQubits::All,
+            InvolvedQubits::None => {
+                InvolvedQubits::Set(self.controlling_qubits.iter().cloned().collect())
+            }
+            InvolvedQubits::Set(s) => {
+                let mut set = s;
+                set.extend(self.controlling_qubits.iter().cloned());
+                InvolvedQubits::Set(set)
+            }
+        }
+    }
+
+    fn involved_classical(&self) -> super::InvolvedClassical {
+        super::InvolvedClassical::None
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaMultiControlledCircuit {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_circuit = self.circuit.remap_qubits(mapping)?;
+        let new_controlling_qubits: Vec<usize> = self
+            .controlling_qubits
+            .iter()
+            .map(|qubit| *mapping.get(qubit).unwrap_or(qubit))
+            .collect();
+        Ok(PragmaMultiControlledCircuit::new(
+            new_controlling_qubits,
+            new_circuit,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
+        Ok(PragmaMultiControlledCircuit::new(
+            self.controlling_qubits.clone(),
+            new_circuit,
+        ))
+    }
+}
+
 /// A wrapper around backend specific PRAGMA operations capable of changing a device.
 ///
 /// This PRAGMA is a thin wrapper around device specific operations that can change
@@ -1413,3 +1600,140 @@ impl InvolveQubits for PragmaAnnotatedOp {
         self.operation.involved_qubits()
     }
 }
+
+/// This PRAGMA Operation sets the state of a quantum register to a stabilizer state.
+///
+/// The stabilizer state is defined by a list of stabilizer generators, each given as a
+/// Pauli string over the characters `I`, `X`, `Y` and `Z` (e.g. "XZZX"). The k-th character of
+/// a generator acts on qubit k. For an n-qubit state, n mutually commuting generators are required.
+///
+/// # Example
+///
+/// For instance, to initialize the | Φ+ > Bell state, we pass the generators "XX" and "ZZ"
+/// to the PragmaSetStabilizerState operation.
+///
+/// ```
+/// use roqoqo::operations::PragmaSetStabilizerState;
+///
+/// let pragma = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+/// ```
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperatePragma,
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSetStabilizerState {
+    /// The stabilizer generators of the state, given as Pauli strings.
+    stabilizer_generators: Vec<String>,
+}
+
+impl super::ImplementedIn1point14 for PragmaSetStabilizerState {}
+
+impl SupportedVersion for PragmaSetStabilizerState {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSetStabilizerState: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaSetStabilizerState"];
+
+// Implementing the InvolveQubits trait for PragmaSetStabilizerState.
+impl InvolveQubits for PragmaSetStabilizerState {
+    /// Lists all involved qubits (here, all).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+/// This PRAGMA Operation applies an arbitrary two-qubit unitary gate.
+///
+/// The gate is defined by its 4x4 unitary matrix in the basis |qubit_1 qubit_0> with
+/// qubit_0 as the least significant qubit. It can be used for native hardware gates that
+/// do not fit into the existing gate operations.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PragmaCustomTwoQubitGate {
+    /// The first qubit the gate acts on.
+    qubit_0: usize,
+    /// The second qubit the gate acts on.
+    qubit_1: usize,
+    /// The unitary matrix of the gate.
+    unitary: Array2<Complex64>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for PragmaCustomTwoQubitGate {
+    fn schema_name() -> String {
+        "PragmaCustomTwoQubitGate".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <SchemaHelperPragmaCustomTwoQubitGate>::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "json_schema")]
+#[derive(schemars::JsonSchema)]
+#[allow(dead_code)]
+struct SchemaHelperPragmaCustomTwoQubitGate {
+    /// The first qubit the gate acts on.
+    qubit_0: usize,
+    /// The second qubit the gate acts on.
+    qubit_1: usize,
+    /// The unitary matrix of the gate.
+    #[serde(with = "Array2C64Def")]
+    unitary: Array2<Complex64>,
+}
+
+impl super::ImplementedIn1point14 for PragmaCustomTwoQubitGate {}
+
+impl SupportedVersion for PragmaCustomTwoQubitGate {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaCustomTwoQubitGate: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaCustomTwoQubitGate"];
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaCustomTwoQubitGate {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        let new_qubit_0 = *mapping.get(&self.qubit_0).unwrap_or(&self.qubit_0);
+        let new_qubit_1 = *mapping.get(&self.qubit_1).unwrap_or(&self.qubit_1);
+        Ok(PragmaCustomTwoQubitGate::new(
+            new_qubit_0,
+            new_qubit_1,
+            self.unitary.clone(),
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, _calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaCustomTwoQubitGate.
+impl InvolveQubits for PragmaCustomTwoQubitGate {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut new_hash_set: HashSet<usize> = HashSet::new();
+        new_hash_set.insert(self.qubit_0);
+        new_hash_set.insert(self.qubit_1);
+        InvolvedQubits::Set(new_hash_set)
+    }
+}
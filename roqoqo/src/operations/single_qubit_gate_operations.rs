@@ -12,7 +12,8 @@
 
 use ndarray::{array, Array2};
 use num_complex::Complex64;
-use qoqo_calculator::CalculatorFloat;
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::f64::consts::PI;
 
@@ -1860,3 +1861,247 @@ impl OperateSingleQubitGate for Identity {
         CalculatorFloat::from(0.0)
     }
 }
+
+/// Implements a rotation around an arbitrary axis given in cartesian coordinates.
+///
+/// The unitary of the gate is exp(-i * θ/2 * (x * X + y * Y + z * Z)), where (x, y, z) is the
+/// rotation axis normalized to unit length: x² + y² + z² = 1.
+/// The axis given on construction does not need to be normalized, it is normalized once
+/// when the operation is created. A rotation axis of zero length is rejected.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::InvolveQubits, roqoqo_derive::Rotate)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct RotateAxis {
+    /// The qubit the unitary gate is applied to.
+    qubit: usize,
+    /// The angle θ of the rotation, in the interval from 0 to 2 * 2π.
+    theta: CalculatorFloat,
+    /// The x component of the normalized rotation axis.
+    axis_x: CalculatorFloat,
+    /// The y component of the normalized rotation axis.
+    axis_y: CalculatorFloat,
+    /// The z component of the normalized rotation axis.
+    axis_z: CalculatorFloat,
+}
+
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for RotateAxis {
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_RotateAxis
+    }
+    fn hqslang(&self) -> &'static str {
+        "RotateAxis"
+    }
+    fn is_parametrized(&self) -> bool {
+        !self.theta.is_float()
+            || !self.axis_x.is_float()
+            || !self.axis_y.is_float()
+            || !self.axis_z.is_float()
+    }
+}
+
+impl OperateSingleQubit for RotateAxis {
+    fn qubit(&self) -> &usize {
+        &self.qubit
+    }
+}
+
+impl SupportedVersion for RotateAxis {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for RotateAxis {}
+
+impl RotateAxis {
+    /// Creates a new instance of `RotateAxis`.
+    ///
+    /// The rotation axis is normalized to unit length. Symbolic axis components are divided by
+    /// the symbolic length of the axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the unitary gate is applied to.
+    /// * `theta` - The angle θ of the rotation.
+    /// * `axis_x` - The x component of the rotation axis.
+    /// * `axis_y` - The y component of the rotation axis.
+    /// * `axis_z` - The z component of the rotation axis.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RotateAxis)` - The operation with the normalized rotation axis.
+    /// * `Err(RoqoqoError)` - The rotation axis has zero length.
+    pub fn new(
+        qubit: usize,
+        theta: CalculatorFloat,
+        axis_x: CalculatorFloat,
+        axis_y: CalculatorFloat,
+        axis_z: CalculatorFloat,
+    ) -> Result<Self, RoqoqoError> {
+        let norm = (axis_x.clone() * axis_x.clone()
+            + axis_y.clone() * axis_y.clone()
+            + axis_z.clone() * axis_z.clone())
+        .sqrt();
+        if let CalculatorFloat::Float(norm_float) = norm {
+            if norm_float == 0.0 || !norm_float.is_finite() {
+                return Err(RoqoqoError::GenericError {
+                    msg: "The rotation axis of RotateAxis must not have zero length".to_string(),
+                });
+            }
+        }
+        Ok(Self {
+            qubit,
+            theta,
+            axis_x: axis_x / norm.clone(),
+            axis_y: axis_y / norm.clone(),
+            axis_z: axis_z / norm,
+        })
+    }
+
+    /// Returns the value of the field `qubit`.
+    #[inline]
+    pub fn qubit(&self) -> &usize {
+        &self.qubit
+    }
+
+    /// Returns the value of the field `theta`.
+    #[inline]
+    pub fn theta(&self) -> &CalculatorFloat {
+        &self.theta
+    }
+
+    /// Returns the x component of the normalized rotation axis.
+    #[inline]
+    pub fn axis_x(&self) -> &CalculatorFloat {
+        &self.axis_x
+    }
+
+    /// Returns the y component of the normalized rotation axis.
+    #[inline]
+    pub fn axis_y(&self) -> &CalculatorFloat {
+        &self.axis_y
+    }
+
+    /// Returns the z component of the normalized rotation axis.
+    #[inline]
+    pub fn axis_z(&self) -> &CalculatorFloat {
+        &self.axis_z
+    }
+}
+
+/// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for RotateAxis {
+    /// Substitutes symbolic parameters in clone of the operation.
+    ///
+    /// The substituted rotation axis is validated and normalized again.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        RotateAxis::new(
+            self.qubit,
+            CalculatorFloat::from(calculator.parse_get(self.theta.clone())?),
+            CalculatorFloat::from(calculator.parse_get(self.axis_x.clone())?),
+            CalculatorFloat::from(calculator.parse_get(self.axis_y.clone())?),
+            CalculatorFloat::from(calculator.parse_get(self.axis_z.clone())?),
+        )
+    }
+
+    /// Remaps the qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        crate::operations::check_valid_mapping(mapping)?;
+        Ok(Self {
+            qubit: *mapping.get(&self.qubit).unwrap_or(&self.qubit),
+            ..self.clone()
+        })
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_RotateAxis: &[&str; 5] = &[
+    "Operation",
+    "GateOperation",
+    "SingleQubitGateOperation",
+    "Rotation",
+    "RotateAxis",
+];
+
+/// Trait for all operations acting with a unitary gate on a set of qubits.
+impl OperateGate for RotateAxis {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed or the rotation axis has zero length.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let c: f64 = (f64::try_from(self.theta.clone())? / 2.0).cos();
+        let s: f64 = (f64::try_from(self.theta.clone())? / 2.0).sin();
+        let x: f64 = f64::try_from(self.axis_x.clone())?;
+        let y: f64 = f64::try_from(self.axis_y.clone())?;
+        let z: f64 = f64::try_from(self.axis_z.clone())?;
+        let norm: f64 = (x.powf(2.0) + y.powf(2.0) + z.powf(2.0)).sqrt();
+        if norm == 0.0 {
+            return Err(RoqoqoError::GenericError {
+                msg: "The rotation axis of RotateAxis must not have zero length".to_string(),
+            });
+        }
+        let (vx, vy, vz) = (x / norm, y / norm, z / norm);
+        Ok(array![
+            [
+                Complex64::new(c, -1.0 * s * vz),
+                Complex64::new(-1.0 * s * vy, -1.0 * s * vx)
+            ],
+            [
+                Complex64::new(s * vy, -1.0 * s * vx),
+                Complex64::new(c, s * vz)
+            ]
+        ])
+    }
+}
+
+/// Trait for unitary operations acting on exactly one qubit.
+impl OperateSingleQubitGate for RotateAxis {
+    /// Returns the alpha_r parameter of the operation.
+    ///
+    /// # Returns
+    ///
+    /// * `alpha_r` - The real part Re(α) of the on-diagonal elements of the single-qubit unitary matrix.
+    fn alpha_r(&self) -> CalculatorFloat {
+        (self.theta.clone() / 2.0).cos()
+    }
+    /// Returns the alpha_i parameter of the operation.
+    ///
+    /// # Returns
+    ///
+    /// * `alpha_i` - The imaginary part Im(α) of the on-diagonal elements of the single-qubit unitary matrix.
+    fn alpha_i(&self) -> CalculatorFloat {
+        let s = (self.theta.clone() / 2.0).sin();
+        s * self.axis_z.clone() * (-1.0)
+    }
+    /// Returns the beta_r parameter of the operation.
+    ///
+    /// # Returns
+    ///
+    /// * `beta_r` - The real part Re(β) of the off-diagonal elements of the single-qubit unitary matrix.
+    fn beta_r(&self) -> CalculatorFloat {
+        let s = (self.theta.clone() / 2.0).sin();
+        s * self.axis_y.clone()
+    }
+    /// Returns the beta_i parameter of the operation.
+    ///
+    /// # Returns
+    ///
+    /// * `beta_i` - The imaginary part Im(β) of the off-diagonal elements of the single-qubit unitary matrix.
+    fn beta_i(&self) -> CalculatorFloat {
+        let s = (self.theta.clone() / 2.0).sin();
+        s * self.axis_x.clone() * (-1.0)
+    }
+    /// Returns global_phase parameter of the operation.
+    ///
+    /// # Returns
+    ///
+    /// * `global_phase` - The global phase φ of the single-qubit unitary.
+    fn global_phase(&self) -> CalculatorFloat {
+        CalculatorFloat::from(0.0)
+    }
+}
@@ -2614,3 +2614,747 @@ impl OperateTwoQubitGate for EchoCrossResonance {
         }
     }
 }
+
+/// The two-qubit rotation gate under the XX Ising coupling.
+///
+/// Applies the unitary exp(-i * θ/2 * X_control X_target) to two qubits `control` and `target`.
+/// The unitary is identical to the one of VariableMSXX.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+    roqoqo_derive::Rotate,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct RXX {
+    /// The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    target: usize,
+    /// The rotation angle θ.
+    theta: CalculatorFloat,
+}
+
+impl SupportedVersion for RXX {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for RXX {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_RXX: &[&str; 5] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "Rotation",
+    "RXX",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for RXX {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let c: f64 = (f64::try_from(self.theta.clone())? / 2.0).cos();
+        let s: f64 = (f64::try_from(self.theta.clone())? / 2.0).sin();
+        Ok(array![
+            [
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, (-1.0) * s)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, 0.0)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for RXX {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        KakDecomposition {
+            global_phase: CalculatorFloat::ZERO,
+            k_vector: [
+                self.theta.clone() * (-1.0 / 2.0),
+                CalculatorFloat::ZERO,
+                CalculatorFloat::ZERO,
+            ],
+            circuit_before: None,
+            circuit_after: None,
+        }
+    }
+}
+
+/// The two-qubit rotation gate under the YY Ising coupling.
+///
+/// Applies the unitary exp(-i * θ/2 * Y_control Y_target) to two qubits `control` and `target`.
+/// The gate is diagonal in the basis of the Pauli Y eigenstates.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+    roqoqo_derive::Rotate,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct RYY {
+    /// The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    target: usize,
+    /// The rotation angle θ.
+    theta: CalculatorFloat,
+}
+
+impl SupportedVersion for RYY {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for RYY {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_RYY: &[&str; 5] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "Rotation",
+    "RYY",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for RYY {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let c: f64 = (f64::try_from(self.theta.clone())? / 2.0).cos();
+        let s: f64 = (f64::try_from(self.theta.clone())? / 2.0).sin();
+        Ok(array![
+            [
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, s)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, s),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, 0.0)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for RYY {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        KakDecomposition {
+            global_phase: CalculatorFloat::ZERO,
+            k_vector: [
+                CalculatorFloat::ZERO,
+                self.theta.clone() * (-1.0 / 2.0),
+                CalculatorFloat::ZERO,
+            ],
+            circuit_before: None,
+            circuit_after: None,
+        }
+    }
+}
+
+/// The two-qubit rotation gate under the ZZ Ising coupling.
+///
+/// Applies the unitary exp(-i * θ/2 * Z_control Z_target) to two qubits `control` and `target`.
+/// The gate is diagonal in the computational basis.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+    roqoqo_derive::Rotate,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct RZZ {
+    /// The index of the most significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. The gate is symmetric under the exchange of qubits.
+    target: usize,
+    /// The rotation angle θ.
+    theta: CalculatorFloat,
+}
+
+impl SupportedVersion for RZZ {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for RZZ {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_RZZ: &[&str; 5] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "Rotation",
+    "RZZ",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for RZZ {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let c: f64 = (f64::try_from(self.theta.clone())? / 2.0).cos();
+        let s: f64 = (f64::try_from(self.theta.clone())? / 2.0).sin();
+        Ok(array![
+            [
+                Complex64::new(c, (-1.0) * s),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, s),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, s),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, (-1.0) * s)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for RZZ {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        KakDecomposition {
+            global_phase: CalculatorFloat::ZERO,
+            k_vector: [
+                CalculatorFloat::ZERO,
+                CalculatorFloat::ZERO,
+                self.theta.clone() * (-1.0 / 2.0),
+            ],
+            circuit_before: None,
+            circuit_after: None,
+        }
+    }
+}
+
+/// The controlled Hadamard gate.
+///
+/// Applies a Hadamard gate to the `target` qubit depending on the state of the `control` qubit.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct ControlledHadamard {
+    /// The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the Hadamard gate on the target qubit.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. Here, the qubit the Hadamard gate is applied to.
+    target: usize,
+}
+
+impl SupportedVersion for ControlledHadamard {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for ControlledHadamard {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_ControlledHadamard: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "ControlledHadamard",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for ControlledHadamard {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed (here, not possible).
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let f: f64 = 1.0 / ((2.0_f64).sqrt());
+        Ok(array![
+            [
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(f, 0.0),
+                Complex64::new(f, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(f, 0.0),
+                Complex64::new((-1.0) * f, 0.0)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for ControlledHadamard {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        let mut circuit_b = Circuit::new();
+        circuit_b += RotateY::new(self.target, CalculatorFloat::FRAC_PI_4);
+        circuit_b += RotateZ::new(self.control, CalculatorFloat::FRAC_PI_2);
+        circuit_b += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2);
+        circuit_b += RotateX::new(self.target, CalculatorFloat::FRAC_PI_2);
+
+        let mut circuit_a = Circuit::new();
+        circuit_a += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2 * (-1.0));
+        circuit_a += RotateY::new(self.target, CalculatorFloat::FRAC_PI_4 * (-1.0));
+
+        KakDecomposition {
+            global_phase: CalculatorFloat::FRAC_PI_4,
+            k_vector: [
+                CalculatorFloat::FRAC_PI_4,
+                CalculatorFloat::ZERO,
+                CalculatorFloat::ZERO,
+            ],
+            circuit_before: Some(circuit_b),
+            circuit_after: Some(circuit_a),
+        }
+    }
+}
+
+/// The square root CNOT gate.
+///
+/// Square root version of the CNOT gate so that
+/// SqrtCNOT * SqrtCNOT = CNOT
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct SqrtCNOT {
+    /// The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the gate on the target qubit.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. Here, the qubit the gate is applied to.
+    target: usize,
+}
+
+impl SupportedVersion for SqrtCNOT {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for SqrtCNOT {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_SqrtCNOT: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "SqrtCNOT",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for SqrtCNOT {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed (here, not possible).
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        Ok(array![
+            [
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.5, 0.5),
+                Complex64::new(0.5, -0.5)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.5, -0.5),
+                Complex64::new(0.5, 0.5)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for SqrtCNOT {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        let mut circuit_b = Circuit::new();
+        circuit_b += RotateZ::new(self.control, CalculatorFloat::FRAC_PI_4);
+        circuit_b += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2);
+        circuit_b += RotateX::new(self.target, CalculatorFloat::FRAC_PI_4);
+
+        let mut circuit_a = Circuit::new();
+        circuit_a += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2 * (-1.0));
+
+        KakDecomposition {
+            global_phase: CalculatorFloat::from(PI / 8.0),
+            k_vector: [
+                CalculatorFloat::from(PI / 8.0),
+                CalculatorFloat::ZERO,
+                CalculatorFloat::ZERO,
+            ],
+            circuit_before: Some(circuit_b),
+            circuit_after: Some(circuit_a),
+        }
+    }
+}
+
+/// The inverse square root CNOT gate.
+///
+/// InvSqrtCNOT * SqrtCNOT = Identity
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct InvSqrtCNOT {
+    /// The index of the most significant qubit in the unitary representation. Here, the qubit that controls the application of the gate on the target qubit.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. Here, the qubit the gate is applied to.
+    target: usize,
+}
+
+impl SupportedVersion for InvSqrtCNOT {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for InvSqrtCNOT {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_InvSqrtCNOT: &[&str; 4] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "InvSqrtCNOT",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for InvSqrtCNOT {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed (here, not possible).
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        Ok(array![
+            [
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.5, -0.5),
+                Complex64::new(0.5, 0.5)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.5, 0.5),
+                Complex64::new(0.5, -0.5)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for InvSqrtCNOT {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        let mut circuit_b = Circuit::new();
+        circuit_b += RotateZ::new(self.control, CalculatorFloat::FRAC_PI_4 * (-1.0));
+        circuit_b += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2);
+        circuit_b += RotateX::new(self.target, CalculatorFloat::FRAC_PI_4 * (-1.0));
+
+        let mut circuit_a = Circuit::new();
+        circuit_a += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2 * (-1.0));
+
+        KakDecomposition {
+            global_phase: CalculatorFloat::from((-1.0) * PI / 8.0),
+            k_vector: [
+                CalculatorFloat::from((-1.0) * PI / 8.0),
+                CalculatorFloat::ZERO,
+                CalculatorFloat::ZERO,
+            ],
+            circuit_before: Some(circuit_b),
+            circuit_after: Some(circuit_a),
+        }
+    }
+}
+
+/// The ZX coupling gate.
+///
+/// Applies the unitary exp(-i * θ * Z_control X_target) to two qubits `control` and `target`.
+/// This is the native two-qubit interaction of cross-resonance type hardware.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::InvolveQubits,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateTwoQubit,
+    roqoqo_derive::Rotate,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct ZXCoupling {
+    /// The index of the most significant qubit in the unitary representation. Here, the qubit the Pauli Z operator acts on.
+    control: usize,
+    /// The index of the least significant qubit in the unitary representation. Here, the qubit the Pauli X operator acts on.
+    target: usize,
+    /// The coupling angle θ.
+    theta: CalculatorFloat,
+}
+
+impl SupportedVersion for ZXCoupling {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 14, 0)
+    }
+}
+
+impl super::ImplementedIn1point14 for ZXCoupling {}
+
+#[allow(non_upper_case_globals)]
+const TAGS_ZXCoupling: &[&str; 5] = &[
+    "Operation",
+    "GateOperation",
+    "TwoQubitGateOperation",
+    "Rotation",
+    "ZXCoupling",
+];
+
+/// Trait for all Operations acting with a unitary gate on a set of qubits.
+impl OperateGate for ZXCoupling {
+    /// Returns unitary matrix of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix representation of the gate.
+    /// * `Err(RoqoqoError)` - The conversion of parameters to f64 failed.
+    fn unitary_matrix(&self) -> Result<Array2<Complex64>, RoqoqoError> {
+        let c: f64 = (f64::try_from(self.theta.clone())?).cos();
+        let s: f64 = (f64::try_from(self.theta.clone())?).sin();
+        Ok(array![
+            [
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, (-1.0) * s),
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(c, 0.0),
+                Complex64::new(0.0, s)
+            ],
+            [
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, s),
+                Complex64::new(c, 0.0)
+            ],
+        ])
+    }
+}
+
+/// Trait for all gate operations acting on exactly two qubits.
+impl OperateTwoQubitGate for ZXCoupling {
+    /// Returns [KakDecomposition] of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * struct `KakDecomposition { global_phase, k_vector, circuit_before, circuit_after }`
+    fn kak_decomposition(&self) -> KakDecomposition {
+        let mut circuit_b = Circuit::new();
+        circuit_b += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2);
+
+        let mut circuit_a = Circuit::new();
+        circuit_a += RotateY::new(self.control, CalculatorFloat::FRAC_PI_2 * (-1.0));
+
+        KakDecomposition {
+            global_phase: CalculatorFloat::ZERO,
+            k_vector: [
+                self.theta.clone() * (-1.0),
+                CalculatorFloat::ZERO,
+                CalculatorFloat::ZERO,
+            ],
+            circuit_before: Some(circuit_b),
+            circuit_after: Some(circuit_a),
+        }
+    }
+}
@@ -11,6 +11,9 @@
 // limitations under the License.
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, JSONSchema};
+use ndarray::linalg::kron;
+use ndarray::Array2;
+use num_complex::Complex64;
 use qoqo_calculator::{Calculator, CalculatorFloat};
 use roqoqo::operations::*;
 use roqoqo::{AsVec, Circuit};
@@ -18,7 +21,7 @@ use roqoqo::{AsVec, Circuit};
 use schemars::schema_for;
 use std::collections::{HashMap, HashSet};
 #[cfg(feature = "overrotate")]
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::iter::FromIterator;
 use test_case::test_case;
 
@@ -233,6 +236,500 @@ fn test_get_operation_types() {
     assert!(circuit.get_operation_types() == test);
 }
 
+/// Test inverse function
+#[test]
+fn inverse() {
+    // Bell state preparation is disentangled by the inverse circuit
+    let mut circuit = Circuit::new();
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(CNOT::new(0, 1));
+    let mut inverse = Circuit::new();
+    inverse.add_operation(CNOT::new(0, 1));
+    inverse.add_operation(Hadamard::new(0));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(DefinitionBit::new("ro".to_string(), 2, true));
+    circuit.add_operation(RotateZ::new(0, CalculatorFloat::from(0.4)));
+    circuit.add_operation(SqrtPauliX::new(1));
+    circuit.add_operation(PragmaGlobalPhase::new(CalculatorFloat::from(0.5)));
+    circuit.add_operation(SingleQubitGate::new(
+        0,
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(0.3),
+    ));
+    circuit.add_operation(ControlledPhaseShift::new(0, 1, CalculatorFloat::from(0.7)));
+    let mut inverse = Circuit::new();
+    inverse.add_operation(DefinitionBit::new("ro".to_string(), 2, true));
+    inverse.add_operation(ControlledPhaseShift::new(0, 1, CalculatorFloat::from(-0.7)));
+    inverse.add_operation(SingleQubitGate::new(
+        0,
+        CalculatorFloat::from(0.5),
+        CalculatorFloat::from(-0.5),
+        CalculatorFloat::from(-0.5),
+        CalculatorFloat::from(-0.5),
+        CalculatorFloat::from(-0.3),
+    ));
+    inverse.add_operation(PragmaGlobalPhase::new(CalculatorFloat::from(-0.5)));
+    inverse.add_operation(InvSqrtPauliX::new(1));
+    inverse.add_operation(RotateZ::new(0, CalculatorFloat::from(-0.4)));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+    assert_eq!(circuit.inverse().unwrap().inverse().unwrap(), circuit);
+
+    // Symbolic rotations are inverted without checking the unitary matrix
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, CalculatorFloat::from("theta")));
+    let mut inverse = Circuit::new();
+    inverse.add_operation(RotateX::new(
+        0,
+        CalculatorFloat::from(-1.0) * CalculatorFloat::from("theta"),
+    ));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(SGate::new(0));
+    circuit.add_operation(TGate::new(1));
+    let mut inverse = Circuit::new();
+    inverse.add_operation(PhaseShiftState1::new(
+        1,
+        CalculatorFloat::from(-std::f64::consts::FRAC_PI_4),
+    ));
+    inverse.add_operation(PhaseShiftState1::new(
+        0,
+        CalculatorFloat::from(-std::f64::consts::FRAC_PI_2),
+    ));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(MeasureQubit::new(0, "ro".to_string(), 0));
+    assert!(circuit.inverse().is_err());
+}
+
+/// Test that the product of a gate and its inverse is the identity
+#[test_case(Operation::from(GPi::new(0, CalculatorFloat::from(0.3))); "GPi")]
+#[test_case(Operation::from(GPi2::new(0, CalculatorFloat::from(0.3))); "GPi2")]
+#[test_case(Operation::from(SGate::new(0)); "SGate")]
+#[test_case(Operation::from(TGate::new(0)); "TGate")]
+#[test_case(Operation::from(RotateXY::new(0, CalculatorFloat::from(0.3), CalculatorFloat::from(1.2))); "RotateXY")]
+#[test_case(Operation::from(PhaseShiftedControlledPhase::new(0, 1, CalculatorFloat::from(0.3), CalculatorFloat::from(1.2))); "PhaseShiftedControlledPhase")]
+fn inverse_unitary(operation: Operation) {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(operation.clone());
+    let inverse = circuit.inverse().unwrap();
+    assert_eq!(inverse.len(), 1);
+    let unitary = GateOperation::try_from(operation)
+        .unwrap()
+        .unitary_matrix()
+        .unwrap();
+    let inverse_unitary = GateOperation::try_from(inverse[0].clone())
+        .unwrap()
+        .unitary_matrix()
+        .unwrap();
+    assert_identity(&inverse_unitary.dot(&unitary));
+}
+
+/// Test inverse function for gates with a phase φ
+#[test]
+fn inverse_phase_gates() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(GivensRotation::new(
+        0,
+        1,
+        CalculatorFloat::from(0.7),
+        CalculatorFloat::from(1.3),
+    ));
+    let mut inverse = Circuit::new();
+    inverse.add_operation(GivensRotation::new(
+        0,
+        1,
+        CalculatorFloat::from(-0.7),
+        CalculatorFloat::ZERO,
+    ));
+    inverse.add_operation(PhaseShiftState1::new(1, CalculatorFloat::from(-1.3)));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+    // The target qubit is the less significant qubit of the two-qubit unitary
+    let unitary = kron(
+        &Array2::eye(2),
+        &PhaseShiftState1::new(1, CalculatorFloat::from(-1.3))
+            .unitary_matrix()
+            .unwrap(),
+    )
+    .dot(
+        &GivensRotation::new(0, 1, CalculatorFloat::from(-0.7), CalculatorFloat::ZERO)
+            .unitary_matrix()
+            .unwrap(),
+    )
+    .dot(
+        &GivensRotation::new(0, 1, CalculatorFloat::from(0.7), CalculatorFloat::from(1.3))
+            .unitary_matrix()
+            .unwrap(),
+    );
+    assert_identity(&unitary);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(GivensRotationLittleEndian::new(
+        0,
+        1,
+        CalculatorFloat::from(0.7),
+        CalculatorFloat::from(1.3),
+    ));
+    let mut inverse = Circuit::new();
+    inverse.add_operation(PhaseShiftState1::new(0, CalculatorFloat::from(-1.3)));
+    inverse.add_operation(GivensRotationLittleEndian::new(
+        0,
+        1,
+        CalculatorFloat::from(-0.7),
+        CalculatorFloat::ZERO,
+    ));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+    // The control qubit is the more significant qubit of the two-qubit unitary
+    let unitary =
+        GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from(-0.7), CalculatorFloat::ZERO)
+            .unitary_matrix()
+            .unwrap()
+            .dot(&kron(
+                &PhaseShiftState1::new(0, CalculatorFloat::from(-1.3))
+                    .unitary_matrix()
+                    .unwrap(),
+                &Array2::eye(2),
+            ))
+            .dot(
+                &GivensRotationLittleEndian::new(
+                    0,
+                    1,
+                    CalculatorFloat::from(0.7),
+                    CalculatorFloat::from(1.3),
+                )
+                .unitary_matrix()
+                .unwrap(),
+            );
+    assert_identity(&unitary);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PhaseShiftedControlledPhase::new(
+        0,
+        1,
+        CalculatorFloat::from(0.3),
+        CalculatorFloat::from(1.2),
+    ));
+    assert_eq!(circuit.inverse().unwrap().inverse().unwrap(), circuit);
+}
+
+/// Test inverse function for gates with symbolic parameters
+#[test]
+fn inverse_symbolic() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(GPi::new(0, CalculatorFloat::from("theta")));
+    circuit.add_operation(GPi2::new(1, CalculatorFloat::from("theta")));
+    circuit.add_operation(PhaseShiftedControlledPhase::new(
+        0,
+        1,
+        CalculatorFloat::from("theta"),
+        CalculatorFloat::from("phi"),
+    ));
+    circuit.add_operation(GivensRotation::new(
+        0,
+        1,
+        CalculatorFloat::from("theta"),
+        CalculatorFloat::from("phi"),
+    ));
+    circuit.add_operation(GivensRotationLittleEndian::new(
+        0,
+        1,
+        CalculatorFloat::from("theta"),
+        CalculatorFloat::from("phi"),
+    ));
+    let minus_theta = CalculatorFloat::from("theta") * (-1.0);
+    let minus_phi = CalculatorFloat::from("phi") * (-1.0);
+    let mut inverse = Circuit::new();
+    inverse.add_operation(PhaseShiftState1::new(0, minus_phi.clone()));
+    inverse.add_operation(GivensRotationLittleEndian::new(
+        0,
+        1,
+        minus_theta.clone(),
+        CalculatorFloat::ZERO,
+    ));
+    inverse.add_operation(GivensRotation::new(
+        0,
+        1,
+        minus_theta.clone(),
+        CalculatorFloat::ZERO,
+    ));
+    inverse.add_operation(PhaseShiftState1::new(1, minus_phi.clone()));
+    inverse.add_operation(PhaseShiftedControlledPhase::new(
+        0,
+        1,
+        minus_theta,
+        minus_phi,
+    ));
+    inverse.add_operation(GPi2::new(
+        1,
+        CalculatorFloat::from("theta") + std::f64::consts::PI,
+    ));
+    inverse.add_operation(GPi::new(0, CalculatorFloat::from("theta")));
+    assert_eq!(circuit.inverse().unwrap(), inverse);
+
+    // The inverse of the substituted circuit is the substituted inverse
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 0.4);
+    calculator.set_variable("phi", 1.1);
+    let substituted_inverse = inverse.substitute_parameters(&calculator).unwrap();
+    let inverse_substituted = circuit
+        .substitute_parameters(&calculator)
+        .unwrap()
+        .inverse()
+        .unwrap();
+    for (first, second) in substituted_inverse.iter().zip(inverse_substituted.iter()) {
+        let first = GateOperation::try_from(first.clone())
+            .unwrap()
+            .unitary_matrix()
+            .unwrap();
+        let second = GateOperation::try_from(second.clone())
+            .unwrap()
+            .unitary_matrix()
+            .unwrap();
+        for (value, expected) in first.iter().zip(second.iter()) {
+            assert!((value - expected).norm() < 1e-12);
+        }
+    }
+
+    // Gates without a known inverse cannot be checked with symbolic parameters
+    let mut circuit = Circuit::new();
+    circuit.add_operation(Qsim::new(
+        0,
+        1,
+        CalculatorFloat::from("x"),
+        CalculatorFloat::ZERO,
+        CalculatorFloat::ZERO,
+    ));
+    assert!(circuit.inverse().is_err());
+}
+
+/// Test that SGate and TGate are inverted twice to the PhaseShiftState1 with the same unitary
+#[test]
+fn inverse_sgate_tgate() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(SGate::new(0));
+    circuit.add_operation(TGate::new(1));
+    let mut double_inverse = Circuit::new();
+    double_inverse.add_operation(PhaseShiftState1::new(
+        0,
+        CalculatorFloat::from(std::f64::consts::FRAC_PI_2),
+    ));
+    double_inverse.add_operation(PhaseShiftState1::new(
+        1,
+        CalculatorFloat::from(std::f64::consts::FRAC_PI_4),
+    ));
+    assert_eq!(
+        circuit.inverse().unwrap().inverse().unwrap(),
+        double_inverse
+    );
+    for (op, double_inverse_op) in circuit.iter().zip(double_inverse.iter()) {
+        let unitary = GateOperation::try_from(op.clone())
+            .unwrap()
+            .unitary_matrix()
+            .unwrap();
+        let double_inverse_unitary = GateOperation::try_from(double_inverse_op.clone())
+            .unwrap()
+            .unitary_matrix()
+            .unwrap();
+        for (value, expected) in unitary.iter().zip(double_inverse_unitary.iter()) {
+            assert!((value - expected).norm() < 1e-12);
+        }
+    }
+}
+
+// Helper function asserting that a matrix is the identity matrix up to numerical accuracy.
+fn assert_identity(matrix: &Array2<Complex64>) {
+    for ((row, column), value) in matrix.indexed_iter() {
+        let expected = if row == column { 1.0 } else { 0.0 };
+        assert!((*value - expected).norm() < 1e-12);
+    }
+}
+
+/// Test depth and gate_volume functions
+#[test]
+fn depth_gate_volume() {
+    let circuit = Circuit::new();
+    assert_eq!(circuit.depth(), 0);
+    assert_eq!(circuit.gate_volume(), 0);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(CNOT::new(0, 1));
+    circuit.add_operation(Hadamard::new(1));
+    assert_eq!(circuit.depth(), 3);
+    assert_eq!(circuit.gate_volume(), 6);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(Hadamard::new(1));
+    circuit.add_operation(PauliX::new(2));
+    circuit.add_operation(RotateZ::new(3, CalculatorFloat::from(0.5)));
+    assert_eq!(circuit.depth(), 1);
+    assert_eq!(circuit.gate_volume(), 4);
+
+    circuit.add_operation(PragmaGlobalPhase::new(CalculatorFloat::from(0.5)));
+    assert_eq!(circuit.depth(), 1);
+    circuit.add_operation(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None));
+    circuit.add_operation(PauliX::new(0));
+    assert_eq!(circuit.depth(), 3);
+    assert_eq!(circuit.gate_volume(), 12);
+}
+
+/// Test gate_count_by_qubit function
+#[test]
+fn gate_count_by_qubit() {
+    let circuit = Circuit::new();
+    assert!(circuit.gate_count_by_qubit().is_empty());
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(DefinitionBit::new("ro".to_string(), 2, true));
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(CNOT::new(0, 1));
+    circuit.add_operation(Hadamard::new(1));
+    circuit.add_operation(PauliX::new(3));
+    assert_eq!(
+        circuit.gate_count_by_qubit(),
+        HashMap::from([(0, 2), (1, 2), (3, 1)])
+    );
+
+    circuit.add_operation(PragmaGlobalPhase::new(CalculatorFloat::from(0.5)));
+    circuit.add_operation(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None));
+    assert_eq!(
+        circuit.gate_count_by_qubit(),
+        HashMap::from([(0, 3), (1, 3), (3, 2)])
+    );
+}
+
+/// Test critical_path_gates function
+#[test]
+fn critical_path_gates() {
+    let circuit = Circuit::new();
+    assert!(circuit.critical_path_gates().is_empty());
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(CNOT::new(0, 1));
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(CNOT::new(0, 1));
+    assert_eq!(
+        circuit.critical_path_gates(),
+        vec![
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+        ]
+    );
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(Hadamard::new(1));
+    circuit.add_operation(RotateX::new(1, CalculatorFloat::from(0.5)));
+    circuit.add_operation(PragmaGlobalPhase::new(CalculatorFloat::from(0.5)));
+    circuit.add_operation(CNOT::new(1, 2));
+    circuit.add_operation(PauliX::new(0));
+    let path = circuit.critical_path_gates();
+    assert_eq!(path.len(), circuit.depth());
+    assert_eq!(
+        path,
+        vec![
+            Operation::from(Hadamard::new(1)),
+            Operation::from(RotateX::new(1, CalculatorFloat::from(0.5))),
+            Operation::from(CNOT::new(1, 2)),
+        ]
+    );
+
+    circuit.add_operation(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None));
+    let path = circuit.critical_path_gates();
+    assert_eq!(path.len(), circuit.depth());
+    assert_eq!(path.last().unwrap().hqslang(), "PragmaRepeatedMeasurement");
+}
+
+/// Test constant_folding function
+#[test]
+fn constant_folding() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(DefinitionBit::new("ro".to_string(), 2, true));
+    circuit.add_operation(RotateZ::new(0, CalculatorFloat::from(0.0)));
+    circuit.add_operation(PauliX::new(0));
+    circuit.add_operation(RotateX::new(1, CalculatorFloat::from("2 * 0.25")));
+    circuit.add_operation(PauliX::new(0));
+    circuit.add_operation(Hadamard::new(1));
+    circuit.add_operation(Identity::new(1));
+    circuit.add_operation(CNOT::new(0, 1));
+    circuit.add_operation(CNOT::new(0, 1));
+    circuit.add_operation(PauliY::new(1));
+    circuit.add_operation(MeasureQubit::new(1, "ro".to_string(), 1));
+    circuit.add_operation(PauliY::new(1));
+
+    let mut folded = Circuit::new();
+    folded.add_operation(DefinitionBit::new("ro".to_string(), 2, true));
+    folded.add_operation(RotateX::new(1, CalculatorFloat::from(0.5)));
+    folded.add_operation(Hadamard::new(1));
+    folded.add_operation(PauliY::new(1));
+    folded.add_operation(MeasureQubit::new(1, "ro".to_string(), 1));
+    folded.add_operation(PauliY::new(1));
+    assert_eq!(circuit.constant_folding().unwrap(), folded);
+
+    // Non-trivial gates are unchanged
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateZ::new(0, CalculatorFloat::from(0.3)));
+    circuit.add_operation(RotateZ::new(0, CalculatorFloat::from(0.3)));
+    circuit.add_operation(SqrtPauliX::new(0));
+    circuit.add_operation(SqrtPauliX::new(0));
+    assert_eq!(circuit.constant_folding().unwrap(), circuit);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateZ::new(0, CalculatorFloat::from("theta")));
+    assert!(circuit.constant_folding().is_err());
+}
+
+/// Test flatten_pragma_loops function
+#[test]
+fn flatten_pragma_loops() {
+    let mut inner = Circuit::new();
+    inner.add_operation(PauliX::new(1));
+    let mut circuit = Circuit::new();
+    circuit.add_operation(Hadamard::new(0));
+    circuit.add_operation(PragmaLoop::new(CalculatorFloat::from(3.0), inner.clone()));
+    circuit.add_operation(PauliZ::new(0));
+
+    let mut flattened = Circuit::new();
+    flattened.add_operation(Hadamard::new(0));
+    flattened.add_operation(PauliX::new(1));
+    flattened.add_operation(PauliX::new(1));
+    flattened.add_operation(PauliX::new(1));
+    flattened.add_operation(PauliZ::new(0));
+    assert_eq!(circuit.flatten_pragma_loops().unwrap(), flattened);
+
+    // Nested loops are flattened recursively and repetitions are rounded down
+    let mut middle = Circuit::new();
+    middle.add_operation(DefinitionBit::new("ro".to_string(), 1, true));
+    middle.add_operation(PragmaLoop::new(CalculatorFloat::from(2.0), inner));
+    middle.add_operation(PauliY::new(0));
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PragmaLoop::new(CalculatorFloat::from(2.5), middle));
+
+    let mut flattened = Circuit::new();
+    flattened.add_operation(DefinitionBit::new("ro".to_string(), 1, true));
+    for _ in 0..2 {
+        flattened.add_operation(PauliX::new(1));
+        flattened.add_operation(PauliX::new(1));
+        flattened.add_operation(PauliY::new(0));
+    }
+    assert_eq!(circuit.flatten_pragma_loops().unwrap(), flattened);
+
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PragmaLoop::new(
+        CalculatorFloat::from("repetitions"),
+        Circuit::new(),
+    ));
+    assert!(circuit.flatten_pragma_loops().is_err());
+}
+
 /// Test indexing function
 #[test]
 fn index_access() {
@@ -273,6 +770,10 @@ fn into_iter_from_iter() {
     let circuit_to = circuit.clone().into_iter();
     assert!(*circuit.get(0).unwrap() == circuit_to.clone().next().unwrap());
     assert!(*circuit.get(1).unwrap() == circuit_to.clone().nth(1).unwrap());
+    assert_eq!(circuit_to.len(), 2);
+    let mut partially_consumed = circuit_to.clone();
+    partially_consumed.next();
+    assert_eq!(partially_consumed.len(), 1);
 
     assert_eq!(
         format!("{:?}", circuit_to),
@@ -23,6 +23,21 @@ use test_case::test_case;
 static DEFAULT_NODE_NUMBER: usize = 10;
 static DEFAULT_EDGE_NUMBER: usize = 30;
 
+/// Test topological_sort
+#[test]
+fn test_topological_sort() {
+    let mut dag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    dag.add_to_back(Operation::from(PauliX::new(0)));
+    dag.add_to_back(Operation::from(CNOT::new(0, 1)));
+    dag.add_to_front(Operation::from(PauliZ::new(1)));
+
+    let sorted = dag.topological_sort().unwrap();
+    assert_eq!(sorted.len(), 3);
+    assert_eq!(sorted[2], &Operation::from(CNOT::new(0, 1)));
+    assert!(sorted.contains(&&Operation::from(PauliX::new(0))));
+    assert!(sorted.contains(&&Operation::from(PauliZ::new(1))));
+}
+
 /// Test conversion
 #[test]
 fn test_conversion() {
@@ -508,3 +523,26 @@ fn test_successors() {
     assert_eq!(iter.next().unwrap(), d.into());
     assert!(iter.next().is_none());
 }
+
+#[test]
+fn test_node_edge_count() {
+    let mut circuit = Circuit::new();
+    for _ in 0..5 {
+        circuit += PauliX::new(0);
+    }
+    let dag = CircuitDag::from(circuit);
+    assert_eq!(dag.node_count(), 5);
+    assert_eq!(dag.edge_count(), 4);
+
+    let mut circuit = Circuit::new();
+    for qubit in 0..5 {
+        circuit += PauliX::new(qubit);
+    }
+    let dag = CircuitDag::from(circuit);
+    assert_eq!(dag.node_count(), 5);
+    assert_eq!(dag.edge_count(), 0);
+
+    let dag: CircuitDag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    assert_eq!(dag.node_count(), 0);
+    assert_eq!(dag.edge_count(), 0);
+}
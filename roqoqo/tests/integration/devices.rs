@@ -14,7 +14,10 @@
 use jsonschema::{Draft, JSONSchema};
 use ndarray::array;
 use roqoqo::{
-    devices::{AllToAllDevice, Device, GenericDevice, SquareLatticeDevice},
+    devices::{
+        AllToAllDevice, Device, GenericDevice, HexagonalLatticeDevice, LinearChainDevice,
+        SquareLatticeDevice,
+    },
     RoqoqoError,
 };
 #[cfg(feature = "json_schema")]
@@ -192,6 +195,12 @@ fn generic_device_works() {
             array![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.001, 0.0]]
         )
         .is_err());
+    assert!(device
+        .set_qubit_decoherence_rates(
+            3,
+            array![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.001, 0.0]]
+        )
+        .is_err());
 
     device.add_damping(0, 0.1).unwrap();
     assert!(device.add_damping(10, 0.1).is_err());
@@ -439,6 +448,10 @@ fn test_square_lattice() {
         .clone()
         .set_two_qubit_gate_time("CNOT", 0, 3, 0.5)
         .is_err());
+    assert!(device
+        .clone()
+        .set_two_qubit_gate_time("ControlledPauliZ", 0, 1, 0.5)
+        .is_err());
     assert_eq!(device.two_qubit_gate_time("CNOT", &0, &1), Some(0.5f64));
 
     device
@@ -494,6 +507,138 @@ fn test_square_lattice() {
         .contains("The `change_device()` method has not been implemented."));
 }
 
+#[test]
+fn test_hexagonal_lattice() {
+    let mut device = HexagonalLatticeDevice::new(
+        3,
+        3,
+        true,
+        &["RotateX".to_string()],
+        &["CNOT".to_string()],
+        0.1,
+    );
+    assert_eq!(device.number_rows(), 3);
+    assert_eq!(device.number_columns(), 3);
+    assert!(device.offset_rows());
+    assert_eq!(device.number_qubits(), 9);
+    assert_eq!(device.qubit_to_hex_coordinates(5), Some((1, 2)));
+    assert_eq!(device.qubit_to_hex_coordinates(9), None);
+    assert_eq!(
+        device.qubit_decoherence_rates(&0),
+        Some(array![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+
+    // The central qubit of the lattice has three neighbours
+    let edges = device.two_qubit_edges();
+    let mut neighbours: Vec<usize> = edges
+        .iter()
+        .filter_map(|(a, b)| match (a, b) {
+            (4, other) | (other, 4) => Some(*other),
+            _ => None,
+        })
+        .collect();
+    neighbours.sort();
+    assert_eq!(neighbours, vec![1, 3, 5]);
+    assert_eq!(device.two_qubit_gate_time("CNOT", &4, &1), Some(0.1));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &1, &4), Some(0.1));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &4, &7), None);
+
+    device.set_two_qubit_gate_time("CNOT", 4, 5, 0.5).unwrap();
+    assert_eq!(device.two_qubit_gate_time("CNOT", &4, &5), Some(0.5));
+    assert!(device.set_two_qubit_gate_time("CNOT", 4, 7, 0.5).is_err());
+    assert!(device
+        .set_two_qubit_gate_time("ControlledPauliZ", 4, 5, 0.5)
+        .is_err());
+
+    device = device.set_all_single_qubit_gate_times("RotateX", 0.2);
+    device = device.set_all_two_qubit_gate_times("CNOT", 0.2);
+    assert_eq!(device.single_qubit_gate_time("RotateX", &8), Some(0.2));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &4, &5), Some(0.2));
+
+    device = device.add_damping_all(0.1);
+    assert_eq!(
+        device.qubit_decoherence_rates(&0),
+        Some(array![[0.1, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+
+    let test_edges = [(0, 1), (0, 2), (2, 3), (3, 5), (4, 5)];
+    let device = HexagonalLatticeDevice::new(
+        3,
+        2,
+        false,
+        &["RotateX".to_string()],
+        &["CNOT".to_string()],
+        0.1,
+    );
+    let edges = device.two_qubit_edges();
+    assert_eq!(test_edges.len(), edges.len());
+    for edge in edges {
+        assert!(test_edges.contains(&edge));
+    }
+    assert_eq!(device.to_generic_device().two_qubit_edges().len(), 5);
+}
+
+#[test]
+fn test_hexagonal_lattice_neighbour_count() {
+    for offset_rows in [false, true] {
+        let device =
+            HexagonalLatticeDevice::new(4, 6, offset_rows, &[], &["CNOT".to_string()], 0.1);
+        let edges = device.two_qubit_edges();
+        let number_neighbours = |qubit: usize| {
+            edges
+                .iter()
+                .filter(|(a, b)| *a == qubit || *b == qubit)
+                .count()
+        };
+        // Qubits 7 to 10 and 13 to 16 are the interior qubits of the 4x6 lattice
+        for qubit in [7, 8, 9, 10, 13, 14, 15, 16] {
+            assert_eq!(number_neighbours(qubit), 3);
+        }
+        for qubit in 0..device.number_qubits() {
+            assert!(number_neighbours(qubit) <= 3);
+        }
+    }
+}
+
+#[test]
+fn test_linear_chain() {
+    let mut device =
+        LinearChainDevice::new(4, &["RotateX".to_string()], &["CNOT".to_string()], 0.1);
+    assert_eq!(device.number_qubits(), 4);
+    assert_eq!(device.two_qubit_edges(), vec![(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(device.two_qubit_gate_time("CNOT", &2, &1), Some(0.1));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &2), None);
+    assert_eq!(device.single_qubit_gate_time("RotateX", &3), Some(0.1));
+
+    device.set_two_qubit_gate_time("CNOT", 1, 2, 0.5).unwrap();
+    assert_eq!(device.two_qubit_gate_time("CNOT", &1, &2), Some(0.5));
+    assert!(device.set_two_qubit_gate_time("CNOT", 0, 3, 0.5).is_err());
+    assert!(device
+        .set_two_qubit_gate_time("ControlledPauliZ", 1, 2, 0.5)
+        .is_err());
+
+    device = device.set_all_single_qubit_gate_times("RotateX", 0.2);
+    device = device.set_all_two_qubit_gate_times("CNOT", 0.2);
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.2));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &1, &2), Some(0.2));
+
+    device = device.add_damping_all(0.1);
+    device = device.add_dephasing_all(10.0);
+    device = device.add_depolarising_all(1.0);
+    assert_eq!(
+        device.qubit_decoherence_rates(&3),
+        Some(array![
+            [0.1 + 1.0 / 2.0, 0.0, 0.0],
+            [0.0, 1.0 / 2.0, 0.0],
+            [0.0, 0.0, 1.0 / 4.0 + 10.0]
+        ])
+    );
+    assert_eq!(device.to_generic_device().two_qubit_edges().len(), 3);
+
+    let single_qubit = LinearChainDevice::new(1, &[], &["CNOT".to_string()], 0.1);
+    assert!(single_qubit.two_qubit_edges().is_empty());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_json_schema() {
@@ -14,12 +14,13 @@
 
 #[cfg(feature = "jsonschema")]
 use jsonschema::{Draft, JSONSchema};
+use ndarray::array;
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations;
 use roqoqo::prelude::*;
 use roqoqo::Circuit;
 use roqoqo::{
-    measurements::{PauliZProduct, PauliZProductInput},
+    measurements::{PauliZProduct, PauliZProductInput, ReadoutErrorMitigationInput},
     registers::BitOutputRegister,
 };
 #[cfg(feature = "jsonschema")]
@@ -365,6 +366,71 @@ fn test_evaluate_symbolic(register: Vec<Vec<bool>>, constant: f64) {
     assert!((result.get("constant").unwrap() - constant).abs() < f64::EPSILON);
 }
 
+#[test_case(false ; "not flipped")]
+#[test_case(true ; "flipped")]
+fn test_evaluate_readout_error_matrix(flipped: bool) {
+    let mut bri = PauliZProductInput::new(1, flipped);
+    let _a = bri.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    let mut linear_map: HashMap<usize, f64> = HashMap::new();
+    linear_map.insert(0, 1.0);
+    bri.add_linear_exp_val("z".to_string(), linear_map).unwrap();
+
+    // 99 shots measured in state 0 and one shot measured in state 1
+    let mut register: BitOutputRegister = vec![vec![false]; 99];
+    register.push(vec![true]);
+    let mut flipped_register: BitOutputRegister = vec![vec![true]; 99];
+    flipped_register.push(vec![false]);
+    let mut measured_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+    let _ = measured_registers.insert("ro".to_string(), register);
+    if flipped {
+        let _ = measured_registers.insert("ro_flipped".to_string(), flipped_register);
+    }
+
+    let br = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input: bri,
+    };
+    let raw = br
+        .evaluate(measured_registers.clone(), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert!((raw.get("z").unwrap() - 0.98).abs() < 1e-10);
+
+    // The identity matrix leaves the results unchanged
+    let mitigation = ReadoutErrorMitigationInput::new(array![[1.0, 0.0], [0.0, 1.0]]).unwrap();
+    let identity = br
+        .evaluate_with_readout_error_mitigation(
+            measured_registers.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            &mitigation,
+        )
+        .unwrap()
+        .unwrap();
+    assert!((identity.get("z").unwrap() - 0.98).abs() < 1e-10);
+
+    // The observed single shot errors are fully explained by the readout error
+    let mitigation = ReadoutErrorMitigationInput::new(array![[0.99, 0.01], [0.01, 0.99]]).unwrap();
+    let corrected = br
+        .evaluate_with_readout_error_mitigation(
+            measured_registers.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            &mitigation,
+        )
+        .unwrap()
+        .unwrap();
+    assert!((corrected.get("z").unwrap() - 1.0).abs() < 1e-10);
+
+    // Plain evaluation is not affected by the mitigation
+    let raw_again = br
+        .evaluate(measured_registers, HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert_eq!(raw, raw_again);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_json_schema() {
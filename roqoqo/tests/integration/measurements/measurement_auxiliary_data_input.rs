@@ -13,10 +13,13 @@
 //! Integration test for public API of measurement inputs
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, JSONSchema};
+use ndarray::array;
 use num_complex::Complex64;
 use roqoqo::measurements::{
     CheatedInput, CheatedPauliZProductInput, PauliProductsToExpVal, PauliZProductInput,
+    ReadoutErrorMitigationInput,
 };
+use roqoqo::operations::SupportedVersion;
 use roqoqo::RoqoqoError;
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
@@ -115,6 +118,56 @@ fn double_insertion_br() {
     assert_eq!(x, 2);
 }
 
+#[test]
+fn readout_error_mitigation_input() {
+    let matrix = array![[0.99, 0.02], [0.01, 0.98]];
+    let mitigation = ReadoutErrorMitigationInput::new(matrix.clone()).unwrap();
+    assert_eq!(mitigation.readout_error_matrix(), &matrix);
+    assert_eq!(mitigation.clone(), mitigation);
+    assert_eq!(mitigation.minimum_supported_roqoqo_version(), (1, 14, 0));
+
+    // Wrong shape
+    assert!(ReadoutErrorMitigationInput::new(array![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]).is_err());
+    // Entries outside [0, 1]
+    assert!(ReadoutErrorMitigationInput::new(array![[1.5, 0.0], [-0.5, 1.0]]).is_err());
+    // Columns not summing to one
+    assert!(ReadoutErrorMitigationInput::new(array![[0.9, 0.1], [0.2, 0.9]]).is_err());
+    // Singular matrix
+    assert!(ReadoutErrorMitigationInput::new(array![[0.5, 0.5], [0.5, 0.5]]).is_err());
+}
+
+/// Test that PauliZProductInput can be read from bincode written by older roqoqo versions
+#[cfg(feature = "serialize")]
+#[test]
+fn bincode_compatibility_br() {
+    let mut bri = PauliZProductInput::new(1, false);
+    let _ = bri.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    assert_eq!(bri.minimum_supported_roqoqo_version(), (1, 0, 0));
+
+    // Bytes of the same PauliZProductInput written by roqoqo 1.13
+    let mut old_bytes: Vec<u8> = Vec::new();
+    // pauli_product_qubit_masks: {"ro": {0: [0]}}
+    old_bytes.extend(1u64.to_le_bytes());
+    old_bytes.extend(2u64.to_le_bytes());
+    old_bytes.extend(b"ro");
+    old_bytes.extend(1u64.to_le_bytes());
+    old_bytes.extend(0u64.to_le_bytes());
+    old_bytes.extend(1u64.to_le_bytes());
+    old_bytes.extend(0u64.to_le_bytes());
+    // number_qubits
+    old_bytes.extend(1u64.to_le_bytes());
+    // number_pauli_products
+    old_bytes.extend(1u64.to_le_bytes());
+    // measured_exp_vals: {}
+    old_bytes.extend(0u64.to_le_bytes());
+    // use_flipped_measurement
+    old_bytes.push(0);
+
+    let deserialized: PauliZProductInput = bincode::deserialize(&old_bytes).unwrap();
+    assert_eq!(deserialized, bri);
+    assert_eq!(bincode::serialize(&bri).unwrap(), old_bytes);
+}
+
 #[test]
 fn double_insertion_cbr() {
     let mut bri = CheatedPauliZProductInput::new();
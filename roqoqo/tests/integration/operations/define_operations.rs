@@ -16,7 +16,6 @@
 use jsonschema::{Draft, JSONSchema};
 use qoqo_calculator::Calculator;
 use roqoqo::operations::*;
-#[cfg(feature = "unstable_operation_definition")]
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
@@ -884,7 +883,6 @@ pub fn input_bit_json_schema() {
 }
 
 /// Test GateDefinition inputs and involved qubits
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn gate_definition_inputs_qubits() {
     let def = GateDefinition::new(
@@ -906,7 +904,6 @@ fn gate_definition_inputs_qubits() {
 
 /// Test GateDefinition standard derived traits (Debug, Clone, PartialEq)
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn gate_definition_simple_traits() {
     let def = GateDefinition::new(
         Circuit::new(),
@@ -944,7 +941,6 @@ fn gate_definition_simple_traits() {
 }
 
 /// Test GateDefinition tags, hslang and is_parametized() trait
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn gate_definition_operate_trait() {
     let def = GateDefinition::new(
@@ -966,7 +962,6 @@ fn gate_definition_operate_trait() {
 }
 
 /// Test GateDefinition Substitute and remap qubits functions
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn gate_definition_substitute_trait() {
     let def = GateDefinition::new(
@@ -1005,7 +1000,6 @@ fn gate_definition_substitute_trait() {
 
 /// Test GateDefinition Serialization and Deserialization traits (readable)
 #[cfg(feature = "serialize")]
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn gate_definition_serde_readable() {
     let def = GateDefinition::new(
@@ -1061,7 +1055,6 @@ fn gate_definition_serde_readable() {
 
 /// Test GateDefinition Serialization and Deserialization traits (compact)
 #[cfg(feature = "serialize")]
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn gate_definition_serde_compact() {
     let def = GateDefinition::new(
@@ -1117,11 +1110,12 @@ fn gate_definition_serde_compact() {
 
 /// Test GateDefinition JsonSchema trait
 #[cfg(feature = "json_schema")]
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 pub fn gate_definition_json_schema() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "test".into());
     let def = GateDefinition::new(
-        Circuit::new(),
+        circuit,
         String::from("test"),
         vec![0],
         vec!["test".to_owned()],
@@ -1141,4 +1135,28 @@ pub fn gate_definition_json_schema() {
 
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
+
+    // Deserialize
+    let deserialized: GateDefinition = serde_json::from_str(&test_json).unwrap();
+    assert_eq!(deserialized, def);
+}
+
+/// Test that GateDefinition keeps its bincode representation in the Operation enum
+#[cfg(feature = "serialize")]
+#[test]
+fn gate_definition_bincode() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "test".into());
+    let def: Operation = GateDefinition::new(
+        circuit,
+        String::from("test"),
+        vec![0],
+        vec!["test".to_owned()],
+    )
+    .into();
+    let serialized = bincode::serialize(&def).unwrap();
+    // The variant index of GateDefinition in the Operation enum must not change between versions
+    assert_eq!(serialized[0..4], 95u32.to_le_bytes());
+    let deserialized: Operation = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, def);
 }
@@ -112,6 +112,7 @@ fn test_to_single_qubit_gate_symbolic(operation: SingleQubitGateOperation) {
 #[test_case(Operation::from(PragmaStopParallelBlock::new(vec![0, 1], CalculatorFloat::from(0.0000001))); "PragmaStopParallelBlock")]
 #[test_case(Operation::from(PragmaGlobalPhase::new(CalculatorFloat::from(0.05))); "PragmaGlobalPhase")]
 #[test_case(Operation::from(PragmaSleep::new(vec![0, 1], CalculatorFloat::from(0.0000001))); "PragmaSleep")]
+#[test_case(Operation::from(PragmaQubitSleep::new(HashMap::from([(0, CalculatorFloat::from(0.0000001)), (1, CalculatorFloat::from(0.0000002))]))); "PragmaQubitSleep")]
 #[test_case(Operation::from(PragmaActiveReset::new(0)); "PragmaActiveReset")]
 #[test_case(Operation::from(PragmaStartDecompositionBlock::new(vec![0, 1], get_reordering())); "PragmaStartDecompositionBlock")]
 #[test_case(Operation::from( PragmaStopDecompositionBlock::new(vec![0, 1])); "PragmaStopDecompositionBlock")]
@@ -544,6 +544,23 @@ fn pragma_get_density_matrix_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test that PragmaGetDensityMatrix keeps the bincode representation of older versions
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_density_matrix_bincode() {
+    let pragma = PragmaGetDensityMatrix::new(String::from("ro"), None);
+
+    // Bytes of the same PragmaGetDensityMatrix written by roqoqo 1.13
+    let mut old_bytes: Vec<u8> = Vec::new();
+    old_bytes.extend(2u64.to_le_bytes());
+    old_bytes.extend(b"ro");
+    old_bytes.push(0);
+
+    let deserialized: PragmaGetDensityMatrix = bincode::deserialize(&old_bytes).unwrap();
+    assert_eq!(deserialized, pragma);
+    assert_eq!(bincode::serialize(&pragma).unwrap(), old_bytes);
+}
+
 /// Test PragmaGetOccupationProbability inputs and involved qubits
 #[test]
 fn pragma_get_occupation_probability_inputs_qubits() {
@@ -976,6 +993,249 @@ fn pragma_get_pauli_product_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test PragmaGetPauliProducts inputs and involved qubits
+#[test]
+fn pragma_get_pauli_products_inputs_qubits() {
+    let qubit_paulis_list: Vec<HashMap<usize, usize>> =
+        vec![HashMap::from([(0, 1)]), HashMap::from([(1, 3), (2, 3)])];
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(3));
+    let pragma = PragmaGetPauliProducts::new(
+        qubit_paulis_list.clone(),
+        String::from("ro"),
+        circuit.clone(),
+    );
+
+    // Test inputs are correct
+    assert_eq!(pragma.qubit_paulis_list(), &qubit_paulis_list);
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.circuit(), &circuit);
+
+    // Test InvolveQubits trait
+    let qubits: HashSet<usize> = HashSet::from([0, 1, 2, 3]);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::All(String::from("ro"))
+    );
+}
+
+/// Test PragmaGetPauliProducts Operate trait
+#[test]
+fn pragma_get_pauli_products_operate_trait() {
+    let pragma = PragmaGetPauliProducts::new(
+        vec![HashMap::from([(0, 1)])],
+        String::from("ro"),
+        Circuit::default(),
+    );
+
+    // (1) Test tags function
+    let tags: &[&str; 4] = &[
+        "Operation",
+        "Measurement",
+        "PragmaOperation",
+        "PragmaGetPauliProducts",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaGetPauliProducts"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaGetPauliProducts Substitute trait
+#[test]
+fn pragma_get_pauli_products_substitute_trait() {
+    let mut circuit_test = Circuit::new();
+    circuit_test.add_operation(RotateX::new(0, "theta".into()));
+    let pragma = PragmaGetPauliProducts::new(
+        vec![HashMap::from([(0, 1)]), HashMap::from([(1, 3)])],
+        String::from("ro"),
+        circuit_test,
+    );
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, 0.5.into()));
+    let test_gate = PragmaGetPauliProducts::new(
+        vec![HashMap::from([(0, 1)]), HashMap::from([(1, 3)])],
+        String::from("ro"),
+        circuit,
+    );
+    assert_eq!(result, test_gate);
+
+    // (2) Remap qubits function
+    let qubit_mapping_test: HashMap<usize, usize> = HashMap::from([(0, 1), (1, 0)]);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(1, "theta".into()));
+    let test_gate = PragmaGetPauliProducts::new(
+        vec![HashMap::from([(1, 1)]), HashMap::from([(0, 3)])],
+        String::from("ro"),
+        circuit,
+    );
+    assert_eq!(result, test_gate);
+}
+
+/// Test each entry of PragmaGetPauliProducts serializes like a PragmaGetPauliProduct
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_pauli_products_serde_matches_single() {
+    let qubit_paulis_list: Vec<HashMap<usize, usize>> =
+        vec![HashMap::from([(0, 1)]), HashMap::from([(2, 3)])];
+    let pragma = PragmaGetPauliProducts::new(
+        qubit_paulis_list.clone(),
+        String::from("ro"),
+        Circuit::default(),
+    );
+    let value: serde_json::Value = serde_json::to_value(&pragma).unwrap();
+    for (index, qubit_paulis) in qubit_paulis_list.into_iter().enumerate() {
+        let single =
+            PragmaGetPauliProduct::new(qubit_paulis, String::from("ro"), Circuit::default());
+        let single_value: serde_json::Value = serde_json::to_value(&single).unwrap();
+        assert_eq!(
+            value["qubit_paulis_list"][index],
+            single_value["qubit_paulis"]
+        );
+    }
+    let deserialized: PragmaGetPauliProducts = serde_json::from_value(value).unwrap();
+    assert_eq!(deserialized, pragma);
+}
+
+/// Test PragmaGetReducedDensityMatrix inputs and involved qubits
+#[test]
+fn pragma_get_reduced_density_matrix_inputs_qubits() {
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![2, 0], None);
+
+    // Test inputs are correct
+    assert_eq!(pragma.readout(), &String::from("ro"));
+    assert_eq!(pragma.qubits(), &vec![2, 0]);
+    assert_eq!(pragma.circuit(), &None);
+
+    // Test InvolveQubits trait
+    let qubits: HashSet<usize> = HashSet::from([0, 2]);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::All(String::from("ro"))
+    );
+
+    // Qubits of the measurement preparation circuit are involved as well
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(3));
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![2, 0], Some(circuit));
+    let qubits: HashSet<usize> = HashSet::from([0, 2, 3]);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+}
+
+/// Test PragmaGetReducedDensityMatrix Operate trait
+#[test]
+fn pragma_get_reduced_density_matrix_operate_trait() {
+    let pragma = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0], None);
+
+    // (1) Test tags function
+    let tags: &[&str; 4] = &[
+        "Operation",
+        "Measurement",
+        "PragmaOperation",
+        "PragmaGetReducedDensityMatrix",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaGetReducedDensityMatrix")
+    );
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaGetReducedDensityMatrix Substitute trait
+#[test]
+fn pragma_get_reduced_density_matrix_substitute_trait() {
+    let mut circuit_test = Circuit::new();
+    circuit_test.add_operation(RotateX::new(0, "theta".into()));
+    let pragma =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 2], Some(circuit_test));
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(0, 0.5.into()));
+    let test_gate =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 2], Some(circuit));
+    assert_eq!(result, test_gate);
+
+    // (2) Remap qubits function
+    let qubit_mapping_test: HashMap<usize, usize> = HashMap::from([(0, 1), (1, 0)]);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(1, "theta".into()));
+    let test_gate =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![1, 2], Some(circuit));
+    assert_eq!(result, test_gate);
+}
+
+/// Test PragmaGetReducedDensityMatrix Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_get_reduced_density_matrix_serde_readable() {
+    let pragma_serialization =
+        PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 2], None);
+
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaGetReducedDensityMatrix",
+                len: 3,
+            },
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("qubits"),
+            Token::Seq { len: Some(2) },
+            Token::U64(0),
+            Token::U64(2),
+            Token::SeqEnd,
+            Token::Str("circuit"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaGetReducedDensityMatrix JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_get_reduced_density_matrix_json_schema() {
+    let op = PragmaGetReducedDensityMatrix::new(String::from("ro"), vec![0, 2], None);
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaGetReducedDensityMatrix);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
 /// Test PragmaRepeatedMeasurement inputs and involved qubits
 #[test]
 fn pragma_repeated_measurement_inputs_qubits() {
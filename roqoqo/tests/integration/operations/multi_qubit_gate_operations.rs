@@ -14,7 +14,8 @@
 
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, JSONSchema};
-use ndarray::array;
+use ndarray::linalg::kron;
+use ndarray::{array, Array2};
 use num_complex::Complex64;
 use qoqo_calculator::Calculator;
 use qoqo_calculator::CalculatorFloat;
@@ -572,15 +573,245 @@ fn test_rotatex_powercf_multi_qubit_zz(theta: CalculatorFloat, power: Calculator
     assert_eq!(power_gate.theta(), test_gate.theta());
 }
 
+#[test_case(vec![0,1]; "two_qubit")]
+#[test_case(vec![0,1,2]; "three_qubit")]
+#[test_case(vec![1,3]; "non_contiguous")]
+fn test_circuit_multi_xx(qubits: Vec<usize>) {
+    let gate = MultiQubitXX::new(qubits.clone(), CalculatorFloat::FRAC_PI_2);
+    let c = gate.circuit();
+    let mut comparison_circuit = Circuit::new();
+    for q in qubits.iter() {
+        comparison_circuit += Hadamard::new(*q);
+    }
+    for pair in qubits.windows(2) {
+        comparison_circuit += CNOT::new(pair[0], pair[1]);
+    }
+    comparison_circuit += RotateZ::new(*qubits.last().unwrap(), CalculatorFloat::FRAC_PI_2);
+    for pair in qubits.windows(2).rev() {
+        comparison_circuit += CNOT::new(pair[0], pair[1]);
+    }
+    for q in qubits.iter() {
+        comparison_circuit += Hadamard::new(*q);
+    }
+    assert!(c == comparison_circuit);
+}
+
+/// Test that the MultiQubitXX unitary matches MultiQubitMS and VariableMSXX on two qubits
+#[test_case(CalculatorFloat::FRAC_PI_2; "pi_half")]
+#[test_case(CalculatorFloat::from(0.3); "float")]
+fn test_matrix_output_multi_qubit_xx(theta: CalculatorFloat) {
+    let gate = MultiQubitXX::new(vec![0, 1], theta.clone());
+    let unit = gate.unitary_matrix().unwrap();
+    let ms_unit = MultiQubitMS::new(vec![0, 1], theta.clone())
+        .unitary_matrix()
+        .unwrap();
+    let msxx_unit = VariableMSXX::new(0, 1, theta).unitary_matrix().unwrap();
+    assert!((unit.clone() - ms_unit).iter().all(|x| x.norm() < 1e-12));
+    assert!((unit - msxx_unit).iter().all(|x| x.norm() < 1e-12));
+}
+
+#[test]
+fn test_operate_multi_qubit_xx() {
+    let qubits = vec![0, 1, 2];
+    let gate = MultiQubitXX::new(qubits.clone(), CalculatorFloat::FRAC_PI_2);
+    assert_eq!(gate.hqslang(), "MultiQubitXX");
+    assert_eq!(
+        gate.tags(),
+        &[
+            "Operation",
+            "GateOperation",
+            "MultiQubitGateOperation",
+            "MultiQubitXX",
+        ]
+    );
+    assert_eq!(gate.qubits(), &vec![0, 1, 2]);
+    assert!(!gate.is_parametrized());
+    let gate1 = MultiQubitXX::new(qubits, "theta".into());
+    assert!(gate1.is_parametrized());
+    assert_eq!(gate1.minimum_supported_roqoqo_version(), (1, 14, 0));
+}
+
+#[test]
+fn test_substitute_multi_qubit_xx() {
+    let qubits = vec![0, 1, 2];
+    let gate1 = MultiQubitXX::new(qubits.clone(), "theta".into());
+    let gate = MultiQubitXX::new(qubits, CalculatorFloat::FRAC_PI_2);
+    let mut calc = Calculator::new();
+    calc.set_variable("theta", std::f64::consts::FRAC_PI_2);
+    let gate_substituted = roqoqo::operations::Substitute::substitute_parameters(&gate1, &calc);
+    let subs = gate_substituted.unwrap();
+    assert_eq!(gate, subs);
+    let mut mapping: HashMap<usize, usize> = std::collections::HashMap::new();
+    let _ = mapping.insert(0, 1);
+    let _ = mapping.insert(1, 0);
+    let remapped = gate1.remap_qubits(&mapping).unwrap();
+    let qubits = remapped.qubits();
+    assert_eq!(qubits, &vec![1, 0, 2]);
+}
+
+/// Computes the unitary of a circuit of single-qubit and two-qubit gates acting on qubits 0 and 1
+///
+/// Asymmetric two-qubit gates are expected to use qubit 0 as control and qubit 1 as target.
+fn two_qubit_circuit_unitary(circuit: &Circuit) -> Array2<Complex64> {
+    let identity: Array2<Complex64> = Array2::eye(2);
+    let mut unitary: Array2<Complex64> = Array2::eye(4);
+    for op in circuit.iter() {
+        let gate_unitary = match op {
+            Operation::Hadamard(gate) if *gate.qubit() == 0 => {
+                kron(&gate.unitary_matrix().unwrap(), &identity)
+            }
+            Operation::Hadamard(gate) => kron(&identity, &gate.unitary_matrix().unwrap()),
+            Operation::ControlledPhaseShift(gate) => gate.unitary_matrix().unwrap(),
+            Operation::SWAP(gate) => gate.unitary_matrix().unwrap(),
+            Operation::CNOT(gate) => gate.unitary_matrix().unwrap(),
+            _ => panic!("Unexpected operation in circuit"),
+        };
+        unitary = gate_unitary.dot(&unitary);
+    }
+    unitary
+}
+
+/// Test the QFT unitary against the analytical form of the two-qubit QFT
+#[test]
+fn test_matrix_output_qft() {
+    let gate = QFT::new(vec![0, 1], false, true);
+    let unit = gate.unitary_matrix().unwrap();
+    let i = Complex64::new(0.0, 1.0);
+    let one = Complex64::new(1.0, 0.0);
+    let test_array = array![
+        [one, one, one, one],
+        [one, i, -one, -i],
+        [one, -one, one, -one],
+        [one, -i, -one, i],
+    ] * Complex64::new(0.5, 0.0);
+    assert!((unit - test_array).iter().all(|x| x.norm() < 1e-12));
+
+    let inverse_unit = QFT::new(vec![0, 1], true, true).unitary_matrix().unwrap();
+    let product = inverse_unit.dot(&gate.unitary_matrix().unwrap());
+    let identity: Array2<Complex64> = Array2::eye(4);
+    assert!((product - identity).iter().all(|x| x.norm() < 1e-12));
+}
+
+/// Test that the circuit of the QFT implements its unitary
+#[test_case(false, true; "qft")]
+#[test_case(false, false; "qft_no_swaps")]
+#[test_case(true, true; "inverse_qft")]
+#[test_case(true, false; "inverse_qft_no_swaps")]
+fn test_circuit_qft(inverse: bool, with_swaps: bool) {
+    let gate = QFT::new(vec![0, 1], inverse, with_swaps);
+    let circuit_unit = two_qubit_circuit_unitary(&gate.circuit());
+    let unit = gate.unitary_matrix().unwrap();
+    assert!((unit - circuit_unit).iter().all(|x| x.norm() < 1e-12));
+    assert_eq!(
+        gate.circuit()
+            .iter()
+            .filter(|op| op.hqslang() == "SWAP")
+            .count(),
+        usize::from(with_swaps)
+    );
+}
+
+#[test]
+fn test_operate_qft() {
+    let gate = QFT::new(vec![0, 1, 2], false, true);
+    assert_eq!(gate.hqslang(), "QFT");
+    assert_eq!(
+        gate.tags(),
+        &[
+            "Operation",
+            "GateOperation",
+            "MultiQubitGateOperation",
+            "QFT",
+        ]
+    );
+    assert_eq!(gate.qubits(), &vec![0, 1, 2]);
+    assert!(!gate.inverse());
+    assert!(gate.with_swaps());
+    assert!(!gate.is_parametrized());
+    assert_eq!(gate.minimum_supported_roqoqo_version(), (1, 14, 0));
+    let mut mapping: HashMap<usize, usize> = std::collections::HashMap::new();
+    let _ = mapping.insert(0, 1);
+    let _ = mapping.insert(1, 0);
+    let remapped = gate.remap_qubits(&mapping).unwrap();
+    assert_eq!(remapped, QFT::new(vec![1, 0, 2], false, true));
+    assert_eq!(
+        gate.substitute_parameters(&Calculator::new()).unwrap(),
+        gate
+    );
+}
+
+/// Test the MultiQubitCNOT unitary against |0><0| x I x I + |1><1| x X x X
+#[test]
+fn test_matrix_output_multi_qubit_cnot() {
+    let gate = MultiQubitCNOT::new(vec![0, 1, 2]);
+    let unit = gate.unitary_matrix().unwrap();
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let projector_0 = array![[one, zero], [zero, zero]];
+    let projector_1 = array![[zero, zero], [zero, one]];
+    let identity: Array2<Complex64> = Array2::eye(2);
+    let pauli_x = array![[zero, one], [one, zero]];
+    let test_array = kron(&projector_0, &kron(&identity, &identity))
+        + kron(&projector_1, &kron(&pauli_x, &pauli_x));
+    assert_eq!(unit, test_array);
+
+    let two_qubit_unit = MultiQubitCNOT::new(vec![0, 1]).unitary_matrix().unwrap();
+    assert_eq!(two_qubit_unit, CNOT::new(0, 1).unitary_matrix().unwrap());
+
+    assert!(MultiQubitCNOT::new(vec![0]).unitary_matrix().is_err());
+}
+
+#[test]
+fn test_circuit_multi_qubit_cnot() {
+    let gate = MultiQubitCNOT::new(vec![2, 0, 3]);
+    let mut comparison_circuit = Circuit::new();
+    comparison_circuit += CNOT::new(2, 0);
+    comparison_circuit += CNOT::new(2, 3);
+    assert_eq!(gate.circuit(), comparison_circuit);
+    assert_eq!(
+        two_qubit_circuit_unitary(&MultiQubitCNOT::new(vec![0, 1]).circuit()),
+        CNOT::new(0, 1).unitary_matrix().unwrap()
+    );
+}
+
+#[test]
+fn test_operate_multi_qubit_cnot() {
+    let gate = MultiQubitCNOT::new(vec![0, 1, 2]);
+    assert_eq!(gate.hqslang(), "MultiQubitCNOT");
+    assert_eq!(
+        gate.tags(),
+        &[
+            "Operation",
+            "GateOperation",
+            "MultiQubitGateOperation",
+            "MultiQubitCNOT",
+        ]
+    );
+    assert_eq!(gate.qubits(), &vec![0, 1, 2]);
+    assert!(!gate.is_parametrized());
+    assert_eq!(gate.minimum_supported_roqoqo_version(), (1, 14, 0));
+    let mut mapping: HashMap<usize, usize> = std::collections::HashMap::new();
+    let _ = mapping.insert(0, 2);
+    let _ = mapping.insert(2, 0);
+    let remapped = gate.remap_qubits(&mapping).unwrap();
+    assert_eq!(remapped, MultiQubitCNOT::new(vec![2, 1, 0]));
+}
+
 /// Test JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test_case(MultiQubitGateOperation::from(MultiQubitZZ::new(vec![0, 1, 2, 3], 0.23.into())); "MultiQubitZZ")]
 #[test_case(MultiQubitGateOperation::from(MultiQubitMS::new(vec![0, 1, 2], 0.45.into())); "MultiQubitMS")]
+#[test_case(MultiQubitGateOperation::from(MultiQubitXX::new(vec![0, 1, 2], 0.45.into())); "MultiQubitXX")]
+#[test_case(MultiQubitGateOperation::from(QFT::new(vec![0, 1, 2], true, false)); "QFT")]
+#[test_case(MultiQubitGateOperation::from(MultiQubitCNOT::new(vec![0, 1, 2])); "MultiQubitCNOT")]
 pub fn test_json_schema_multi_qubit_gate_operations(gate: MultiQubitGateOperation) {
     // Serialize
     let test_json = match gate.clone() {
         MultiQubitGateOperation::MultiQubitMS(op) => serde_json::to_string(&op).unwrap(),
         MultiQubitGateOperation::MultiQubitZZ(op) => serde_json::to_string(&op).unwrap(),
+        MultiQubitGateOperation::MultiQubitXX(op) => serde_json::to_string(&op).unwrap(),
+        MultiQubitGateOperation::QFT(op) => serde_json::to_string(&op).unwrap(),
+        MultiQubitGateOperation::MultiQubitCNOT(op) => serde_json::to_string(&op).unwrap(),
         _ => unreachable!(),
     };
     let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
@@ -589,6 +820,9 @@ pub fn test_json_schema_multi_qubit_gate_operations(gate: MultiQubitGateOperatio
     let test_schema = match gate {
         MultiQubitGateOperation::MultiQubitMS(_) => schema_for!(MultiQubitMS),
         MultiQubitGateOperation::MultiQubitZZ(_) => schema_for!(MultiQubitZZ),
+        MultiQubitGateOperation::MultiQubitXX(_) => schema_for!(MultiQubitXX),
+        MultiQubitGateOperation::QFT(_) => schema_for!(QFT),
+        MultiQubitGateOperation::MultiQubitCNOT(_) => schema_for!(MultiQubitCNOT),
         _ => unreachable!(),
     };
     let schema = serde_json::to_string(&test_schema).unwrap();
@@ -603,7 +837,6 @@ pub fn test_json_schema_multi_qubit_gate_operations(gate: MultiQubitGateOperatio
 }
 
 // Test partialEq function of CallDefinedGate
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_clone_partial_eq_call_defined_gate() {
     let qubits = vec![0, 1, 2];
@@ -627,7 +860,6 @@ fn test_clone_partial_eq_call_defined_gate() {
     assert_eq!(gate2, gate1);
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_substitute_call_defined_gate() {
     let qubits = vec![0, 1, 2];
@@ -647,7 +879,6 @@ fn test_substitute_call_defined_gate() {
     assert_eq!(qubits, &vec![1, 2, 0]);
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[test]
 fn test_substitute_error_call_defined_gate() {
     let qubits = vec![0, 1, 2];
@@ -663,7 +894,6 @@ fn test_substitute_error_call_defined_gate() {
 }
 
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_format_call_defined_gate() {
     let qubits = vec![0, 1, 2];
     let gate = CallDefinedGate::new(
@@ -679,7 +909,6 @@ fn test_format_call_defined_gate() {
 }
 
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_remap_defined_gate() {
     let qubits = vec![0, 1, 2];
     let gate = CallDefinedGate::new(
@@ -697,7 +926,6 @@ fn test_remap_defined_gate() {
 }
 
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_remap_error_call_defined_gate() {
     let qubits = vec![0, 1, 2];
     let gate = CallDefinedGate::new(
@@ -713,7 +941,6 @@ fn test_remap_error_call_defined_gate() {
 }
 
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 fn test_involved_qubits_call_defined_gate() {
     let qubits = vec![0, 1, 2];
     let gate = CallDefinedGate::new(
@@ -730,7 +957,6 @@ fn test_involved_qubits_call_defined_gate() {
 }
 
 #[test]
-#[cfg(feature = "unstable_operation_definition")]
 #[cfg(feature = "json_schema")]
 pub fn test_json_schema_multi_qubit_call_gate() {
     let qubits = vec![0, 1, 2];
@@ -754,4 +980,25 @@ pub fn test_json_schema_multi_qubit_call_gate() {
 
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
+
+    // Deserialize
+    let deserialized: CallDefinedGate = serde_json::from_str(&test_json).unwrap();
+    assert_eq!(deserialized, gate);
+}
+
+/// Test that CallDefinedGate keeps its bincode representation in the Operation enum
+#[test]
+#[cfg(feature = "serialize")]
+fn test_bincode_call_defined_gate() {
+    let gate: Operation = CallDefinedGate::new(
+        "name".into(),
+        vec![0, 1, 2],
+        vec![CalculatorFloat::Float(0.6), "theta".into()],
+    )
+    .into();
+    let serialized = bincode::serialize(&gate).unwrap();
+    // The variant index of CallDefinedGate in the Operation enum must not change between versions
+    assert_eq!(serialized[0..4], 94u32.to_le_bytes());
+    let deserialized: Operation = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, gate);
 }
@@ -319,6 +319,122 @@ fn pragma_set_number_of_measurements_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test PragmaSimulationRepetitions inputs and involved qubits
+#[test]
+fn pragma_simulation_repetitions_inputs_qubits() {
+    let pragma = PragmaSimulationRepetitions::new(100);
+
+    // Test inputs are correct
+    assert_eq!(pragma.repetitions(), &100_usize);
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+}
+
+/// Test PragmaSimulationRepetitions standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_simulation_repetitions_simple_traits() {
+    let pragma = PragmaSimulationRepetitions::new(100);
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSimulationRepetitions { repetitions: 100 }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSimulationRepetitions::new(100);
+    let pragma_1 = PragmaSimulationRepetitions::new(10);
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSimulationRepetitions Operate trait
+#[test]
+fn pragma_simulation_repetitions_operate_trait() {
+    let pragma = PragmaSimulationRepetitions::new(100);
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &[
+        "Operation",
+        "PragmaOperation",
+        "PragmaSimulationRepetitions",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaSimulationRepetitions")
+    );
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaSimulationRepetitions Substitute trait
+#[test]
+fn pragma_simulation_repetitions_substitute_trait() {
+    let pragma = PragmaSimulationRepetitions::new(100);
+    // (1) Substitute parameters function
+    let substitution_dict: Calculator = Calculator::new();
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaSimulationRepetitions Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_simulation_repetitions_serde_readable() {
+    let pragma_serialization = PragmaSimulationRepetitions::new(100);
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSimulationRepetitions",
+                len: 1,
+            },
+            Token::Str("repetitions"),
+            Token::U64(100),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSimulationRepetitions JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_simulation_repetitions_json_schema() {
+    let op = PragmaSimulationRepetitions::new(100);
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaSimulationRepetitions);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
 /// Test PragmaSetStateVector inputs and involved qubits
 #[test]
 fn pragma_set_statevector_inputs_qubits() {
@@ -774,6 +890,235 @@ fn pragma_set_density_matrix_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test PragmaSetStabilizerState inputs and involved qubits
+#[test]
+fn pragma_set_stabilizer_state_inputs_qubits() {
+    let pragma = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+
+    // Test inputs are correct
+    assert_eq!(
+        pragma.stabilizer_generators(),
+        &vec!["XX".to_string(), "ZZ".to_string()]
+    );
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+}
+
+/// Test PragmaSetStabilizerState standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_set_stabilizer_state_simple_traits() {
+    let pragma = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSetStabilizerState { stabilizer_generators: [\"XX\", \"ZZ\"] }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+    let pragma_1 = PragmaSetStabilizerState::new(vec!["XX".to_string(), "YY".to_string()]);
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSetStabilizerState Operate trait
+#[test]
+fn pragma_set_stabilizer_state_operate_trait() {
+    let pragma = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSetStabilizerState"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaSetStabilizerState"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaSetStabilizerState Substitute trait
+#[test]
+fn pragma_set_stabilizer_state_substitute_trait() {
+    let pragma = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+    let pragma_test = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma_test
+        .substitute_parameters(&substitution_dict)
+        .unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma_test.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaSetStabilizerState Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_set_stabilizer_state_serde_readable() {
+    let pragma_serialization =
+        PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSetStabilizerState",
+                len: 1,
+            },
+            Token::Str("stabilizer_generators"),
+            Token::Seq { len: Some(2) },
+            Token::Str("XX"),
+            Token::Str("ZZ"),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSetStabilizerState JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_set_stabilizer_state_json_schema() {
+    let op = PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()]);
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaSetStabilizerState);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
+fn custom_two_qubit_unitary() -> Array2<Complex64> {
+    Array2::from_diag(&array![
+        Complex64::new(1.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(-1.0, 0.0)
+    ])
+}
+
+/// Test PragmaCustomTwoQubitGate inputs and involved qubits
+#[test]
+fn pragma_custom_two_qubit_gate_inputs_qubits() {
+    let pragma = PragmaCustomTwoQubitGate::new(0, 1, custom_two_qubit_unitary());
+
+    // Test inputs are correct
+    assert_eq!(pragma.qubit_0(), &0_usize);
+    assert_eq!(pragma.qubit_1(), &1_usize);
+    assert_eq!(pragma.unitary(), &custom_two_qubit_unitary());
+
+    // Test InvolveQubits trait
+    let mut qubits: HashSet<usize> = HashSet::new();
+    qubits.insert(0);
+    qubits.insert(1);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+}
+
+/// Test PragmaCustomTwoQubitGate standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_custom_two_qubit_gate_simple_traits() {
+    let pragma = PragmaCustomTwoQubitGate::new(0, 1, custom_two_qubit_unitary());
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaCustomTwoQubitGate::new(0, 1, custom_two_qubit_unitary());
+    let pragma_1 = PragmaCustomTwoQubitGate::new(0, 2, custom_two_qubit_unitary());
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaCustomTwoQubitGate Operate trait
+#[test]
+fn pragma_custom_two_qubit_gate_operate_trait() {
+    let pragma = PragmaCustomTwoQubitGate::new(0, 1, custom_two_qubit_unitary());
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaCustomTwoQubitGate"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaCustomTwoQubitGate"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaCustomTwoQubitGate Substitute trait
+#[test]
+fn pragma_custom_two_qubit_gate_substitute_trait() {
+    let pragma = PragmaCustomTwoQubitGate::new(0, 1, custom_two_qubit_unitary());
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 1);
+    qubit_mapping_test.insert(1, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(
+        result,
+        PragmaCustomTwoQubitGate::new(1, 0, custom_two_qubit_unitary())
+    );
+
+    // (3) Invalid mapping
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    let result = pragma.remap_qubits(&qubit_mapping_test);
+    assert!(result.is_err());
+}
+
+/// Test PragmaCustomTwoQubitGate JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_custom_two_qubit_gate_json_schema() {
+    let op = PragmaCustomTwoQubitGate::new(0, 1, custom_two_qubit_unitary());
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaCustomTwoQubitGate);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
 /// Test PragmaRepeatGate inputs and involved qubits
 #[test]
 fn pragma_repeat_gate_inputs_qubits() {
@@ -1678,6 +2023,112 @@ fn pragma_sleep_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+fn qubit_sleep_times() -> HashMap<usize, CalculatorFloat> {
+    let mut sleep_times: HashMap<usize, CalculatorFloat> = HashMap::new();
+    sleep_times.insert(0, CalculatorFloat::from(0.0000001));
+    sleep_times.insert(2, CalculatorFloat::from(0.0000003));
+    sleep_times
+}
+
+/// Test PragmaQubitSleep inputs and involved qubits
+#[test]
+fn pragma_qubit_sleep_inputs_qubits() {
+    let pragma = PragmaQubitSleep::new(qubit_sleep_times());
+
+    // Test inputs are correct
+    assert_eq!(pragma.sleep_times(), &qubit_sleep_times());
+
+    // Test InvolveQubits trait
+    let mut qubits: HashSet<usize> = HashSet::new();
+    qubits.insert(0);
+    qubits.insert(2);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::Set(qubits));
+}
+
+/// Test PragmaQubitSleep Operate trait
+#[test]
+fn pragma_qubit_sleep_operate_trait() {
+    let pragma = PragmaQubitSleep::new(qubit_sleep_times());
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaQubitSleep"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaQubitSleep"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let mut sleep_times = qubit_sleep_times();
+    sleep_times.insert(1, CalculatorFloat::from("t_1"));
+    assert!(PragmaQubitSleep::new(sleep_times).is_parametrized());
+
+    // (4) Test minimum supported version
+    assert_eq!(pragma.minimum_supported_roqoqo_version(), (1, 14, 0));
+}
+
+/// Test PragmaQubitSleep Substitute trait
+#[test]
+fn pragma_qubit_sleep_substitute_trait() {
+    let pragma = PragmaQubitSleep::new(qubit_sleep_times());
+
+    // (1) Substitute parameters function resolves every qubit independently
+    let mut sleep_times: HashMap<usize, CalculatorFloat> = HashMap::new();
+    sleep_times.insert(0, CalculatorFloat::from("t_0"));
+    sleep_times.insert(2, CalculatorFloat::from("3 * t_0"));
+    let pragma_test = PragmaQubitSleep::new(sleep_times);
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("t_0", 0.0000001);
+    let result = pragma_test
+        .substitute_parameters(&substitution_dict)
+        .unwrap();
+    assert_eq!(result.sleep_times()[&0], CalculatorFloat::from(0.0000001));
+    assert!((*result.sleep_times()[&2].float().unwrap() - 0.0000003).abs() < 1e-15);
+    assert!(pragma_test
+        .substitute_parameters(&Calculator::new())
+        .is_err());
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 1);
+    qubit_mapping_test.insert(1, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut sleep_times: HashMap<usize, CalculatorFloat> = HashMap::new();
+    sleep_times.insert(1, CalculatorFloat::from(0.0000001));
+    sleep_times.insert(2, CalculatorFloat::from(0.0000003));
+    assert_eq!(result, PragmaQubitSleep::new(sleep_times));
+
+    let mut qubit_mapping_err: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_err.insert(0, 1);
+    let result = pragma.remap_qubits(&qubit_mapping_err);
+    assert_eq!(result, Err(RoqoqoError::QubitMappingError { qubit: 1 }));
+}
+
+/// Test PragmaQubitSleep JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_qubit_sleep_json_schema() {
+    let op = PragmaQubitSleep::new(qubit_sleep_times());
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+    let deserialized: PragmaQubitSleep = serde_json::from_str(&test_json).unwrap();
+    assert_eq!(deserialized, op);
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaQubitSleep);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
 /// Test PragmaActiveReset inputs and involved qubits
 #[test]
 fn pragma_active_reset_inputs_qubits() {
@@ -3795,6 +4246,177 @@ fn pragma_controlled_circuit_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test PragmaMultiControlledCircuit inputs and involved qubits
+#[test_case(PauliX::new(2).into(), InvolvedQubits::Set(HashSet::from([0, 1, 2])); "Some")]
+#[test_case(DefinitionBit::new("ro".to_string(),1,false).into(), InvolvedQubits::Set(HashSet::from([0, 1])); "None")]
+#[test_case(PragmaRepeatedMeasurement::new("ro".to_string(),10,None).into(), InvolvedQubits::All; "All")]
+fn pragma_multi_controlled_circuit_inputs_qubits(
+    operation: Operation,
+    involved_qubits: InvolvedQubits,
+) {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(operation);
+    let pragma = PragmaMultiControlledCircuit::new(vec![0, 1], circuit.clone());
+
+    // Test inputs are correct
+    assert_eq!(pragma.controlling_qubits(), &vec![0, 1]);
+    assert_eq!(pragma.circuit(), &circuit);
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), involved_qubits);
+}
+
+/// Test PragmaMultiControlledCircuit standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_multi_controlled_circuit_simple_traits() {
+    let pragma = PragmaMultiControlledCircuit::new(vec![0, 1], Circuit::default());
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaMultiControlledCircuit { controlling_qubits: [0, 1], circuit: Circuit { definitions: [], operations: [], _roqoqo_version: RoqoqoVersion } }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaMultiControlledCircuit::new(vec![0, 1], Circuit::default());
+    let pragma_1 = PragmaMultiControlledCircuit::new(vec![0], Circuit::default());
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaMultiControlledCircuit Operate trait
+#[test]
+fn pragma_multi_controlled_circuit_operate_trait() {
+    let pragma = PragmaMultiControlledCircuit::new(vec![0, 1], Circuit::default());
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &[
+        "Operation",
+        "PragmaOperation",
+        "PragmaMultiControlledCircuit",
+    ];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(
+        pragma.hqslang(),
+        String::from("PragmaMultiControlledCircuit")
+    );
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(2, "theta".into()));
+    let pragma = PragmaMultiControlledCircuit::new(vec![0, 1], circuit);
+    assert!(pragma.is_parametrized());
+}
+
+/// Test PragmaMultiControlledCircuit Substitute trait
+#[test]
+fn pragma_multi_controlled_circuit_substitute_trait() {
+    let mut circuit = Circuit::new();
+    circuit.add_operation(RotateX::new(2, "theta".into()));
+    let pragma = PragmaMultiControlledCircuit::new(vec![0, 1], circuit);
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 0.5);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    let mut circuit_substituted = Circuit::new();
+    circuit_substituted.add_operation(RotateX::new(2, 0.5.into()));
+    assert_eq!(
+        result,
+        PragmaMultiControlledCircuit::new(vec![0, 1], circuit_substituted)
+    );
+
+    // (2) Remap qubits function applies to controlling qubits and circuit
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let mut circuit = Circuit::new();
+    circuit.add_operation(PauliX::new(2));
+    let pragma = PragmaMultiControlledCircuit::new(vec![0, 1], circuit);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    let mut circuit_remapped = Circuit::new();
+    circuit_remapped.add_operation(PauliX::new(0));
+    assert_eq!(
+        result,
+        PragmaMultiControlledCircuit::new(vec![2, 1], circuit_remapped)
+    );
+}
+
+/// Test PragmaMultiControlledCircuit Serialization and Deserialization traits (readable)
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_multi_controlled_circuit_serde_readable() {
+    let pragma_serialization = PragmaMultiControlledCircuit::new(vec![0, 1], Circuit::default());
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaMultiControlledCircuit",
+                len: 2,
+            },
+            Token::Str("controlling_qubits"),
+            Token::Seq { len: Some(2) },
+            Token::U64(0),
+            Token::U64(1),
+            Token::SeqEnd,
+            Token::Str("circuit"),
+            Token::Struct {
+                name: "Circuit",
+                len: 3,
+            },
+            Token::Str("definitions"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("operations"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("_roqoqo_version"),
+            Token::Struct {
+                name: "RoqoqoVersionSerializable",
+                len: 2,
+            },
+            Token::Str("major_version"),
+            Token::U32(1),
+            Token::Str("minor_version"),
+            Token::U32(0),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaMultiControlledCircuit JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_multi_controlled_circuit_json_schema() {
+    let op = PragmaMultiControlledCircuit::new(vec![0, 1], Circuit::default());
+
+    // Serialize
+    let test_json = serde_json::to_string(&op).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    // Create JSONSchema
+    let test_schema = schema_for!(PragmaMultiControlledCircuit);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema_value)
+        .unwrap();
+
+    let validation_result = compiled_schema.validate(&test_value);
+    assert!(validation_result.is_ok());
+}
+
 /// Test PragmaAnnotatedOp inputs and involved qubits
 #[test_case(PauliX::new(0).into(), InvolvedQubits::Set(HashSet::from([0])); "Some")]
 #[test_case(DefinitionBit::new("ro".to_string(),1,false).into(), InvolvedQubits::None; "None")]
@@ -1125,6 +1125,12 @@ fn test_is_parametrized_false(gate: SingleQubitGateOperation) {
 #[test_case(SingleQubitGateOperation::from(GPi::new(0, CalculatorFloat::from(PI))); "gpi")]
 #[test_case(SingleQubitGateOperation::from(GPi2::new(0, CalculatorFloat::from(PI))); "gpi2")]
 #[test_case(SingleQubitGateOperation::from(Identity::new(0)); "Identity")]
+#[test_case(SingleQubitGateOperation::from(RotateAxis::new(
+    0,
+    CalculatorFloat::from(PI/3.0),
+    CalculatorFloat::from(1.0),
+    CalculatorFloat::from(2.0),
+    CalculatorFloat::from(-0.5)).unwrap()); "RotateAxis")]
 fn test_singlequbitgates_unitarity(gate: SingleQubitGateOperation) {
     let result: Result<Array2<Complex64>, RoqoqoError> = gate.unitary_matrix();
     let result_matrix: Array2<Complex64> = result.unwrap();
@@ -1299,6 +1305,36 @@ fn test_rotatearoundsphericalaxis_substitute_parameters() {
     );
 }
 
+/// Test substitute parameters for RotateAxis
+#[test]
+fn test_rotateaxis_substitute_parameters() {
+    let gate = RotateAxis::new(
+        0,
+        CalculatorFloat::from("theta"),
+        CalculatorFloat::from("x"),
+        CalculatorFloat::from("y"),
+        CalculatorFloat::from("z"),
+    )
+    .unwrap();
+    assert!(gate.is_parametrized());
+    assert!(!gate.axis_x().is_float());
+    assert!(!gate.axis_y().is_float());
+    assert!(!gate.axis_z().is_float());
+    assert!(!gate.alpha_i().is_float());
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", PI);
+    substitution_dict.set_variable("x", 0.0);
+    substitution_dict.set_variable("y", 0.0);
+    substitution_dict.set_variable("z", 2.0);
+    let result = gate.substitute_parameters(&substitution_dict).unwrap();
+    assert!(!result.is_parametrized());
+    assert_eq!(result.theta().clone(), CalculatorFloat::from(PI));
+    assert_eq!(result.axis_x().clone(), CalculatorFloat::from(0.0));
+    assert_eq!(result.axis_y().clone(), CalculatorFloat::from(0.0));
+    assert_eq!(result.axis_z().clone(), CalculatorFloat::from(1.0));
+    assert!((f64::try_from(result.alpha_i()).unwrap() + 1.0).abs() < 1e-12);
+}
+
 /// Test substitute parameters for RotateXY
 #[test]
 fn test_rotatexy_substitute_parameters() {
@@ -2382,6 +2418,21 @@ fn test_rotatearoundsphericalaxis_powerfc(
         "Identity",
         ];
     "Identity")]
+#[test_case(
+    SingleQubitGateOperation::from(RotateAxis::new(
+        0,
+        CalculatorFloat::from(0),
+        CalculatorFloat::from(0),
+        CalculatorFloat::from(0),
+        CalculatorFloat::from(1)).unwrap()),
+    vec![
+        "Operation",
+        "GateOperation",
+        "SingleQubitGateOperation",
+        "Rotation",
+        "RotateAxis",
+        ];
+    "RotateAxis")]
 pub fn test_tags(gate: SingleQubitGateOperation, tags: Vec<&str>) {
     let range = 0..tags.len();
     for i in range {
@@ -2426,6 +2477,12 @@ pub fn test_tags(gate: SingleQubitGateOperation, tags: Vec<&str>) {
 #[test_case(SingleQubitGateOperation::from(GPi::new(0, CalculatorFloat::from(PI/2.0))); "GPi")]
 #[test_case(SingleQubitGateOperation::from(GPi2::new(0, CalculatorFloat::from(PI/2.0))); "GPi2")]
 #[test_case(SingleQubitGateOperation::from(Identity::new(0)); "Identity")]
+#[test_case(SingleQubitGateOperation::from(RotateAxis::new(
+    0,
+    CalculatorFloat::from("theta"),
+    CalculatorFloat::from("x"),
+    CalculatorFloat::from("y"),
+    CalculatorFloat::from("z")).unwrap()); "RotateAxis")]
 pub fn test_json_schema_single_qubit_gate_operations(gate: SingleQubitGateOperation) {
     // Serialize
     let test_json = match gate.clone() {
@@ -2450,6 +2507,7 @@ pub fn test_json_schema_single_qubit_gate_operations(gate: SingleQubitGateOperat
         SingleQubitGateOperation::GPi(op) => serde_json::to_string(&op).unwrap(),
         SingleQubitGateOperation::GPi2(op) => serde_json::to_string(&op).unwrap(),
         SingleQubitGateOperation::Identity(op) => serde_json::to_string(&op).unwrap(),
+        SingleQubitGateOperation::RotateAxis(op) => serde_json::to_string(&op).unwrap(),
         _ => unreachable!(),
     };
     let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
@@ -2477,6 +2535,7 @@ pub fn test_json_schema_single_qubit_gate_operations(gate: SingleQubitGateOperat
         SingleQubitGateOperation::GPi(_) => schema_for!(GPi),
         SingleQubitGateOperation::GPi2(_) => schema_for!(GPi2),
         SingleQubitGateOperation::Identity(_) => schema_for!(Identity),
+        SingleQubitGateOperation::RotateAxis(_) => schema_for!(RotateAxis),
         _ => unreachable!(),
     };
     let schema = serde_json::to_string(&test_schema).unwrap();
@@ -2489,3 +2548,104 @@ pub fn test_json_schema_single_qubit_gate_operations(gate: SingleQubitGateOperat
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Test that RotateAxis around the z axis by pi is PauliZ up to a global phase
+#[test]
+fn test_rotateaxis_pauliz() {
+    let gate = RotateAxis::new(
+        0,
+        CalculatorFloat::PI,
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(1.0),
+    )
+    .unwrap();
+    let matrix = gate.unitary_matrix().unwrap();
+    let pauli_z = PauliZ::new(0).unitary_matrix().unwrap();
+    // RotateAxis(pi, z) = -i * PauliZ
+    let phase = Complex64::new(0.0, -1.0);
+    for (value, expected) in matrix.iter().zip(pauli_z.iter()) {
+        assert!((value - phase * expected).norm() < 1e-12);
+    }
+}
+
+/// Test that RotateAxis matches RotateAroundSphericalAxis and normalizes the rotation axis
+#[test_case(0.3, 1.2, 0.7; "generic")]
+#[test_case(PI, PI / 2.0, 0.0; "x_axis")]
+#[test_case(-1.0, 0.2, PI; "negative")]
+fn test_rotateaxis_spherical(theta: f64, spherical_theta: f64, spherical_phi: f64) {
+    let scale = 3.5;
+    let gate = RotateAxis::new(
+        0,
+        CalculatorFloat::from(theta),
+        CalculatorFloat::from(scale * spherical_theta.sin() * spherical_phi.cos()),
+        CalculatorFloat::from(scale * spherical_theta.sin() * spherical_phi.sin()),
+        CalculatorFloat::from(scale * spherical_theta.cos()),
+    )
+    .unwrap();
+    let spherical_gate = RotateAroundSphericalAxis::new(
+        0,
+        CalculatorFloat::from(theta),
+        CalculatorFloat::from(spherical_theta),
+        CalculatorFloat::from(spherical_phi),
+    );
+    let matrix = gate.unitary_matrix().unwrap();
+    let spherical_matrix = spherical_gate.unitary_matrix().unwrap();
+    for (value, expected) in matrix.iter().zip(spherical_matrix.iter()) {
+        assert!((value - expected).norm() < 1e-12);
+    }
+    let single_qubit_gate: SingleQubitGate = gate.to_single_qubit_gate();
+    let single_qubit_matrix = single_qubit_gate.unitary_matrix().unwrap();
+    for (value, expected) in single_qubit_matrix.iter().zip(matrix.iter()) {
+        assert!((value - expected).norm() < 1e-12);
+    }
+}
+
+/// Test that RotateAxis normalizes the rotation axis on construction
+#[test]
+fn test_rotateaxis_normalized_axis() {
+    let gate = RotateAxis::new(
+        0,
+        CalculatorFloat::PI,
+        CalculatorFloat::from(3.0),
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(4.0),
+    )
+    .unwrap();
+    assert_eq!(gate.axis_x().clone(), CalculatorFloat::from(0.6));
+    assert_eq!(gate.axis_y().clone(), CalculatorFloat::from(0.0));
+    assert_eq!(gate.axis_z().clone(), CalculatorFloat::from(0.8));
+    assert_eq!(gate.beta_r(), CalculatorFloat::from(0.0));
+    assert!((f64::try_from(gate.beta_i()).unwrap() + 0.6).abs() < 1e-12);
+    assert!((f64::try_from(gate.alpha_i()).unwrap() + 0.8).abs() < 1e-12);
+}
+
+/// Test that RotateAxis with a zero length axis returns an error
+#[test]
+fn test_rotateaxis_zero_axis() {
+    let gate = RotateAxis::new(
+        0,
+        CalculatorFloat::PI,
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(0.0),
+    );
+    assert_eq!(
+        gate,
+        Err(RoqoqoError::GenericError {
+            msg: "The rotation axis of RotateAxis must not have zero length".to_string()
+        })
+    );
+
+    let gate = RotateAxis::new(
+        0,
+        CalculatorFloat::PI,
+        CalculatorFloat::from("x"),
+        CalculatorFloat::from(0.0),
+        CalculatorFloat::from(0.0),
+    )
+    .unwrap();
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("x", 0.0);
+    assert!(gate.substitute_parameters(&substitution_dict).is_err());
+}
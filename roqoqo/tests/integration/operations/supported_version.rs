@@ -13,7 +13,6 @@
 //! Integration test for supported version trait
 
 use ndarray::array;
-#[cfg(feature = "unstable_operation_definition")]
 use qoqo_calculator::CalculatorFloat;
 #[cfg(feature = "circuitdag")]
 use roqoqo::measurements::Cheated;
@@ -83,6 +82,23 @@ fn test_version_1_8_0_two_qubit_gate(operation: operations::TwoQubitGateOperatio
     assert_eq!(op.minimum_supported_roqoqo_version(), (1, 8, 0));
 }
 
+#[test_case(operations::TwoQubitGateOperation::from(operations::ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(operations::TwoQubitGateOperation::from(operations::SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(operations::TwoQubitGateOperation::from(operations::InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
+#[test_case(operations::TwoQubitGateOperation::from(operations::ZXCoupling::new(0, 1, 0.1.into())); "ZXCoupling")]
+fn test_version_1_14_0_two_qubit_gate(operation: operations::TwoQubitGateOperation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 14, 0));
+    let op = operations::Operation::from(operation);
+    assert_eq!(op.minimum_supported_roqoqo_version(), (1, 14, 0));
+}
+
+#[test_case(operations::SingleQubitGateOperation::from(operations::RotateAxis::new(0, 0.1.into(), 0.0.into(), 0.0.into(), 1.0.into()).unwrap()); "RotateAxis")]
+fn test_version_1_14_0_single_qubit_gate(operation: operations::SingleQubitGateOperation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 14, 0));
+    let op = operations::Operation::from(operation);
+    assert_eq!(op.minimum_supported_roqoqo_version(), (1, 14, 0));
+}
+
 #[test_case(operations::ThreeQubitGateOperation::from(operations::ControlledControlledPauliZ::new(0, 1, 2)); "ControlledControlledPauliZ")]
 #[test_case(operations::ThreeQubitGateOperation::from(operations::ControlledControlledPhaseShift::new(0, 1, 2, 0.1.into())); "ControlledControlledPhaseShift")]
 #[test_case(operations::ThreeQubitGateOperation::from(operations::Toffoli::new(0, 1, 2)); "Toffoli")]
@@ -128,7 +144,14 @@ fn test_version_1_0_0_multi_qubit_gate(operation: operations::MultiQubitGateOper
     assert_eq!(op.minimum_supported_roqoqo_version(), (1, 0, 0));
 }
 
-#[cfg(feature = "unstable_operation_definition")]
+#[test_case(operations::MultiQubitGateOperation::from(operations::QFT::new(vec![0,1,2], false, true)); "QFT")]
+#[test_case(operations::MultiQubitGateOperation::from(operations::MultiQubitCNOT::new(vec![0,1,2])); "MultiQubitCNOT")]
+fn test_version_1_14_0_multi_qubit_gate(operation: operations::MultiQubitGateOperation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 14, 0));
+    let op = operations::Operation::from(operation);
+    assert_eq!(op.minimum_supported_roqoqo_version(), (1, 14, 0));
+}
+
 #[test_case(operations::MultiQubitOperation::from(operations::CallDefinedGate::new("test".into(), vec![0,1,2,3], vec![CalculatorFloat::Float(1.0)])); "CallDefinedGate")]
 fn test_version_1_13_0_multi_qubit_gate(operation: operations::MultiQubitOperation) {
     assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 13, 0));
@@ -257,6 +280,17 @@ fn test_version_1_8_0_pragmas(operation: operations::Operation) {
     assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 8, 0));
 }
 
+#[test_case(operations::Operation::from(operations::PragmaSetStabilizerState::new(vec!["XX".to_string(), "ZZ".to_string()])); "PragmaSetStabilizerState")]
+#[test_case(operations::Operation::from(operations::PragmaCustomTwoQubitGate::new(0, 1, ndarray::Array2::eye(4))); "PragmaCustomTwoQubitGate")]
+#[test_case(operations::Operation::from(operations::PragmaMultiControlledCircuit::new(vec![0, 1], roqoqo::Circuit::new())); "PragmaMultiControlledCircuit")]
+#[test_case(operations::Operation::from(operations::PragmaSimulationRepetitions::new(100)); "PragmaSimulationRepetitions")]
+#[test_case(operations::Operation::from(operations::PragmaQubitSleep::new(std::collections::HashMap::from([(0, 1.0.into())]))); "PragmaQubitSleep")]
+#[test_case(operations::Operation::from(operations::PragmaGetPauliProducts::new(vec![std::collections::HashMap::from([(0, 3)])], "ro".to_string(), roqoqo::Circuit::new())); "PragmaGetPauliProducts")]
+#[test_case(operations::Operation::from(operations::PragmaGetReducedDensityMatrix::new("ro".to_string(), vec![0], None)); "PragmaGetReducedDensityMatrix")]
+fn test_version_1_14_0_pragmas(operation: operations::Operation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 14, 0));
+}
+
 #[cfg(feature = "circuitdag")]
 #[test_case(roqoqo::Circuit::from_iter(vec![
     operations::Operation::from(operations::DefinitionBit::new("ro".to_string(), 2, true))
@@ -353,7 +387,6 @@ fn test_version_circuit(circuit: roqoqo::Circuit, version: (u32, u32, u32)) {
     assert_eq!(program.minimum_supported_roqoqo_version(), version);
 }
 
-#[cfg(feature = "unstable_operation_definition")]
 #[test_case(operations::Operation::from(operations::GateDefinition::new(roqoqo::Circuit::new(), "name".to_string(), vec![2], vec!["name".to_owned()])); "GateDefinition")]
 fn test_version_1_13_0_gate_definition(operation: operations::Operation) {
     assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 13, 0));
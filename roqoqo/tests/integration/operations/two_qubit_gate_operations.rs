@@ -112,7 +112,15 @@ fn kak_sigma_matrix(
 #[test_case(TwoQubitGateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(TwoQubitGateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(TwoQubitGateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "VariableMSXX")]
+#[test_case(TwoQubitGateOperation::from(RXX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "RXX")]
+#[test_case(TwoQubitGateOperation::from(RYY::new(0, 1, CalculatorFloat::FRAC_PI_2)); "RYY")]
+#[test_case(TwoQubitGateOperation::from(RZZ::new(0, 1, CalculatorFloat::FRAC_PI_2)); "RZZ")]
+#[test_case(TwoQubitGateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ZXCoupling")]
 #[test_case(TwoQubitGateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX_pi")]
+#[test_case(TwoQubitGateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX_pi")]
+#[test_case(TwoQubitGateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY_pi")]
+#[test_case(TwoQubitGateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ_pi")]
+#[test_case(TwoQubitGateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling_pi")]
 #[test_case(TwoQubitGateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(TwoQubitGateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(TwoQubitGateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -136,6 +144,9 @@ fn kak_sigma_matrix(
 #[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_4, CalculatorFloat::ZERO)); "ControlledRotateXY_pi_4_zero")]
 #[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_2)); "ControlledRotateXY_pi_2_pi_2")]
 #[test_case(TwoQubitGateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(TwoQubitGateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(TwoQubitGateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(TwoQubitGateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_kakdecomposition(gate: TwoQubitGateOperation) {
     // k vector
     let k = gate.kak_decomposition().k_vector;
@@ -255,6 +266,10 @@ fn test_kakdecomposition(gate: TwoQubitGateOperation) {
 #[test_case(GateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(GateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(GateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(GateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(GateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(GateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(GateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(GateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(GateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(GateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -279,6 +294,9 @@ fn test_kakdecomposition(gate: TwoQubitGateOperation) {
 #[test_case(GateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_4, CalculatorFloat::ZERO)); "ControlledRotateXY_pi_4_zero")]
 #[test_case(GateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::ZERO, CalculatorFloat::FRAC_PI_2)); "ControlledRotateXY_zero_pi_2")]
 #[test_case(GateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(GateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(GateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(GateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_twoqubitgates_unitarity(gate: GateOperation) {
     let result: Result<Array2<Complex64>, RoqoqoError> = gate.unitary_matrix();
     let result_array: Array2<Complex64> = result.unwrap();
@@ -310,6 +328,10 @@ fn test_twoqubitgates_unitarity(gate: GateOperation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -323,6 +345,9 @@ fn test_twoqubitgates_unitarity(gate: GateOperation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_twoqubitgates_clone(gate1: Operation) {
     #[allow(clippy::redundant_clone)]
     let gate2 = gate1.clone();
@@ -341,6 +366,10 @@ fn test_twoqubitgates_clone(gate1: Operation) {
 #[test_case(TwoQubitGateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(TwoQubitGateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(TwoQubitGateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(TwoQubitGateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(TwoQubitGateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(TwoQubitGateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(TwoQubitGateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(TwoQubitGateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(TwoQubitGateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(TwoQubitGateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -354,6 +383,9 @@ fn test_twoqubitgates_clone(gate1: Operation) {
 #[test_case(TwoQubitGateOperation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(TwoQubitGateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(TwoQubitGateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(TwoQubitGateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(TwoQubitGateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_qubits_twoqubitgates(gate: TwoQubitGateOperation) {
     let control: &usize = gate.control();
     assert_eq!(control, &0);
@@ -378,6 +410,10 @@ fn test_qubits_twoqubitgates(gate: TwoQubitGateOperation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -391,6 +427,9 @@ fn test_qubits_twoqubitgates(gate: TwoQubitGateOperation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_is_parametrized_false(gate: Operation) {
     let bool_parameter = gate.is_parametrized();
     assert!(!bool_parameter);
@@ -399,6 +438,10 @@ fn test_is_parametrized_false(gate: Operation) {
 #[test_case(Operation::from(XY::new(0, 1, CalculatorFloat::from("parameter"))); "XY")]
 #[test_case(Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::from("parameter"))); "ControlledPhaseShift")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("parameter"))); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("parameter"))); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("parameter"))); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("parameter"))); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("parameter"))); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from("parameter1"), CalculatorFloat::from("parameter2"))); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from("parameter1"), CalculatorFloat::from("parameter2"))); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from("x"), CalculatorFloat::from("y"), CalculatorFloat::from("z"))); "Qsim")]
@@ -431,6 +474,10 @@ fn test_is_parametrized_true(gate: Operation) {
 #[test_case("ControlledPauliZ", Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case("MolmerSorensenXX", Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case("VariableMSXX", Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case("RXX", Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case("RYY", Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case("RZZ", Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case("ZXCoupling", Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case("GivensRotation", Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case("GivensRotationLittleEndian", Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case("Qsim", Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -444,6 +491,9 @@ fn test_is_parametrized_true(gate: Operation) {
 #[test_case("ControlledRotateX", Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::from("theta"))); "ControlledRotateX")]
 #[test_case("ControlledRotateXY", Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::from("theta"), CalculatorFloat::from("phi"))); "ControlledRotateXY")]
 #[test_case("EchoCrossResonance", Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case("ControlledHadamard", Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case("SqrtCNOT", Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case("InvSqrtCNOT", Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_twoqubitgateoperations_hqslang(name: &'static str, gate: Operation) {
     assert!(!gate.hqslang().is_empty());
     assert_eq!(gate.hqslang(), name);
@@ -485,6 +535,18 @@ fn test_twoqubitgateoperations_hqslang(name: &'static str, gate: Operation) {
 #[test_case(
     GateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)),
     GateOperation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    GateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)),
+    GateOperation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    GateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)),
+    GateOperation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    GateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)),
+    GateOperation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    GateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)),
+    GateOperation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     GateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)),
     GateOperation::from(GivensRotation::new(1, 0, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
@@ -524,6 +586,15 @@ fn test_twoqubitgateoperations_hqslang(name: &'static str, gate: Operation) {
 #[test_case(
     GateOperation::from(EchoCrossResonance::new(0, 1)),
     GateOperation::from(EchoCrossResonance::new(1, 0)); "EchoCrossResonance")]
+#[test_case(
+    GateOperation::from(ControlledHadamard::new(0, 1)),
+    GateOperation::from(ControlledHadamard::new(1, 0)); "ControlledHadamard")]
+#[test_case(
+    GateOperation::from(SqrtCNOT::new(0, 1)),
+    GateOperation::from(SqrtCNOT::new(1, 0)); "SqrtCNOT")]
+#[test_case(
+    GateOperation::from(InvSqrtCNOT::new(0, 1)),
+    GateOperation::from(InvSqrtCNOT::new(1, 0)); "InvSqrtCNOT")]
 fn remap_qubits_result(gate: GateOperation, test_gate: GateOperation) {
     let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
     qubit_mapping.insert(0, 1);
@@ -544,6 +615,10 @@ fn remap_qubits_result(gate: GateOperation, test_gate: GateOperation) {
 #[test_case(GateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(GateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(GateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(GateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(GateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(GateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(GateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(GateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(GateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(GateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -557,6 +632,9 @@ fn remap_qubits_result(gate: GateOperation, test_gate: GateOperation) {
 #[test_case(GateOperation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(GateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(GateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(GateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(GateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(GateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn remap_qubits_error0(gate: GateOperation) {
     let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
     qubit_mapping.insert(1, 0);
@@ -576,6 +654,10 @@ fn remap_qubits_error0(gate: GateOperation) {
 #[test_case(GateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(GateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(GateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(GateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(GateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(GateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(GateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(GateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(GateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(GateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -589,6 +671,9 @@ fn remap_qubits_error0(gate: GateOperation) {
 #[test_case(GateOperation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(GateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(GateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(GateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(GateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(GateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn remap_qubits_error1(gate: GateOperation) {
     let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
     qubit_mapping.insert(0, 2);
@@ -695,6 +780,42 @@ fn remap_qubits_error1(gate: GateOperation) {
         "VariableMSXX",
         ],
     Operation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "RXX",
+        ],
+    Operation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "RYY",
+        ],
+    Operation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "RZZ",
+        ],
+    Operation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "Rotation",
+        "ZXCoupling",
+        ],
+    Operation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     vec![
         "Operation",
@@ -803,6 +924,30 @@ fn remap_qubits_error1(gate: GateOperation) {
         "EchoCrossResonance"
     ],
     Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "ControlledHadamard"
+    ],
+    Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "SqrtCNOT"
+    ],
+    Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(
+    vec![
+        "Operation",
+        "GateOperation",
+        "TwoQubitGateOperation",
+        "InvSqrtCNOT"
+    ],
+    Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 pub fn test_tags(tags: Vec<&str>, gate: Operation) {
     let range = 0..tags.len();
     for i in range {
@@ -846,6 +991,18 @@ pub fn test_tags(tags: Vec<&str>, gate: Operation) {
 #[test_case(
     "VariableMSXX(VariableMSXX { control: 1, target: 0, theta: Float(3.141592653589793) })",
     Operation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    "RXX(RXX { control: 1, target: 0, theta: Float(3.141592653589793) })",
+    Operation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    "RYY(RYY { control: 1, target: 0, theta: Float(3.141592653589793) })",
+    Operation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    "RZZ(RZZ { control: 1, target: 0, theta: Float(3.141592653589793) })",
+    Operation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    "ZXCoupling(ZXCoupling { control: 1, target: 0, theta: Float(3.141592653589793) })",
+    Operation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     "GivensRotation(GivensRotation { control: 1, target: 0, theta: Float(0.0), phi: Float(0.0) })",
     Operation::from(GivensRotation::new(1, 0, CalculatorFloat::ZERO, CalculatorFloat::ZERO)); "GivensRotation")]
@@ -885,6 +1042,15 @@ pub fn test_tags(tags: Vec<&str>, gate: Operation) {
 #[test_case(
     "EchoCrossResonance(EchoCrossResonance { control: 1, target: 0 })",
     Operation::from(EchoCrossResonance::new(1, 0)); "EchoCrossResonance")]
+#[test_case(
+    "ControlledHadamard(ControlledHadamard { control: 1, target: 0 })",
+    Operation::from(ControlledHadamard::new(1, 0)); "ControlledHadamard")]
+#[test_case(
+    "SqrtCNOT(SqrtCNOT { control: 1, target: 0 })",
+    Operation::from(SqrtCNOT::new(1, 0)); "SqrtCNOT")]
+#[test_case(
+    "InvSqrtCNOT(InvSqrtCNOT { control: 1, target: 0 })",
+    Operation::from(InvSqrtCNOT::new(1, 0)); "InvSqrtCNOT")]
 fn test_two_qubitgates_debug(message: &'static str, gate: Operation) {
     assert_eq!(format!("{:?}", gate), message);
 }
@@ -926,6 +1092,18 @@ fn test_two_qubitgates_debug(message: &'static str, gate: Operation) {
 #[test_case(
     Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)),
     Operation::from(VariableMSXX::new(1, 0, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(
+    Operation::from(RXX::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(RXX::new(1, 0, CalculatorFloat::PI)); "RXX")]
+#[test_case(
+    Operation::from(RYY::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(RYY::new(1, 0, CalculatorFloat::PI)); "RYY")]
+#[test_case(
+    Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(RZZ::new(1, 0, CalculatorFloat::PI)); "RZZ")]
+#[test_case(
+    Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)),
+    Operation::from(ZXCoupling::new(1, 0, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(
     Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)),
     Operation::from(GivensRotation::new(1, 0, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
@@ -965,6 +1143,15 @@ fn test_two_qubitgates_debug(message: &'static str, gate: Operation) {
 #[test_case(
     Operation::from(EchoCrossResonance::new(0, 1)),
     Operation::from(EchoCrossResonance::new(1, 0)); "EchoCrossResonance")]
+#[test_case(
+    Operation::from(ControlledHadamard::new(0, 1)),
+    Operation::from(ControlledHadamard::new(1, 0)); "ControlledHadamard")]
+#[test_case(
+    Operation::from(SqrtCNOT::new(0, 1)),
+    Operation::from(SqrtCNOT::new(1, 0)); "SqrtCNOT")]
+#[test_case(
+    Operation::from(InvSqrtCNOT::new(0, 1)),
+    Operation::from(InvSqrtCNOT::new(1, 0)); "InvSqrtCNOT")]
 fn test_twoqubitgates_partialeq(gate1: Operation, gate2: Operation) {
     assert!(gate1 == gate1.clone());
     assert_eq!(gate1, gate1.clone());
@@ -979,6 +1166,14 @@ fn test_twoqubitgates_partialeq(gate1: Operation, gate2: Operation) {
             Rotation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::FRAC_PI_4 * 1.5)); "ControlledPhaseShift")]
 #[test_case(Rotation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)),
             Rotation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI * 1.5)); "VariableMSXX")]
+#[test_case(Rotation::from(RXX::new(0, 1, CalculatorFloat::PI)),
+            Rotation::from(RXX::new(0, 1, CalculatorFloat::PI * 1.5)); "RXX")]
+#[test_case(Rotation::from(RYY::new(0, 1, CalculatorFloat::PI)),
+            Rotation::from(RYY::new(0, 1, CalculatorFloat::PI * 1.5)); "RYY")]
+#[test_case(Rotation::from(RZZ::new(0, 1, CalculatorFloat::PI)),
+            Rotation::from(RZZ::new(0, 1, CalculatorFloat::PI * 1.5)); "RZZ")]
+#[test_case(Rotation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)),
+            Rotation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI * 1.5)); "ZXCoupling")]
 #[test_case(Rotation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)),
             Rotation::from(GivensRotation::new(0, 1, CalculatorFloat::PI * 1.5, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Rotation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)),
@@ -1005,7 +1200,15 @@ fn test_rotate_powercf(gate: Rotation, gate2: Rotation) {
 #[test_case(Operation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(Operation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::FRAC_PI_2)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::FRAC_PI_2)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ZXCoupling")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX_pi")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX_pi")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY_pi")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ_pi")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling_pi")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -1021,6 +1224,9 @@ fn test_rotate_powercf(gate: Rotation, gate2: Rotation) {
 #[test_case(Operation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(Operation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(Operation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(Operation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(Operation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(Operation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 fn test_ineffective_substitute_parameters(gate: Operation) {
     let mut substitution_dict: Calculator = Calculator::new();
     substitution_dict.set_variable("theta", 0.0);
@@ -1035,6 +1241,14 @@ fn test_ineffective_substitute_parameters(gate: Operation) {
             Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::ZERO)); "ControlledPhaseShift")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("theta"))),
             Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::ZERO)); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("theta"))),
+            Operation::from(RXX::new(0, 1, CalculatorFloat::ZERO)); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("theta"))),
+            Operation::from(RYY::new(0, 1, CalculatorFloat::ZERO)); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("theta"))),
+            Operation::from(RZZ::new(0, 1, CalculatorFloat::ZERO)); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("theta"))),
+            Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::ZERO)); "ZXCoupling")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from("theta"), CalculatorFloat::FRAC_PI_4)),
             Operation::from(GivensRotation::new(0, 1, CalculatorFloat::ZERO, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from("theta"), CalculatorFloat::FRAC_PI_4)),
@@ -1070,7 +1284,15 @@ fn test_substitute_parameters(gate: Operation, gate2: Operation) {
 #[test_case(Operation::from(XY::new(0, 1, CalculatorFloat::from("theta"))); "XY")]
 #[test_case(Operation::from(ControlledPhaseShift::new(0, 1, CalculatorFloat::from("theta"))); "ControlledPhaseShift")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("theta"))); "VariableMSXX")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("theta"))); "RXX")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("theta"))); "RYY")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("theta"))); "RZZ")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("theta"))); "ZXCoupling")]
 #[test_case(Operation::from(VariableMSXX::new(0, 1, CalculatorFloat::from("theta"))); "VariableMSXX_pi")]
+#[test_case(Operation::from(RXX::new(0, 1, CalculatorFloat::from("theta"))); "RXX_pi")]
+#[test_case(Operation::from(RYY::new(0, 1, CalculatorFloat::from("theta"))); "RYY_pi")]
+#[test_case(Operation::from(RZZ::new(0, 1, CalculatorFloat::from("theta"))); "RZZ_pi")]
+#[test_case(Operation::from(ZXCoupling::new(0, 1, CalculatorFloat::from("theta"))); "ZXCoupling_pi")]
 #[test_case(Operation::from(GivensRotation::new(0, 1, CalculatorFloat::from("theta"), CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(Operation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::from("theta"), CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(Operation::from(Qsim::new(0, 1, CalculatorFloat::from("theta"), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -1256,6 +1478,10 @@ fn test_kakdecomposition_debug() {
 #[test_case(TwoQubitGateOperation::from(ControlledPauliZ::new(0, 1)); "ControlledPauliZ")]
 #[test_case(TwoQubitGateOperation::from(MolmerSorensenXX::new(0, 1)); "MolmerSorensenXX")]
 #[test_case(TwoQubitGateOperation::from(VariableMSXX::new(0, 1, CalculatorFloat::PI)); "VariableMSXX")]
+#[test_case(TwoQubitGateOperation::from(RXX::new(0, 1, CalculatorFloat::PI)); "RXX")]
+#[test_case(TwoQubitGateOperation::from(RYY::new(0, 1, CalculatorFloat::PI)); "RYY")]
+#[test_case(TwoQubitGateOperation::from(RZZ::new(0, 1, CalculatorFloat::PI)); "RZZ")]
+#[test_case(TwoQubitGateOperation::from(ZXCoupling::new(0, 1, CalculatorFloat::PI)); "ZXCoupling")]
 #[test_case(TwoQubitGateOperation::from(GivensRotation::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotation")]
 #[test_case(TwoQubitGateOperation::from(GivensRotationLittleEndian::new(0, 1, CalculatorFloat::PI, CalculatorFloat::FRAC_PI_4)); "GivensRotationLittleEndian")]
 #[test_case(TwoQubitGateOperation::from(Qsim::new(0, 1, CalculatorFloat::from(1.0), CalculatorFloat::from(1.0), CalculatorFloat::from(-1.0))); "Qsim")]
@@ -1269,6 +1495,9 @@ fn test_kakdecomposition_debug() {
 #[test_case(TwoQubitGateOperation::from(ControlledRotateX::new(0, 1, CalculatorFloat::FRAC_PI_2)); "ControlledRotateX")]
 #[test_case(TwoQubitGateOperation::from(ControlledRotateXY::new(0, 1, CalculatorFloat::FRAC_PI_2, CalculatorFloat::FRAC_PI_4)); "ControlledRotateXY")]
 #[test_case(TwoQubitGateOperation::from(EchoCrossResonance::new(0, 1)); "EchoCrossResonance")]
+#[test_case(TwoQubitGateOperation::from(ControlledHadamard::new(0, 1)); "ControlledHadamard")]
+#[test_case(TwoQubitGateOperation::from(SqrtCNOT::new(0, 1)); "SqrtCNOT")]
+#[test_case(TwoQubitGateOperation::from(InvSqrtCNOT::new(0, 1)); "InvSqrtCNOT")]
 pub fn test_json_schema_two_qubit_gate_operations(gate: TwoQubitGateOperation) {
     // Serialize
     let test_json = match gate.clone() {
@@ -1301,6 +1530,13 @@ pub fn test_json_schema_two_qubit_gate_operations(gate: TwoQubitGateOperation) {
         TwoQubitGateOperation::ControlledRotateX(op) => serde_json::to_string(&op).unwrap(),
         TwoQubitGateOperation::ControlledRotateXY(op) => serde_json::to_string(&op).unwrap(),
         TwoQubitGateOperation::EchoCrossResonance(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::RXX(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::RYY(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::RZZ(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::ZXCoupling(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::ControlledHadamard(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::SqrtCNOT(op) => serde_json::to_string(&op).unwrap(),
+        TwoQubitGateOperation::InvSqrtCNOT(op) => serde_json::to_string(&op).unwrap(),
         _ => unreachable!(),
     };
     let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
@@ -1336,6 +1572,13 @@ pub fn test_json_schema_two_qubit_gate_operations(gate: TwoQubitGateOperation) {
         TwoQubitGateOperation::ControlledRotateX(_) => schema_for!(ControlledRotateX),
         TwoQubitGateOperation::ControlledRotateXY(_) => schema_for!(ControlledRotateXY),
         TwoQubitGateOperation::EchoCrossResonance(_) => schema_for!(EchoCrossResonance),
+        TwoQubitGateOperation::RXX(_) => schema_for!(RXX),
+        TwoQubitGateOperation::RYY(_) => schema_for!(RYY),
+        TwoQubitGateOperation::RZZ(_) => schema_for!(RZZ),
+        TwoQubitGateOperation::ZXCoupling(_) => schema_for!(ZXCoupling),
+        TwoQubitGateOperation::ControlledHadamard(_) => schema_for!(ControlledHadamard),
+        TwoQubitGateOperation::SqrtCNOT(_) => schema_for!(SqrtCNOT),
+        TwoQubitGateOperation::InvSqrtCNOT(_) => schema_for!(InvSqrtCNOT),
         _ => unreachable!(),
     };
     let schema = serde_json::to_string(&test_schema).unwrap();
@@ -1348,3 +1591,105 @@ pub fn test_json_schema_two_qubit_gate_operations(gate: TwoQubitGateOperation) {
     let validation_result = compiled_schema.validate(&test_value);
     assert!(validation_result.is_ok());
 }
+
+/// Test that the Ising rotation gates are related by the expected basis changes
+#[test_case(CalculatorFloat::FRAC_PI_2; "pi_half")]
+#[test_case(CalculatorFloat::from(0.7); "float")]
+fn test_ising_rotations_unitary(theta: CalculatorFloat) {
+    // RXX is identical to VariableMSXX
+    let rxx = RXX::new(0, 1, theta.clone()).unitary_matrix().unwrap();
+    let msxx = VariableMSXX::new(0, 1, theta.clone())
+        .unitary_matrix()
+        .unwrap();
+    assert!((rxx.clone() - msxx).iter().all(|x| x.norm() < 1e-12));
+
+    // RZZ = (H x H) RXX (H x H)
+    let hadamard = Hadamard::new(0).unitary_matrix().unwrap();
+    let mut hh: Array2<Complex64> = Array2::zeros((4, 4));
+    for ((i, j), h) in hadamard.indexed_iter() {
+        for ((k, l), h2) in hadamard.indexed_iter() {
+            hh[(2 * i + k, 2 * j + l)] = h * h2;
+        }
+    }
+    let rzz = RZZ::new(0, 1, theta.clone()).unitary_matrix().unwrap();
+    let transformed = hh.dot(&rxx).dot(&hh);
+    assert!((rzz - transformed).iter().all(|x| x.norm() < 1e-12));
+
+    // RYY = (RotateX(pi/2) x RotateX(pi/2)) RZZ (RotateX(-pi/2) x RotateX(-pi/2))
+    let rx = RotateX::new(0, CalculatorFloat::FRAC_PI_2)
+        .unitary_matrix()
+        .unwrap();
+    let rx_inv = RotateX::new(0, -CalculatorFloat::FRAC_PI_2)
+        .unitary_matrix()
+        .unwrap();
+    let mut rxrx: Array2<Complex64> = Array2::zeros((4, 4));
+    let mut rxrx_inv: Array2<Complex64> = Array2::zeros((4, 4));
+    for ((i, j), a) in rx.indexed_iter() {
+        for ((k, l), b) in rx.indexed_iter() {
+            rxrx[(2 * i + k, 2 * j + l)] = a * b;
+            rxrx_inv[(2 * i + k, 2 * j + l)] = rx_inv[(i, j)] * rx_inv[(k, l)];
+        }
+    }
+    let ryy = RYY::new(0, 1, theta.clone()).unitary_matrix().unwrap();
+    let rzz = RZZ::new(0, 1, theta).unitary_matrix().unwrap();
+    let transformed = rxrx.dot(&rzz).dot(&rxrx_inv);
+    assert!((ryy - transformed).iter().all(|x| x.norm() < 1e-12));
+}
+
+/// Test that ControlledHadamard applies the Hadamard gate on the target when the control is set
+#[test]
+fn test_controlled_hadamard_unitary() {
+    let gate = ControlledHadamard::new(0, 1);
+    let matrix = gate.unitary_matrix().unwrap();
+    let hadamard = Hadamard::new(1).unitary_matrix().unwrap();
+    for i in 0..2 {
+        for j in 0..2 {
+            let identity_value = if i == j { 1.0 } else { 0.0 };
+            assert!((matrix[(i, j)] - Complex64::new(identity_value, 0.0)).norm() < 1e-12);
+            assert!((matrix[(i + 2, j + 2)] - hadamard[(i, j)]).norm() < 1e-12);
+            assert!(matrix[(i, j + 2)].norm() < 1e-12);
+            assert!(matrix[(i + 2, j)].norm() < 1e-12);
+        }
+    }
+    assert_eq!(
+        gate.involved_qubits(),
+        InvolvedQubits::Set(HashSet::from([0, 1]))
+    );
+}
+
+/// Test that the square root CNOT gates square to CNOT and invert each other
+#[test]
+fn test_sqrt_cnot_unitary() {
+    let sqrt_cnot = SqrtCNOT::new(0, 1).unitary_matrix().unwrap();
+    let inv_sqrt_cnot = InvSqrtCNOT::new(0, 1).unitary_matrix().unwrap();
+    let cnot = CNOT::new(0, 1).unitary_matrix().unwrap();
+    let identity: Array2<Complex64> = Array2::eye(4);
+
+    let squared = sqrt_cnot.dot(&sqrt_cnot);
+    assert!((squared - cnot).iter().all(|x| x.norm() < 1e-12));
+    let product = inv_sqrt_cnot.dot(&sqrt_cnot);
+    assert!((product - identity).iter().all(|x| x.norm() < 1e-12));
+}
+
+/// Test that ZXCoupling is the exponential of the Z_control X_target operator
+#[test_case(CalculatorFloat::FRAC_PI_4; "pi_quarter")]
+#[test_case(CalculatorFloat::from(-0.3); "float")]
+fn test_zx_coupling_unitary(theta: CalculatorFloat) {
+    let theta_f: f64 = f64::try_from(theta.clone()).unwrap();
+    let matrix = ZXCoupling::new(0, 1, theta).unitary_matrix().unwrap();
+
+    // Z x X
+    let pauli_z = PauliZ::new(0).unitary_matrix().unwrap();
+    let pauli_x = PauliX::new(0).unitary_matrix().unwrap();
+    let mut zx: Array2<Complex64> = Array2::zeros((4, 4));
+    for ((i, j), a) in pauli_z.indexed_iter() {
+        for ((k, l), b) in pauli_x.indexed_iter() {
+            zx[(2 * i + k, 2 * j + l)] = a * b;
+        }
+    }
+    // exp(-i theta ZX) = cos(theta) I - i sin(theta) ZX, as (ZX)^2 = I
+    let identity: Array2<Complex64> = Array2::eye(4);
+    let expected =
+        identity * Complex64::new(theta_f.cos(), 0.0) - zx * Complex64::new(0.0, theta_f.sin());
+    assert!((matrix - expected).iter().all(|x| x.norm() < 1e-12));
+}